@@ -1,4 +1,4 @@
-use hao::{Module};
+use hao::Module;
 
 fn main() {
     let module = Module::from_path(r#"C:\re\dnspy\bin\dnlib.dll"#).unwrap();
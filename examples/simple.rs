@@ -12,7 +12,10 @@ fn main() {
                 .values()
                 .filter(|x| !x.flags().contains(FieldFlags::SpecialName))
             {
-                println!("\t{},", field.name());
+                match field.constant() {
+                    Some(value) => println!("\t{} = {},", field.name(), value),
+                    None => println!("\t{},", field.name()),
+                }
             }
         } else {
             for field in ty.fields().values() {
@@ -22,7 +25,15 @@ fn main() {
 
         println!("\n");
 
-        for method in ty.methods().values() {
+        for property in ty.properties().values() {
+            println!("\t{}", property);
+        }
+
+        for event in ty.events().values() {
+            println!("\t{};", event);
+        }
+
+        for method in ty.methods().values().filter(|x| x.semantics().is_empty()) {
             println!("\t{};", method);
         }
 
@@ -0,0 +1,238 @@
+use std::ops::Range;
+
+use bitflags::bitflags;
+
+use super::{BodySource, Instruction, TokenOperand};
+use crate::{
+    dotnet::{entries::signature::TypeDefOrRef, md::MDToken},
+    error::{HaoError, Result},
+    io::{DataReader, ReadData},
+};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct MethodDataSectionFlags: u8 {
+        const EHTable           =   0x01;       // Exception handling data.
+        const OptILTable        =   0x02;       // Reserved, shall be 0.
+        const FatFormat         =   0x40;       // Data format is of the fat variety.
+        const MoreSects         =   0x80;       // Another data section occurs after this current section.
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct ExceptionClauseFlags: u32 {
+        const Exception         =   0x0000;     // A typed exception clause.
+        const Filter            =   0x0001;     // An exception filter and handler clause.
+        const Finally           =   0x0002;     // A finally clause.
+        const Fault             =   0x0004;     // Fault clause (finally that is called on exception only).
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExceptionHandlerKind {
+    Catch,
+    Filter,
+    Finally,
+    Fault,
+}
+
+/// A try block and its handler from the extra data sections of a [`MethodBody`](super::MethodBody).
+///
+/// All ranges are indexes into [`MethodBody::instructions`](super::MethodBody::instructions).
+#[derive(Debug, Clone)]
+pub struct ExceptionHandler {
+    pub(crate) flags: ExceptionClauseFlags,
+    pub(crate) try_range: Range<usize>,
+    pub(crate) handler_range: Range<usize>,
+    pub(crate) filter_start: Option<usize>,
+    pub(crate) catch_type: Option<TypeDefOrRef>,
+}
+
+impl ExceptionHandler {
+    pub(crate) const SMALL_CLAUSE_SIZE: usize = 12;
+    pub(crate) const FAT_CLAUSE_SIZE: usize = 24;
+
+    pub fn flags(&self) -> ExceptionClauseFlags {
+        self.flags
+    }
+
+    pub fn kind(&self) -> ExceptionHandlerKind {
+        if self.flags.contains(ExceptionClauseFlags::Fault) {
+            ExceptionHandlerKind::Fault
+        } else if self.flags.contains(ExceptionClauseFlags::Finally) {
+            ExceptionHandlerKind::Finally
+        } else if self.flags.contains(ExceptionClauseFlags::Filter) {
+            ExceptionHandlerKind::Filter
+        } else {
+            ExceptionHandlerKind::Catch
+        }
+    }
+
+    /// The instructions protected by this handler.
+    pub fn try_range(&self) -> Range<usize> {
+        self.try_range.clone()
+    }
+
+    /// The instructions of the handler block.
+    pub fn handler_range(&self) -> Range<usize> {
+        self.handler_range.clone()
+    }
+
+    /// The first instruction of the filter block.
+    /// Only set for [`ExceptionHandlerKind::Filter`] clauses.
+    pub fn filter_start(&self) -> Option<usize> {
+        self.filter_start
+    }
+
+    /// The exception type caught by this handler.
+    /// Only set for [`ExceptionHandlerKind::Catch`] clauses.
+    pub fn catch_type(&self) -> Option<&TypeDefOrRef> {
+        self.catch_type.as_ref()
+    }
+}
+
+// Maps an IL offset to an instruction index. The end of a range
+// may point one past the last instruction.
+fn instruction_index(instructions: &[Instruction], code_size: usize, offset: u32) -> Result<usize> {
+    if offset as usize == code_size {
+        return Ok(instructions.len());
+    }
+    instructions
+        .binary_search_by_key(&offset, |i| i.offset)
+        .map_err(|_| HaoError::BadImageFormat("Exception handler offset is not an instruction"))
+}
+
+// Reads the header of a method data section, returning the size of the data after it.
+fn read_section_header(reader: &mut DataReader) -> Result<(MethodDataSectionFlags, usize)> {
+    // Sections start on a 4 byte boundary. Method bodies
+    // are 4 byte aligned, so the reader position is enough.
+    let padding = (4 - reader.position() % 4) % 4;
+    reader.offset(padding)?;
+
+    let kind = MethodDataSectionFlags::from_bits_retain(reader.read()?);
+    let data_size = if kind.contains(MethodDataSectionFlags::FatFormat) {
+        let size = reader.read_slice(3)?;
+        u32::from_le_bytes([size[0], size[1], size[2], 0]) as usize
+    } else {
+        let size: u8 = reader.read()?;
+        reader.offset(2)?;
+        size as usize
+    };
+    if data_size < 4 {
+        return Err(HaoError::BadImageFormat("Method data section is too small"));
+    }
+    Ok((kind, data_size - 4))
+}
+
+/// Reads the raw data sections following the code of a fat method body.
+///
+/// `reader` must be positioned directly after the code. The returned data
+/// starts on a 4 byte boundary of the body, so it can be read again
+/// with [`read_exception_handlers`].
+pub(crate) fn read_data_sections(reader: &mut DataReader) -> Result<Vec<u8>> {
+    let padding = (4 - reader.position() % 4) % 4;
+    reader.offset(padding)?;
+
+    let data = reader.remaning_slice();
+    let start = reader.position();
+    loop {
+        let (kind, size) = read_section_header(reader)?;
+        reader.offset(size)?;
+        if !kind.contains(MethodDataSectionFlags::MoreSects) {
+            break;
+        }
+    }
+    Ok(data[..reader.position() - start].to_vec())
+}
+
+/// Decodes the exception handlers from the data sections of a method body.
+pub(crate) fn read_exception_handlers(
+    data_sections: &[u8],
+    instructions: &[Instruction],
+    code_size: usize,
+    entries: &BodySource,
+) -> Result<Vec<ExceptionHandler>> {
+    let mut reader = DataReader::new(data_sections);
+    let mut handlers = Vec::new();
+
+    while reader.remaning() > 0 {
+        let (kind, size) = read_section_header(&mut reader)?;
+        let mut section = DataReader::new(reader.read_slice(size)?);
+
+        if kind.contains(MethodDataSectionFlags::EHTable) {
+            let is_fat = kind.contains(MethodDataSectionFlags::FatFormat);
+            let clause_size = if is_fat {
+                ExceptionHandler::FAT_CLAUSE_SIZE
+            } else {
+                ExceptionHandler::SMALL_CLAUSE_SIZE
+            };
+
+            for _ in 0..section.remaning() / clause_size {
+                let (flags, try_offset, try_length, handler_offset, handler_length) = if is_fat {
+                    (
+                        ReadData::<u32>::read(&mut section)?,
+                        ReadData::<u32>::read(&mut section)?,
+                        ReadData::<u32>::read(&mut section)?,
+                        ReadData::<u32>::read(&mut section)?,
+                        ReadData::<u32>::read(&mut section)?,
+                    )
+                } else {
+                    (
+                        ReadData::<u16>::read(&mut section)? as u32,
+                        ReadData::<u16>::read(&mut section)? as u32,
+                        ReadData::<u8>::read(&mut section)? as u32,
+                        ReadData::<u16>::read(&mut section)? as u32,
+                        ReadData::<u8>::read(&mut section)? as u32,
+                    )
+                };
+                let class_token_or_filter: u32 = section.read()?;
+                let flags = ExceptionClauseFlags::from_bits_retain(flags);
+
+                let index = |offset: u32| instruction_index(instructions, code_size, offset);
+                let range = |offset: u32, length: u32| -> Result<Range<usize>> {
+                    let end = offset.checked_add(length).ok_or(HaoError::BadImageFormat(
+                        "Exception handler range overflows",
+                    ))?;
+                    Ok(index(offset)?..index(end)?)
+                };
+
+                let mut handler = ExceptionHandler {
+                    flags,
+                    try_range: range(try_offset, try_length)?,
+                    handler_range: range(handler_offset, handler_length)?,
+                    filter_start: None,
+                    catch_type: None,
+                };
+
+                match handler.kind() {
+                    ExceptionHandlerKind::Catch => {
+                        handler.catch_type = Some(
+                            match TokenOperand::from_token(
+                                entries,
+                                MDToken::from_raw(class_token_or_filter),
+                            )? {
+                                TokenOperand::TypeDef(e) => TypeDefOrRef::TypeDef(e),
+                                TokenOperand::TypeRef(e) => TypeDefOrRef::TypeRef(e),
+                                TokenOperand::TypeSpec(e) => TypeDefOrRef::TypeSpec(e),
+                                _ => {
+                                    return Err(HaoError::BadImageFormat(
+                                        "Exception handler catch type is not a type",
+                                    ))
+                                }
+                            },
+                        );
+                    }
+                    ExceptionHandlerKind::Filter => {
+                        handler.filter_start = Some(index(class_token_or_filter)?);
+                    }
+                    _ => {}
+                }
+
+                handlers.push(handler);
+            }
+        }
+    }
+
+    Ok(handlers)
+}
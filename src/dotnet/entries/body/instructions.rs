@@ -0,0 +1,633 @@
+use std::fmt::Display;
+
+use super::BodySource;
+use crate::{
+    dotnet::{
+        entries::{
+            values::{
+                Field, MemberRef, Method, MethodSpec, StandAloneSig, TypeDef, TypeRef, TypeSpec,
+            },
+            EntList, Entry,
+        },
+        md::{streams::tables_stream::TableId, MDToken},
+    },
+    error::{HaoError, Result},
+    io::{DataReader, ReadData},
+};
+
+/// The kind of inline operand that follows an [`OpCode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperandType {
+    InlineNone,
+    ShortInlineVar,
+    InlineVar,
+    ShortInlineI,
+    InlineI,
+    InlineI8,
+    ShortInlineR,
+    InlineR,
+    InlineMethod,
+    InlineSig,
+    ShortInlineBrTarget,
+    InlineBrTarget,
+    InlineSwitch,
+    InlineType,
+    InlineString,
+    InlineField,
+    InlineTok,
+}
+
+impl OperandType {
+    /// Size of the operand in bytes. `InlineSwitch` is variable and returns the
+    /// size of its count prefix.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::InlineNone => 0,
+            Self::ShortInlineVar | Self::ShortInlineI | Self::ShortInlineBrTarget => 1,
+            Self::InlineVar => 2,
+            Self::InlineI8 | Self::InlineR => 8,
+            _ => 4,
+        }
+    }
+}
+
+macro_rules! opcodes {
+    ($($name:ident = $value:literal, $mnemonic:literal, $operand:ident;)+) => {
+        /// Every opcode defined in ECMA-335 Partition III.
+        ///
+        /// Two byte opcodes have the `0xFE` prefix in the high byte of [`OpCode::value`].
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum OpCode {
+            $($name,)+
+        }
+
+        impl OpCode {
+            pub fn value(&self) -> u16 {
+                match self {
+                    $(Self::$name => $value,)+
+                }
+            }
+
+            /// The IL assembler name of the opcode. e.g. `ldc.i4.s`.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$name => $mnemonic,)+
+                }
+            }
+
+            pub fn operand_type(&self) -> OperandType {
+                match self {
+                    $(Self::$name => OperandType::$operand,)+
+                }
+            }
+
+            pub fn from_value(value: u16) -> Option<Self> {
+                match value {
+                    $($value => Some(Self::$name),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+opcodes! {
+    Nop = 0x00, "nop", InlineNone;
+    Break = 0x01, "break", InlineNone;
+    Ldarg0 = 0x02, "ldarg.0", InlineNone;
+    Ldarg1 = 0x03, "ldarg.1", InlineNone;
+    Ldarg2 = 0x04, "ldarg.2", InlineNone;
+    Ldarg3 = 0x05, "ldarg.3", InlineNone;
+    Ldloc0 = 0x06, "ldloc.0", InlineNone;
+    Ldloc1 = 0x07, "ldloc.1", InlineNone;
+    Ldloc2 = 0x08, "ldloc.2", InlineNone;
+    Ldloc3 = 0x09, "ldloc.3", InlineNone;
+    Stloc0 = 0x0A, "stloc.0", InlineNone;
+    Stloc1 = 0x0B, "stloc.1", InlineNone;
+    Stloc2 = 0x0C, "stloc.2", InlineNone;
+    Stloc3 = 0x0D, "stloc.3", InlineNone;
+    LdargS = 0x0E, "ldarg.s", ShortInlineVar;
+    LdargaS = 0x0F, "ldarga.s", ShortInlineVar;
+    StargS = 0x10, "starg.s", ShortInlineVar;
+    LdlocS = 0x11, "ldloc.s", ShortInlineVar;
+    LdlocaS = 0x12, "ldloca.s", ShortInlineVar;
+    StlocS = 0x13, "stloc.s", ShortInlineVar;
+    Ldnull = 0x14, "ldnull", InlineNone;
+    LdcI4M1 = 0x15, "ldc.i4.m1", InlineNone;
+    LdcI4_0 = 0x16, "ldc.i4.0", InlineNone;
+    LdcI4_1 = 0x17, "ldc.i4.1", InlineNone;
+    LdcI4_2 = 0x18, "ldc.i4.2", InlineNone;
+    LdcI4_3 = 0x19, "ldc.i4.3", InlineNone;
+    LdcI4_4 = 0x1A, "ldc.i4.4", InlineNone;
+    LdcI4_5 = 0x1B, "ldc.i4.5", InlineNone;
+    LdcI4_6 = 0x1C, "ldc.i4.6", InlineNone;
+    LdcI4_7 = 0x1D, "ldc.i4.7", InlineNone;
+    LdcI4_8 = 0x1E, "ldc.i4.8", InlineNone;
+    LdcI4S = 0x1F, "ldc.i4.s", ShortInlineI;
+    LdcI4 = 0x20, "ldc.i4", InlineI;
+    LdcI8 = 0x21, "ldc.i8", InlineI8;
+    LdcR4 = 0x22, "ldc.r4", ShortInlineR;
+    LdcR8 = 0x23, "ldc.r8", InlineR;
+    Dup = 0x25, "dup", InlineNone;
+    Pop = 0x26, "pop", InlineNone;
+    Jmp = 0x27, "jmp", InlineMethod;
+    Call = 0x28, "call", InlineMethod;
+    Calli = 0x29, "calli", InlineSig;
+    Ret = 0x2A, "ret", InlineNone;
+    BrS = 0x2B, "br.s", ShortInlineBrTarget;
+    BrfalseS = 0x2C, "brfalse.s", ShortInlineBrTarget;
+    BrtrueS = 0x2D, "brtrue.s", ShortInlineBrTarget;
+    BeqS = 0x2E, "beq.s", ShortInlineBrTarget;
+    BgeS = 0x2F, "bge.s", ShortInlineBrTarget;
+    BgtS = 0x30, "bgt.s", ShortInlineBrTarget;
+    BleS = 0x31, "ble.s", ShortInlineBrTarget;
+    BltS = 0x32, "blt.s", ShortInlineBrTarget;
+    BneUnS = 0x33, "bne.un.s", ShortInlineBrTarget;
+    BgeUnS = 0x34, "bge.un.s", ShortInlineBrTarget;
+    BgtUnS = 0x35, "bgt.un.s", ShortInlineBrTarget;
+    BleUnS = 0x36, "ble.un.s", ShortInlineBrTarget;
+    BltUnS = 0x37, "blt.un.s", ShortInlineBrTarget;
+    Br = 0x38, "br", InlineBrTarget;
+    Brfalse = 0x39, "brfalse", InlineBrTarget;
+    Brtrue = 0x3A, "brtrue", InlineBrTarget;
+    Beq = 0x3B, "beq", InlineBrTarget;
+    Bge = 0x3C, "bge", InlineBrTarget;
+    Bgt = 0x3D, "bgt", InlineBrTarget;
+    Ble = 0x3E, "ble", InlineBrTarget;
+    Blt = 0x3F, "blt", InlineBrTarget;
+    BneUn = 0x40, "bne.un", InlineBrTarget;
+    BgeUn = 0x41, "bge.un", InlineBrTarget;
+    BgtUn = 0x42, "bgt.un", InlineBrTarget;
+    BleUn = 0x43, "ble.un", InlineBrTarget;
+    BltUn = 0x44, "blt.un", InlineBrTarget;
+    Switch = 0x45, "switch", InlineSwitch;
+    LdindI1 = 0x46, "ldind.i1", InlineNone;
+    LdindU1 = 0x47, "ldind.u1", InlineNone;
+    LdindI2 = 0x48, "ldind.i2", InlineNone;
+    LdindU2 = 0x49, "ldind.u2", InlineNone;
+    LdindI4 = 0x4A, "ldind.i4", InlineNone;
+    LdindU4 = 0x4B, "ldind.u4", InlineNone;
+    LdindI8 = 0x4C, "ldind.i8", InlineNone;
+    LdindI = 0x4D, "ldind.i", InlineNone;
+    LdindR4 = 0x4E, "ldind.r4", InlineNone;
+    LdindR8 = 0x4F, "ldind.r8", InlineNone;
+    LdindRef = 0x50, "ldind.ref", InlineNone;
+    StindRef = 0x51, "stind.ref", InlineNone;
+    StindI1 = 0x52, "stind.i1", InlineNone;
+    StindI2 = 0x53, "stind.i2", InlineNone;
+    StindI4 = 0x54, "stind.i4", InlineNone;
+    StindI8 = 0x55, "stind.i8", InlineNone;
+    StindR4 = 0x56, "stind.r4", InlineNone;
+    StindR8 = 0x57, "stind.r8", InlineNone;
+    Add = 0x58, "add", InlineNone;
+    Sub = 0x59, "sub", InlineNone;
+    Mul = 0x5A, "mul", InlineNone;
+    Div = 0x5B, "div", InlineNone;
+    DivUn = 0x5C, "div.un", InlineNone;
+    Rem = 0x5D, "rem", InlineNone;
+    RemUn = 0x5E, "rem.un", InlineNone;
+    And = 0x5F, "and", InlineNone;
+    Or = 0x60, "or", InlineNone;
+    Xor = 0x61, "xor", InlineNone;
+    Shl = 0x62, "shl", InlineNone;
+    Shr = 0x63, "shr", InlineNone;
+    ShrUn = 0x64, "shr.un", InlineNone;
+    Neg = 0x65, "neg", InlineNone;
+    Not = 0x66, "not", InlineNone;
+    ConvI1 = 0x67, "conv.i1", InlineNone;
+    ConvI2 = 0x68, "conv.i2", InlineNone;
+    ConvI4 = 0x69, "conv.i4", InlineNone;
+    ConvI8 = 0x6A, "conv.i8", InlineNone;
+    ConvR4 = 0x6B, "conv.r4", InlineNone;
+    ConvR8 = 0x6C, "conv.r8", InlineNone;
+    ConvU4 = 0x6D, "conv.u4", InlineNone;
+    ConvU8 = 0x6E, "conv.u8", InlineNone;
+    Callvirt = 0x6F, "callvirt", InlineMethod;
+    Cpobj = 0x70, "cpobj", InlineType;
+    Ldobj = 0x71, "ldobj", InlineType;
+    Ldstr = 0x72, "ldstr", InlineString;
+    Newobj = 0x73, "newobj", InlineMethod;
+    Castclass = 0x74, "castclass", InlineType;
+    Isinst = 0x75, "isinst", InlineType;
+    ConvRUn = 0x76, "conv.r.un", InlineNone;
+    Unbox = 0x79, "unbox", InlineType;
+    Throw = 0x7A, "throw", InlineNone;
+    Ldfld = 0x7B, "ldfld", InlineField;
+    Ldflda = 0x7C, "ldflda", InlineField;
+    Stfld = 0x7D, "stfld", InlineField;
+    Ldsfld = 0x7E, "ldsfld", InlineField;
+    Ldsflda = 0x7F, "ldsflda", InlineField;
+    Stsfld = 0x80, "stsfld", InlineField;
+    Stobj = 0x81, "stobj", InlineType;
+    ConvOvfI1Un = 0x82, "conv.ovf.i1.un", InlineNone;
+    ConvOvfI2Un = 0x83, "conv.ovf.i2.un", InlineNone;
+    ConvOvfI4Un = 0x84, "conv.ovf.i4.un", InlineNone;
+    ConvOvfI8Un = 0x85, "conv.ovf.i8.un", InlineNone;
+    ConvOvfU1Un = 0x86, "conv.ovf.u1.un", InlineNone;
+    ConvOvfU2Un = 0x87, "conv.ovf.u2.un", InlineNone;
+    ConvOvfU4Un = 0x88, "conv.ovf.u4.un", InlineNone;
+    ConvOvfU8Un = 0x89, "conv.ovf.u8.un", InlineNone;
+    ConvOvfIUn = 0x8A, "conv.ovf.i.un", InlineNone;
+    ConvOvfUUn = 0x8B, "conv.ovf.u.un", InlineNone;
+    Box = 0x8C, "box", InlineType;
+    Newarr = 0x8D, "newarr", InlineType;
+    Ldlen = 0x8E, "ldlen", InlineNone;
+    Ldelema = 0x8F, "ldelema", InlineType;
+    LdelemI1 = 0x90, "ldelem.i1", InlineNone;
+    LdelemU1 = 0x91, "ldelem.u1", InlineNone;
+    LdelemI2 = 0x92, "ldelem.i2", InlineNone;
+    LdelemU2 = 0x93, "ldelem.u2", InlineNone;
+    LdelemI4 = 0x94, "ldelem.i4", InlineNone;
+    LdelemU4 = 0x95, "ldelem.u4", InlineNone;
+    LdelemI8 = 0x96, "ldelem.i8", InlineNone;
+    LdelemI = 0x97, "ldelem.i", InlineNone;
+    LdelemR4 = 0x98, "ldelem.r4", InlineNone;
+    LdelemR8 = 0x99, "ldelem.r8", InlineNone;
+    LdelemRef = 0x9A, "ldelem.ref", InlineNone;
+    StelemI = 0x9B, "stelem.i", InlineNone;
+    StelemI1 = 0x9C, "stelem.i1", InlineNone;
+    StelemI2 = 0x9D, "stelem.i2", InlineNone;
+    StelemI4 = 0x9E, "stelem.i4", InlineNone;
+    StelemI8 = 0x9F, "stelem.i8", InlineNone;
+    StelemR4 = 0xA0, "stelem.r4", InlineNone;
+    StelemR8 = 0xA1, "stelem.r8", InlineNone;
+    StelemRef = 0xA2, "stelem.ref", InlineNone;
+    Ldelem = 0xA3, "ldelem", InlineType;
+    Stelem = 0xA4, "stelem", InlineType;
+    UnboxAny = 0xA5, "unbox.any", InlineType;
+    ConvOvfI1 = 0xB3, "conv.ovf.i1", InlineNone;
+    ConvOvfU1 = 0xB4, "conv.ovf.u1", InlineNone;
+    ConvOvfI2 = 0xB5, "conv.ovf.i2", InlineNone;
+    ConvOvfU2 = 0xB6, "conv.ovf.u2", InlineNone;
+    ConvOvfI4 = 0xB7, "conv.ovf.i4", InlineNone;
+    ConvOvfU4 = 0xB8, "conv.ovf.u4", InlineNone;
+    ConvOvfI8 = 0xB9, "conv.ovf.i8", InlineNone;
+    ConvOvfU8 = 0xBA, "conv.ovf.u8", InlineNone;
+    Refanyval = 0xC2, "refanyval", InlineType;
+    Ckfinite = 0xC3, "ckfinite", InlineNone;
+    Mkrefany = 0xC6, "mkrefany", InlineType;
+    Ldtoken = 0xD0, "ldtoken", InlineTok;
+    ConvU2 = 0xD1, "conv.u2", InlineNone;
+    ConvU1 = 0xD2, "conv.u1", InlineNone;
+    ConvI = 0xD3, "conv.i", InlineNone;
+    ConvOvfI = 0xD4, "conv.ovf.i", InlineNone;
+    ConvOvfU = 0xD5, "conv.ovf.u", InlineNone;
+    AddOvf = 0xD6, "add.ovf", InlineNone;
+    AddOvfUn = 0xD7, "add.ovf.un", InlineNone;
+    MulOvf = 0xD8, "mul.ovf", InlineNone;
+    MulOvfUn = 0xD9, "mul.ovf.un", InlineNone;
+    SubOvf = 0xDA, "sub.ovf", InlineNone;
+    SubOvfUn = 0xDB, "sub.ovf.un", InlineNone;
+    Endfinally = 0xDC, "endfinally", InlineNone;
+    Leave = 0xDD, "leave", InlineBrTarget;
+    LeaveS = 0xDE, "leave.s", ShortInlineBrTarget;
+    StindI = 0xDF, "stind.i", InlineNone;
+    ConvU = 0xE0, "conv.u", InlineNone;
+
+    Arglist = 0xFE00, "arglist", InlineNone;
+    Ceq = 0xFE01, "ceq", InlineNone;
+    Cgt = 0xFE02, "cgt", InlineNone;
+    CgtUn = 0xFE03, "cgt.un", InlineNone;
+    Clt = 0xFE04, "clt", InlineNone;
+    CltUn = 0xFE05, "clt.un", InlineNone;
+    Ldftn = 0xFE06, "ldftn", InlineMethod;
+    Ldvirtftn = 0xFE07, "ldvirtftn", InlineMethod;
+    Ldarg = 0xFE09, "ldarg", InlineVar;
+    Ldarga = 0xFE0A, "ldarga", InlineVar;
+    Starg = 0xFE0B, "starg", InlineVar;
+    Ldloc = 0xFE0C, "ldloc", InlineVar;
+    Ldloca = 0xFE0D, "ldloca", InlineVar;
+    Stloc = 0xFE0E, "stloc", InlineVar;
+    Localloc = 0xFE0F, "localloc", InlineNone;
+    Endfilter = 0xFE11, "endfilter", InlineNone;
+    Unaligned = 0xFE12, "unaligned.", ShortInlineI;
+    Volatile = 0xFE13, "volatile.", InlineNone;
+    Tailcall = 0xFE14, "tail.", InlineNone;
+    Initobj = 0xFE15, "initobj", InlineType;
+    Constrained = 0xFE16, "constrained.", InlineType;
+    Cpblk = 0xFE17, "cpblk", InlineNone;
+    Initblk = 0xFE18, "initblk", InlineNone;
+    No = 0xFE19, "no.", ShortInlineI;
+    Rethrow = 0xFE1A, "rethrow", InlineNone;
+    Sizeof = 0xFE1C, "sizeof", InlineType;
+    Refanytype = 0xFE1D, "refanytype", InlineNone;
+    Readonly = 0xFE1E, "readonly.", InlineNone;
+}
+
+impl OpCode {
+    const TWO_BYTE_PREFIX: u8 = 0xFE;
+
+    /// Returns `true` if the opcode is encoded with the `0xFE` prefix.
+    pub fn is_two_byte(&self) -> bool {
+        (self.value() >> 8) as u8 == Self::TWO_BYTE_PREFIX
+    }
+}
+
+impl Display for OpCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// Row ids in a token start at 1.
+fn entry_from_token<T>(table: &EntList<T>, token: MDToken) -> Result<Entry<T>> {
+    let rid = token.rid() as usize;
+    rid.checked_sub(1)
+        .and_then(|index| table.get(index))
+        .map(|ptr| Entry(ptr.clone()))
+        .ok_or(HaoError::InvalidEntryRefrence(
+            std::any::type_name::<T>(),
+            rid,
+        ))
+}
+
+pub(crate) fn stand_alone_sig_from_token(
+    entries: &BodySource,
+    token: MDToken,
+) -> Result<Entry<StandAloneSig>> {
+    if token.table() != Some(TableId::StandAloneSig) {
+        return Err(HaoError::BadImageFormat("Token is not a StandAloneSig"));
+    }
+    entry_from_token(&entries.stand_alone_sigs, token)
+}
+
+/// An entry that a metadata token operand refers to.
+#[derive(Clone)]
+pub enum TokenOperand {
+    TypeDef(Entry<TypeDef>),
+    TypeRef(Entry<TypeRef>),
+    TypeSpec(Entry<TypeSpec>),
+    Method(Entry<Method>),
+    Field(Entry<Field>),
+    MemberRef(Entry<MemberRef>),
+    MethodSpec(Entry<MethodSpec>),
+    /// A token into a table that is not loaded as entries.
+    Unresolved(MDToken),
+}
+
+impl TokenOperand {
+    pub(crate) fn from_token(entries: &BodySource, token: MDToken) -> Result<Self> {
+        let operand = match token.table() {
+            Some(TableId::TypeRef) => Self::TypeRef(entry_from_token(&entries.type_refs, token)?),
+            Some(TableId::TypeDef) => Self::TypeDef(entry_from_token(&entries.type_defs, token)?),
+            Some(TableId::Field) => Self::Field(entry_from_token(&entries.fields, token)?),
+            Some(TableId::Method) => Self::Method(entry_from_token(&entries.methods, token)?),
+            Some(TableId::MemberRef) => {
+                Self::MemberRef(entry_from_token(&entries.member_refs, token)?)
+            }
+            Some(TableId::TypeSpec) => {
+                Self::TypeSpec(entry_from_token(&entries.type_specs, token)?)
+            }
+            Some(TableId::MethodSpec) => {
+                Self::MethodSpec(entry_from_token(&entries.method_specs, token)?)
+            }
+            _ => Self::Unresolved(token),
+        };
+        Ok(operand)
+    }
+}
+
+// impl to stop infiniate reccursion on debug print
+impl std::fmt::Debug for TokenOperand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeDef(e) => write!(
+                f,
+                "TypeDef(\"{}.{}\")",
+                e.value().namespace(),
+                e.value().name()
+            ),
+            Self::TypeRef(e) => write!(
+                f,
+                "TypeRef(\"{}.{}\")",
+                e.value().namespace(),
+                e.value().name()
+            ),
+            Self::TypeSpec(e) => write!(f, "TypeSpec({:?})", e.value().signature()),
+            Self::Method(e) => write!(f, "Method(\"{}\")", e.value().name()),
+            Self::Field(e) => write!(f, "Field(\"{}\")", e.value().name()),
+            Self::MemberRef(e) => write!(f, "MemberRef(\"{}\")", e.value().name()),
+            Self::MethodSpec(e) => write!(f, "MethodSpec(\"{}\")", e.value()),
+            Self::Unresolved(token) => write!(f, "Unresolved({})", token),
+        }
+    }
+}
+
+impl Display for TokenOperand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeDef(e) => write!(f, "{}", e.value().name()),
+            Self::TypeRef(e) => write!(f, "{}", e.value()),
+            Self::TypeSpec(e) => write!(f, "{}", e.value().signature()),
+            Self::Method(e) => write!(f, "{}", e.value().name()),
+            Self::Field(e) => write!(f, "{}", e.value().name()),
+            Self::MemberRef(e) => write!(f, "{}", e.value()),
+            Self::MethodSpec(e) => write!(f, "{}", e.value()),
+            Self::Unresolved(token) => write!(f, "{}", token),
+        }
+    }
+}
+
+/// The decoded inline operand of an [`Instruction`].
+#[derive(Debug, Clone)]
+pub enum Operand {
+    None,
+    /// `ldc.i4.s`
+    Int8(i8),
+    /// `unaligned.` and `no.`
+    UInt8(u8),
+    Int32(i32),
+    Int64(i64),
+    Float32(f32),
+    Float64(f64),
+    /// Index of an argument or local variable.
+    Variable(u16),
+    /// Index of the target instruction in [`MethodBody::instructions`](super::MethodBody::instructions).
+    BranchTarget(usize),
+    /// Indexes of the target instructions in [`MethodBody::instructions`](super::MethodBody::instructions).
+    Switch(Vec<usize>),
+    /// The string loaded by `ldstr`.
+    String(String),
+    /// The call site signature of a `calli`.
+    Signature(Entry<StandAloneSig>),
+    Token(TokenOperand),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => Ok(()),
+            Self::Int8(v) => write!(f, "{}", v),
+            Self::UInt8(v) => write!(f, "{}", v),
+            Self::Int32(v) => write!(f, "{}", v),
+            Self::Int64(v) => write!(f, "{}", v),
+            Self::Float32(v) => write!(f, "{}", v),
+            Self::Float64(v) => write!(f, "{}", v),
+            Self::Variable(v) => write!(f, "{}", v),
+            Self::BranchTarget(target) => write!(f, "#{}", target),
+            Self::Switch(targets) => {
+                write!(f, "(")?;
+                for (index, target) in targets.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "#{}", target)?;
+                }
+                write!(f, ")")
+            }
+            Self::String(value) => write!(f, "{:?}", value),
+            Self::Signature(sig) => write!(f, "{}", sig.value().signature()),
+            Self::Token(token) => write!(f, "{}", token),
+        }
+    }
+}
+
+/// A single decoded CIL instruction.
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub(crate) offset: u32,
+    pub(crate) opcode: OpCode,
+    pub(crate) operand: Operand,
+}
+
+impl Instruction {
+    /// Offset of the instruction from the start of the IL code.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+    pub fn opcode(&self) -> OpCode {
+        self.opcode
+    }
+    pub fn operand(&self) -> &Operand {
+        &self.operand
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IL_{:04x}: {}", self.offset, self.opcode)?;
+        if !matches!(self.operand, Operand::None) {
+            write!(f, " {}", self.operand)?;
+        }
+        Ok(())
+    }
+}
+
+// Branch targets are decoded as absolute IL offsets first
+// and mapped to instruction indexes once every instruction is read.
+enum RawOperand {
+    Operand(Operand),
+    BranchTarget(i64),
+    Switch(Vec<i64>),
+}
+
+pub(crate) fn read_instructions(code: &[u8], source: &BodySource) -> Result<Vec<Instruction>> {
+    let mut reader = DataReader::new(code);
+    let mut raw_instructions = Vec::new();
+
+    while reader.remaning() > 0 {
+        let offset = reader.position() as u32;
+        let opcode = read_opcode(&mut reader)?;
+        let operand = read_operand(&mut reader, opcode, source)?;
+        raw_instructions.push((offset, opcode, operand));
+    }
+
+    let to_index = |target: i64| -> Result<usize> {
+        raw_instructions
+            .binary_search_by_key(&target, |(offset, _, _)| *offset as i64)
+            .map_err(|_| HaoError::BadImageFormat("Branch target is not an instruction"))
+    };
+
+    let mut instructions = Vec::with_capacity(raw_instructions.len());
+    for (offset, opcode, operand) in raw_instructions.iter() {
+        let operand = match operand {
+            RawOperand::Operand(operand) => operand.clone(),
+            RawOperand::BranchTarget(target) => Operand::BranchTarget(to_index(*target)?),
+            RawOperand::Switch(targets) => Operand::Switch(
+                targets
+                    .iter()
+                    .map(|target| to_index(*target))
+                    .collect::<Result<_>>()?,
+            ),
+        };
+        instructions.push(Instruction {
+            offset: *offset,
+            opcode: *opcode,
+            operand,
+        });
+    }
+    Ok(instructions)
+}
+
+fn read_opcode(reader: &mut DataReader) -> Result<OpCode> {
+    let first: u8 = reader.read()?;
+    let value = if first == OpCode::TWO_BYTE_PREFIX {
+        let second: u8 = reader.read()?;
+        u16::from_be_bytes([first, second])
+    } else {
+        first as u16
+    };
+    OpCode::from_value(value).ok_or(HaoError::InvalidOpCode(value, reader.position()))
+}
+
+fn read_operand(
+    reader: &mut DataReader,
+    opcode: OpCode,
+    source: &BodySource,
+) -> Result<RawOperand> {
+    let operand = match opcode.operand_type() {
+        OperandType::InlineNone => Operand::None,
+        OperandType::ShortInlineVar => Operand::Variable(ReadData::<u8>::read(reader)? as u16),
+        OperandType::InlineVar => Operand::Variable(reader.read()?),
+        OperandType::ShortInlineI => match opcode {
+            OpCode::LdcI4S => Operand::Int8(ReadData::<u8>::read(reader)? as i8),
+            _ => Operand::UInt8(reader.read()?),
+        },
+        OperandType::InlineI => Operand::Int32(ReadData::<u32>::read(reader)? as i32),
+        OperandType::InlineI8 => Operand::Int64(ReadData::<u64>::read(reader)? as i64),
+        OperandType::ShortInlineR => Operand::Float32(f32::from_bits(reader.read()?)),
+        OperandType::InlineR => Operand::Float64(f64::from_bits(reader.read()?)),
+        OperandType::InlineString => {
+            Operand::String(source.user_strings().read_token(reader.read()?)?)
+        }
+        OperandType::InlineSig => Operand::Signature(stand_alone_sig_from_token(
+            source,
+            MDToken::from_raw(reader.read()?),
+        )?),
+        OperandType::InlineMethod
+        | OperandType::InlineType
+        | OperandType::InlineField
+        | OperandType::InlineTok => Operand::Token(TokenOperand::from_token(
+            source,
+            MDToken::from_raw(reader.read()?),
+        )?),
+        OperandType::ShortInlineBrTarget => {
+            let delta = ReadData::<u8>::read(reader)? as i8;
+            return Ok(RawOperand::BranchTarget(
+                reader.position() as i64 + delta as i64,
+            ));
+        }
+        OperandType::InlineBrTarget => {
+            let delta = ReadData::<u32>::read(reader)? as i32;
+            return Ok(RawOperand::BranchTarget(
+                reader.position() as i64 + delta as i64,
+            ));
+        }
+        OperandType::InlineSwitch => {
+            let count: u32 = reader.read()?;
+            // Targets are relative to the end of the whole switch instruction.
+            let deltas = reader.read_slice(
+                (count as usize)
+                    .checked_mul(4)
+                    .ok_or(HaoError::NotEnoughDataLeft(usize::MAX))?,
+            )?;
+            let base = reader.position() as i64;
+            let targets = deltas
+                .chunks_exact(4)
+                .map(|d| base + i32::from_le_bytes([d[0], d[1], d[2], d[3]]) as i64)
+                .collect();
+            return Ok(RawOperand::Switch(targets));
+        }
+    };
+    Ok(RawOperand::Operand(operand))
+}
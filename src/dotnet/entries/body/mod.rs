@@ -0,0 +1,336 @@
+mod exception_handlers;
+mod instructions;
+
+use std::rc::Rc;
+
+use bitflags::bitflags;
+pub use exception_handlers::*;
+pub use instructions::*;
+
+use crate::{
+    dotnet::{
+        entries::{
+            signature::{StandAloneSignature, ValueType},
+            values::{
+                Field, MemberRef, Method, MethodSpec, StandAloneSig, TypeDef, TypeRef, TypeSpec,
+            },
+            EntList, Entry, MaybeUninitEntries,
+        },
+        md::{streams::UserStringsStream, MDToken},
+        metadata::Metadata,
+    },
+    error::{HaoError, Result},
+    io::{DataReader, ReadData},
+};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct MethodBodyFlags: u16 {
+        const FormatMask        =   0x0003;     // Use this mask to retrieve the header format.
+        const TinyFormat        =   0x0002;     // Method header is tiny (1 byte).
+        const FatFormat         =   0x0003;     // Method header is fat (12 bytes).
+        const MoreSects         =   0x0008;     // Additional data sections follow the code.
+        const InitLocals        =   0x0010;     // Call default constructor on all local variables.
+    }
+}
+
+/// The IL body of a [`Method`](super::values::Method).
+///
+/// This is decoded from the method's RVA each time
+/// [`Method::body`](super::values::Method::body) is called.
+#[derive(Debug, Clone)]
+pub struct MethodBody {
+    pub(crate) flags: MethodBodyFlags,
+    pub(crate) header_size: u8,
+    pub(crate) max_stack: u16,
+    pub(crate) local_var_sig_token: u32,
+    pub(crate) local_var_sig: Option<Entry<StandAloneSig>>,
+    pub(crate) code: Vec<u8>,
+    pub(crate) data_sections: Vec<u8>,
+    pub(crate) source: Rc<BodySource>,
+}
+
+impl MethodBody {
+    const TINY_MAX_STACK: u16 = 8;
+
+    pub fn flags(&self) -> MethodBodyFlags {
+        self.flags
+    }
+
+    /// Returns `true` if the body was stored with a one byte header.
+    pub fn is_tiny(&self) -> bool {
+        (self.flags & MethodBodyFlags::FormatMask) == MethodBodyFlags::TinyFormat
+    }
+
+    /// Size of the method header in bytes.
+    pub fn header_size(&self) -> u8 {
+        self.header_size
+    }
+
+    pub fn max_stack(&self) -> u16 {
+        self.max_stack
+    }
+
+    /// The raw `StandAloneSig` token describing the local variables,
+    /// or `0` if the method has no locals.
+    pub fn local_var_sig_token(&self) -> u32 {
+        self.local_var_sig_token
+    }
+
+    /// The [`StandAloneSig`] describing the local variables.
+    pub fn local_var_sig(&self) -> Option<&Entry<StandAloneSig>> {
+        self.local_var_sig.as_ref()
+    }
+
+    /// The types of the local variables of the method, in slot order.
+    ///
+    /// Pinned locals are [`ValueType::Pinned`] and by-ref locals are [`ValueType::ByRef`].
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for method in module.all_methods().values() {
+    ///     let Ok(Some(body)) = method.body() else { continue };
+    ///     for (index, local) in body.locals().iter().enumerate() {
+    ///         println!("{} V_{}: {}", method.name(), index, local);
+    ///     }
+    /// }
+    /// ```
+    pub fn locals(&self) -> Vec<ValueType> {
+        match &self.local_var_sig {
+            Some(sig) => match sig.value().signature() {
+                StandAloneSignature::LocalVar(locals) => locals.0.clone(),
+                _ => Vec::new(),
+            },
+            None => Vec::new(),
+        }
+    }
+
+    pub fn init_locals(&self) -> bool {
+        self.flags.contains(MethodBodyFlags::InitLocals)
+    }
+
+    /// The raw IL bytes of the method.
+    pub fn code(&self) -> &[u8] {
+        &self.code
+    }
+
+    /// Decodes the IL instructions of the method from [`code`](Self::code).
+    ///
+    /// Token operands are resolved to their entries and branch
+    /// targets are indexes into the returned instructions.
+    /// ```
+    /// # use hao::{Module, dotnet::entries::body::{OpCode, Operand}};
+    /// let module = Module::default();
+    ///
+    /// for method in module.all_methods().values() {
+    ///     let Ok(Some(body)) = method.body() else { continue };
+    ///     let Ok(instructions) = body.instructions() else { continue };
+    ///     for instruction in &instructions {
+    ///         if let (OpCode::Call, Operand::Token(target)) =
+    ///             (instruction.opcode(), instruction.operand())
+    ///         {
+    ///             println!("{} calls {}", method.name(), target);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn instructions(&self) -> Result<Vec<Instruction>> {
+        read_instructions(&self.code, &self.source)
+    }
+
+    /// Decodes the try/catch/finally/filter/fault clauses of the method.
+    ///
+    /// Ranges are indexes into the instructions returned by [`instructions`](Self::instructions).
+    /// ```
+    /// # use hao::{Module, dotnet::entries::body::ExceptionHandlerKind};
+    /// let module = Module::default();
+    ///
+    /// for method in module.all_methods().values() {
+    ///     let Ok(Some(body)) = method.body() else { continue };
+    ///     let Ok(handlers) = body.exception_handlers() else { continue };
+    ///     for handler in &handlers {
+    ///         if let (ExceptionHandlerKind::Catch, Some(catch_type)) =
+    ///             (handler.kind(), handler.catch_type())
+    ///         {
+    ///             println!("{} catches {}", method.name(), catch_type);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn exception_handlers(&self) -> Result<Vec<ExceptionHandler>> {
+        self.exception_handlers_with(&self.instructions()?)
+    }
+
+    // Decodes the exception handlers against already decoded instructions.
+    pub(crate) fn exception_handlers_with(
+        &self,
+        instructions: &[Instruction],
+    ) -> Result<Vec<ExceptionHandler>> {
+        read_exception_handlers(
+            &self.data_sections,
+            instructions,
+            self.code.len(),
+            &self.source,
+        )
+    }
+
+    pub(crate) fn from_source(source: &Rc<BodySource>, rva: u32) -> Result<Self> {
+        let data = source.section_data(rva).ok_or(HaoError::BadRva(rva))?;
+        let mut reader = DataReader::new(data);
+        Self::from_reader(&mut reader, source).map_err(|e| match e {
+            HaoError::NotEnoughDataLeft(_) => {
+                HaoError::InvalidMethodBody(rva, "Method body is larger than its section")
+            }
+            HaoError::BadImageFormat(reason) => HaoError::InvalidMethodBody(rva, reason),
+            e => e,
+        })
+    }
+
+    pub(crate) fn from_reader(reader: &mut DataReader, source: &Rc<BodySource>) -> Result<Self> {
+        let first: u8 = reader.read()?;
+        let format = MethodBodyFlags::from_bits_retain(first as u16) & MethodBodyFlags::FormatMask;
+
+        if format == MethodBodyFlags::TinyFormat {
+            let code = reader.read_slice((first >> 2) as usize)?;
+            return Ok(Self {
+                flags: MethodBodyFlags::TinyFormat,
+                header_size: 1,
+                max_stack: Self::TINY_MAX_STACK,
+                local_var_sig_token: 0,
+                local_var_sig: None,
+                code: code.to_vec(),
+                data_sections: Vec::new(),
+                source: source.clone(),
+            });
+        }
+
+        if format != MethodBodyFlags::FatFormat {
+            return Err(HaoError::BadImageFormat("Unknown method header format"));
+        }
+
+        let second: u8 = reader.read()?;
+        let flags_and_size = u16::from_le_bytes([first, second]);
+        let flags = MethodBodyFlags::from_bits_retain(flags_and_size & 0x0FFF);
+        // The header size is stored as a count of 4 byte integers.
+        let header_size = ((flags_and_size >> 12) * 4) as u8;
+        if header_size < 12 {
+            return Err(HaoError::BadImageFormat("Fat method header is too small"));
+        }
+
+        let max_stack: u16 = reader.read()?;
+        let code_size: u32 = reader.read()?;
+        let local_var_sig_token: u32 = reader.read()?;
+
+        reader.offset(header_size as usize - 12)?;
+        let local_var_sig = (local_var_sig_token != 0)
+            .then(|| stand_alone_sig_from_token(source, MDToken::from_raw(local_var_sig_token)))
+            .transpose()?;
+
+        let code = reader.read_slice(code_size as usize)?;
+        let data_sections = if flags.contains(MethodBodyFlags::MoreSects) {
+            read_data_sections(reader)?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            flags,
+            header_size,
+            max_stack,
+            local_var_sig_token,
+            local_var_sig,
+            code: code.to_vec(),
+            data_sections,
+            source: source.clone(),
+        })
+    }
+}
+
+// The parts of an image needed to decode method bodies after it has been loaded.
+// Only the sections that hold method bodies are copied. Tokens in a body are resolved
+// against the entries as they were loaded, so edits to a module do not change what
+// an existing body refers to.
+#[derive(Default)]
+pub(crate) struct BodySource {
+    sections: Vec<(u32, u32, Vec<u8>)>,
+    pub(crate) user_strings: Vec<u8>,
+    pub(crate) type_refs: EntList<TypeRef>,
+    pub(crate) type_defs: EntList<TypeDef>,
+    pub(crate) fields: EntList<Field>,
+    pub(crate) methods: EntList<Method>,
+    pub(crate) member_refs: EntList<MemberRef>,
+    pub(crate) type_specs: EntList<TypeSpec>,
+    pub(crate) method_specs: EntList<MethodSpec>,
+    pub(crate) stand_alone_sigs: EntList<StandAloneSig>,
+}
+
+impl BodySource {
+    pub(crate) fn new(metadata: &Metadata, entries: &MaybeUninitEntries) -> Self {
+        let rvas = entries
+            .methods
+            .iter()
+            .filter_map(|method| {
+                let method = method.try_value()?;
+                method.has_body().then_some(method.rva)
+            })
+            .collect::<Vec<_>>();
+
+        let sections = metadata
+            .pe_image
+            .pe
+            .sections
+            .0
+            .iter()
+            .filter(|section| {
+                let start = section.row.virtual_address;
+                let end = start.saturating_add(section.row.virtual_size);
+                rvas.iter().any(|rva| (start..end).contains(rva))
+            })
+            .map(|section| {
+                (
+                    section.row.virtual_address,
+                    section.row.virtual_size,
+                    section.data.to_vec(),
+                )
+            })
+            .collect();
+
+        Self {
+            sections,
+            user_strings: metadata
+                .metadata_streams
+                .user_strings_stream
+                .heap_data
+                .to_vec(),
+            type_refs: entries.type_refs.clone(),
+            type_defs: entries.type_defs.clone(),
+            fields: entries.fields.clone(),
+            methods: entries.methods.clone(),
+            member_refs: entries.member_refs.clone(),
+            type_specs: entries.type_specs.clone(),
+            method_specs: entries.method_specs.clone(),
+            stand_alone_sigs: entries.stand_alone_sigs.clone(),
+        }
+    }
+
+    pub(crate) fn user_strings(&self) -> UserStringsStream<'_> {
+        UserStringsStream {
+            heap_data: &self.user_strings,
+        }
+    }
+
+    fn section_data(&self, rva: u32) -> Option<&[u8]> {
+        self.sections
+            .iter()
+            .find(|(start, size, _)| rva >= *start && rva - start < *size)
+            .and_then(|(start, _, data)| data.get((rva - start) as usize..))
+    }
+}
+
+// impl to stop printing every section and entry on debug print
+impl std::fmt::Debug for BodySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BodySource").finish_non_exhaustive()
+    }
+}
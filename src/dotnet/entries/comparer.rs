@@ -0,0 +1,385 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{
+    resolve::MAX_RESOLVE_DEPTH,
+    signature::{
+        FieldSignature, MethodSignature, ResolutionScope, TypeDefOrRef, TypeSignature, ValueType,
+    },
+    values::AssemblyRef,
+    Entry,
+};
+
+/// Compares signatures structurally, including signatures from different modules.
+///
+/// By default types are the same if they have the same full name.
+/// Generic parameters are compared by their index, as their names depend on the declaration.
+/// The variable arguments of a `vararg` call site are not compared,
+/// so the signature of a call site is the same as the method it calls.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use hao::{Module, dotnet::{
+/// #     entries::{comparer::SigComparer, signature::{TypeDefOrRef, ValueType}, values::TypeDef},
+/// #     md::streams::tables_stream::TypeAttributes,
+/// # }};
+/// let mut module = Module::default();
+/// let is_const = module.add_type(TypeDef::new(TypeAttributes::Public, "System.Runtime.CompilerServices", "IsConst", None));
+///
+/// let int = ValueType::Int32;
+/// let const_int = ValueType::CModOpt(TypeDefOrRef::TypeDef(is_const), Box::new(ValueType::Int32));
+///
+/// assert!(!SigComparer::default().equals(&int, &const_int));
+///
+/// let comparer = SigComparer { ignore_modifiers: true, ..Default::default() };
+/// assert!(comparer.equals(&int, &const_int));
+///
+/// let mut uses = HashMap::new();
+/// *uses.entry(comparer.key(int)).or_insert(0) += 1;
+/// *uses.entry(comparer.key(const_int)).or_insert(0) += 1;
+/// assert_eq!(uses.len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SigComparer {
+    /// Types are the same if they resolve to the same definition, as with [`TypeDefOrRef::resolve`].
+    /// Types that can not be resolved are compared by their full name.
+    pub compare_resolved: bool,
+    /// Types refrenced from other assemblies must also be refrenced from the same assembly.
+    /// A type refrenced from another assembly is never the same as a type that is not,
+    /// such as a type defined in the module.
+    ///
+    /// ```
+    /// # use hao::{Module, dotnet::{
+    /// #     entries::{comparer::SigComparer, signature::{ResolutionScope, TypeDefOrRef}, values::{AssemblyRef, TypeDef, TypeRef}},
+    /// #     md::streams::tables_stream::{AssemblyFlags, TypeAttributes},
+    /// # }};
+    /// let mut module = Module::default();
+    /// let local = module.add_type(TypeDef::new(TypeAttributes::Public, "Lib", "Widget", None));
+    ///
+    /// let lib = module.add_assembly_ref(AssemblyRef::new("Lib", (1, 0, 0, 0), AssemblyFlags::empty(), Vec::new()));
+    /// let imported = module.add_type_ref(TypeRef::new(ResolutionScope::AssemblyRef(lib), "Lib", "Widget"));
+    ///
+    /// let (local, imported) = (TypeDefOrRef::TypeDef(local), TypeDefOrRef::TypeRef(imported));
+    /// assert!(SigComparer::default().equals(&local, &imported));
+    ///
+    /// let comparer = SigComparer { compare_assemblies: true, ..Default::default() };
+    /// assert!(!comparer.equals(&local, &imported));
+    /// ```
+    pub compare_assemblies: bool,
+    /// Assemblies with different versions are the same.
+    pub ignore_assembly_versions: bool,
+    /// `modreq` and `modopt` custom modifiers are skipped.
+    pub ignore_modifiers: bool,
+}
+
+/// A signature that can be compared with a [`SigComparer`].
+pub trait SigCompare {
+    fn sig_eq(&self, other: &Self, comparer: &SigComparer) -> bool;
+
+    /// Hashes the signature so that signatures that are the same have the same hash.
+    fn sig_hash<H: Hasher>(&self, comparer: &SigComparer, state: &mut H);
+}
+
+impl SigComparer {
+    pub fn equals<T: SigCompare + ?Sized>(&self, a: &T, b: &T) -> bool {
+        a.sig_eq(b, self)
+    }
+
+    pub fn hash<T: SigCompare + ?Sized>(&self, sig: &T) -> u64 {
+        let mut state = DefaultHasher::new();
+        sig.sig_hash(self, &mut state);
+        state.finish()
+    }
+
+    /// Wraps a signature so that it can be used as the key of a `HashMap` or `HashSet`.
+    pub fn key<T: SigCompare>(&self, sig: T) -> SigKey<T> {
+        SigKey {
+            sig,
+            comparer: *self,
+        }
+    }
+
+    fn strip_modifiers<'a>(&self, mut ty: &'a ValueType) -> &'a ValueType {
+        if self.ignore_modifiers {
+            while let ValueType::CModReq(_, inner) | ValueType::CModOpt(_, inner) = ty {
+                ty = inner;
+            }
+        }
+        ty
+    }
+
+    fn type_name_eq(&self, a: &TypeDefOrRef, b: &TypeDefOrRef) -> bool {
+        if type_full_name(a) != type_full_name(b) {
+            return false;
+        }
+        if !self.compare_assemblies {
+            return true;
+        }
+        match (assembly_of(a), assembly_of(b)) {
+            (Some(a), Some(b)) => self.assembly_eq(&a.value(), &b.value()),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn assembly_eq(&self, a: &AssemblyRef, b: &AssemblyRef) -> bool {
+        a.name().eq_ignore_ascii_case(b.name())
+            && a.locale().eq_ignore_ascii_case(b.locale())
+            && a.public_key_token() == b.public_key_token()
+            && (self.ignore_assembly_versions || a.version() == b.version())
+    }
+}
+
+fn type_full_name(ty: &TypeDefOrRef) -> String {
+    match ty {
+        TypeDefOrRef::TypeDef(ty) => ty.value().full_name(),
+        TypeDefOrRef::TypeRef(ty) => ty.value().full_name(),
+        TypeDefOrRef::TypeSpec(ty) => ty.value().signature().to_string(),
+    }
+}
+
+// The assembly a type refrence is scoped to, following nested types to their declaring type.
+// Declaring types that loop back on themselves are not scoped to an assembly.
+fn assembly_of(ty: &TypeDefOrRef) -> Option<Entry<AssemblyRef>> {
+    let TypeDefOrRef::TypeRef(ty) = ty else {
+        return None;
+    };
+    let mut ty = ty.clone();
+    for _ in 0..MAX_RESOLVE_DEPTH {
+        let declaring = match ty.value().resolution_scope() {
+            ResolutionScope::TypeRef(declaring) => declaring.clone(),
+            ResolutionScope::AssemblyRef(assembly) => return Some(assembly.clone()),
+            _ => return None,
+        };
+        ty = declaring;
+    }
+    None
+}
+
+fn slice_eq<T: SigCompare>(a: &[T], b: &[T], comparer: &SigComparer) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.sig_eq(b, comparer))
+}
+
+fn slice_hash<T: SigCompare, H: Hasher>(sigs: &[T], comparer: &SigComparer, state: &mut H) {
+    sigs.len().hash(state);
+    for sig in sigs {
+        sig.sig_hash(comparer, state);
+    }
+}
+
+impl SigCompare for TypeDefOrRef {
+    fn sig_eq(&self, other: &Self, comparer: &SigComparer) -> bool {
+        match (self, other) {
+            (Self::TypeSpec(a), Self::TypeSpec(b)) => a
+                .value()
+                .signature()
+                .sig_eq(b.value().signature(), comparer),
+            (Self::TypeSpec(_), _) | (_, Self::TypeSpec(_)) => false,
+            (a, b) if comparer.compare_resolved => match (a.resolve(), b.resolve()) {
+                (Ok(a), Ok(b)) => a.0.ptr_eq(&b.0),
+                _ => comparer.type_name_eq(a, b),
+            },
+            (a, b) => comparer.type_name_eq(a, b),
+        }
+    }
+
+    // Types that are the same always have the same full name,
+    // as refrences are resolved by the name of the type.
+    fn sig_hash<H: Hasher>(&self, comparer: &SigComparer, state: &mut H) {
+        match self {
+            Self::TypeSpec(ty) => ty.value().signature().sig_hash(comparer, state),
+            ty => type_full_name(ty).hash(state),
+        }
+    }
+}
+
+impl SigCompare for ValueType {
+    fn sig_eq(&self, other: &Self, comparer: &SigComparer) -> bool {
+        use ValueType as V;
+        match (
+            comparer.strip_modifiers(self),
+            comparer.strip_modifiers(other),
+        ) {
+            (V::Ptr(a), V::Ptr(b))
+            | (V::ByRef(a), V::ByRef(b))
+            | (V::SZArray(a), V::SZArray(b))
+            | (V::Pinned(a), V::Pinned(b)) => a.sig_eq(b, comparer),
+            (V::ValueType(a), V::ValueType(b)) | (V::Class(a), V::Class(b)) => {
+                a.sig_eq(b, comparer)
+            }
+            (V::FnPtr(a), V::FnPtr(b)) => a.sig_eq(b, comparer),
+            (V::CModReq(a_mod, a), V::CModReq(b_mod, b))
+            | (V::CModOpt(a_mod, a), V::CModOpt(b_mod, b)) => {
+                a_mod.sig_eq(b_mod, comparer) && a.sig_eq(b, comparer)
+            }
+            (
+                V::Var {
+                    generic_param_index: a,
+                    ..
+                },
+                V::Var {
+                    generic_param_index: b,
+                    ..
+                },
+            )
+            | (
+                V::MVar {
+                    generic_param_index: a,
+                    ..
+                },
+                V::MVar {
+                    generic_param_index: b,
+                    ..
+                },
+            ) => a == b,
+            (V::ValueArray { len: a_len, ty: a }, V::ValueArray { len: b_len, ty: b }) => {
+                a_len == b_len && a.sig_eq(b, comparer)
+            }
+            (V::Module(a_index, a), V::Module(b_index, b)) => {
+                a_index == b_index && a.sig_eq(b, comparer)
+            }
+            (
+                V::GenericInst {
+                    ty: a,
+                    generic_args: a_args,
+                },
+                V::GenericInst {
+                    ty: b,
+                    generic_args: b_args,
+                },
+            ) => a.sig_eq(b, comparer) && slice_eq(a_args, b_args, comparer),
+            (
+                V::Array {
+                    ty: a,
+                    size: a_size,
+                },
+                V::Array {
+                    ty: b,
+                    size: b_size,
+                },
+            ) => a_size == b_size && a.sig_eq(b, comparer),
+            // Every other pair of the same kind has no values to compare.
+            (a, b) => std::mem::discriminant(a) == std::mem::discriminant(b),
+        }
+    }
+
+    fn sig_hash<H: Hasher>(&self, comparer: &SigComparer, state: &mut H) {
+        use ValueType as V;
+        let ty = comparer.strip_modifiers(self);
+        std::mem::discriminant(ty).hash(state);
+        match ty {
+            V::Ptr(ty) | V::ByRef(ty) | V::SZArray(ty) | V::Pinned(ty) => {
+                ty.sig_hash(comparer, state)
+            }
+            V::ValueType(ty) | V::Class(ty) => ty.sig_hash(comparer, state),
+            V::FnPtr(method) => method.sig_hash(comparer, state),
+            V::CModReq(modifier, ty) | V::CModOpt(modifier, ty) => {
+                modifier.sig_hash(comparer, state);
+                ty.sig_hash(comparer, state);
+            }
+            V::Var {
+                generic_param_index,
+                ..
+            }
+            | V::MVar {
+                generic_param_index,
+                ..
+            } => generic_param_index.hash(state),
+            V::ValueArray { len, ty } => {
+                len.hash(state);
+                ty.sig_hash(comparer, state);
+            }
+            V::Module(index, ty) => {
+                index.hash(state);
+                ty.sig_hash(comparer, state);
+            }
+            V::GenericInst { ty, generic_args } => {
+                ty.sig_hash(comparer, state);
+                slice_hash(generic_args, comparer, state);
+            }
+            V::Array { ty, size } => {
+                ty.sig_hash(comparer, state);
+                size.hash(state);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl SigCompare for TypeSignature {
+    fn sig_eq(&self, other: &Self, comparer: &SigComparer) -> bool {
+        match (self, other) {
+            (Self::Other(a), Self::Other(b)) => a.sig_eq(b, comparer),
+            (a, b) => a.to_value_type().sig_eq(&b.to_value_type(), comparer),
+        }
+    }
+
+    fn sig_hash<H: Hasher>(&self, comparer: &SigComparer, state: &mut H) {
+        match self {
+            Self::Other(ty) => ty.sig_hash(comparer, state),
+            sig => sig.to_value_type().sig_hash(comparer, state),
+        }
+    }
+}
+
+impl SigCompare for MethodSignature {
+    fn sig_eq(&self, other: &Self, comparer: &SigComparer) -> bool {
+        self.has_this == other.has_this
+            && self.explicit_this == other.explicit_this
+            && self.calling_convention == other.calling_convention
+            && self.generic_params == other.generic_params
+            && self.return_type.sig_eq(&other.return_type, comparer)
+            && slice_eq(&self.parameters, &other.parameters, comparer)
+    }
+
+    fn sig_hash<H: Hasher>(&self, comparer: &SigComparer, state: &mut H) {
+        self.has_this.hash(state);
+        self.explicit_this.hash(state);
+        self.calling_convention.hash(state);
+        self.generic_params.hash(state);
+        self.return_type.sig_hash(comparer, state);
+        slice_hash(&self.parameters, comparer, state);
+    }
+}
+
+impl SigCompare for FieldSignature {
+    fn sig_eq(&self, other: &Self, comparer: &SigComparer) -> bool {
+        self.0.sig_eq(&other.0, comparer)
+    }
+
+    fn sig_hash<H: Hasher>(&self, comparer: &SigComparer, state: &mut H) {
+        self.0.sig_hash(comparer, state);
+    }
+}
+
+/// A signature that is compared and hashed with a [`SigComparer`]. See [`SigComparer::key`].
+#[derive(Debug, Clone)]
+pub struct SigKey<T> {
+    sig: T,
+    comparer: SigComparer,
+}
+
+impl<T> SigKey<T> {
+    pub fn sig(&self) -> &T {
+        &self.sig
+    }
+    pub fn into_inner(self) -> T {
+        self.sig
+    }
+}
+
+impl<T: SigCompare> PartialEq for SigKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.sig.sig_eq(&other.sig, &self.comparer)
+    }
+}
+
+impl<T: SigCompare> Eq for SigKey<T> {}
+
+impl<T: SigCompare> Hash for SigKey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sig.sig_hash(&self.comparer, state);
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::Display;
+
+use num_traits::FromPrimitive;
+
+use crate::{
+    dotnet::md::streams::ElementType,
+    error::{HaoError, Result},
+    io::{DataReader, ReadData},
+};
+
+/// The compile time value of a field, parameter or property
+/// from the `Constant` table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    Boolean(bool),
+    /// A UTF-16 code unit.
+    Char(u16),
+    SByte(i8),
+    Byte(u8),
+    Int16(i16),
+    UInt16(u16),
+    Int32(i32),
+    UInt32(u32),
+    Int64(i64),
+    UInt64(u64),
+    Single(f32),
+    Double(f64),
+    String(String),
+    /// A `null` refrence type.
+    Null,
+}
+
+impl ConstantValue {
+    /// Decodes the blob of a constant with the given element type.
+    pub(crate) fn from_blob(element_type: u8, blob: &[u8]) -> Result<Self> {
+        let mut reader = DataReader::new(blob);
+        let element_type = ElementType::from_u8(element_type)
+            .ok_or(HaoError::BadImageFormat("Invalid constant element type"))?;
+
+        let value = match element_type {
+            ElementType::Boolean => Self::Boolean(ReadData::<u8>::read(&mut reader)? != 0),
+            ElementType::Char => Self::Char(reader.read()?),
+            ElementType::I1 => Self::SByte(ReadData::<u8>::read(&mut reader)? as i8),
+            ElementType::U1 => Self::Byte(reader.read()?),
+            ElementType::I2 => Self::Int16(ReadData::<u16>::read(&mut reader)? as i16),
+            ElementType::U2 => Self::UInt16(reader.read()?),
+            ElementType::I4 => Self::Int32(ReadData::<u32>::read(&mut reader)? as i32),
+            ElementType::U4 => Self::UInt32(reader.read()?),
+            ElementType::I8 => Self::Int64(ReadData::<u64>::read(&mut reader)? as i64),
+            ElementType::U8 => Self::UInt64(reader.read()?),
+            ElementType::R4 => Self::Single(f32::from_bits(reader.read()?)),
+            ElementType::R8 => Self::Double(f64::from_bits(reader.read()?)),
+            ElementType::String => {
+                let units = blob
+                    .chunks_exact(2)
+                    .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                    .collect::<Vec<_>>();
+                Self::String(String::from_utf16_lossy(&units))
+            }
+            ElementType::Class => Self::Null,
+            _ => return Err(HaoError::BadImageFormat("Invalid constant element type")),
+        };
+        Ok(value)
+    }
+
+    /// Encodes the constant into its element type and blob.
+    pub(crate) fn to_blob(&self) -> (u8, Vec<u8>) {
+        let (element_type, blob) = match self {
+            Self::Boolean(v) => (ElementType::Boolean, vec![*v as u8]),
+            Self::Char(v) => (ElementType::Char, v.to_le_bytes().to_vec()),
+            Self::SByte(v) => (ElementType::I1, v.to_le_bytes().to_vec()),
+            Self::Byte(v) => (ElementType::U1, v.to_le_bytes().to_vec()),
+            Self::Int16(v) => (ElementType::I2, v.to_le_bytes().to_vec()),
+            Self::UInt16(v) => (ElementType::U2, v.to_le_bytes().to_vec()),
+            Self::Int32(v) => (ElementType::I4, v.to_le_bytes().to_vec()),
+            Self::UInt32(v) => (ElementType::U4, v.to_le_bytes().to_vec()),
+            Self::Int64(v) => (ElementType::I8, v.to_le_bytes().to_vec()),
+            Self::UInt64(v) => (ElementType::U8, v.to_le_bytes().to_vec()),
+            Self::Single(v) => (ElementType::R4, v.to_le_bytes().to_vec()),
+            Self::Double(v) => (ElementType::R8, v.to_le_bytes().to_vec()),
+            Self::String(v) => (
+                ElementType::String,
+                v.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+            ),
+            // A null refrence is stored as a 4 byte zero class.
+            Self::Null => (ElementType::Class, vec![0; 4]),
+        };
+        (element_type as u8, blob)
+    }
+}
+
+impl Display for ConstantValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Boolean(v) => write!(f, "{}", v),
+            Self::Char(v) => match char::from_u32(*v as u32) {
+                Some(c) => write!(f, "{:?}", c),
+                None => write!(f, "'\\u{:04x}'", v),
+            },
+            Self::SByte(v) => write!(f, "{}", v),
+            Self::Byte(v) => write!(f, "{}", v),
+            Self::Int16(v) => write!(f, "{}", v),
+            Self::UInt16(v) => write!(f, "{}", v),
+            Self::Int32(v) => write!(f, "{}", v),
+            Self::UInt32(v) => write!(f, "{}", v),
+            Self::Int64(v) => write!(f, "{}", v),
+            Self::UInt64(v) => write!(f, "{}", v),
+            Self::Single(v) => write!(f, "{}", v),
+            Self::Double(v) => write!(f, "{}", v),
+            Self::String(v) => write!(f, "{:?}", v),
+            Self::Null => write!(f, "null"),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use super::TableEntry;
+use crate::dotnet::md::MDToken;
 use std::{
     cell::{Ref, RefCell, RefMut},
     fmt::{Debug, Display},
@@ -27,6 +29,14 @@ impl<T: Sized> MaybeUnsetEntry<T> {
         unsafe { value.assume_init().value }
     }
 
+    pub fn into_value(self) -> Option<T> {
+        if self.is_set() {
+            Some(self.assume_init_value())
+        } else {
+            None
+        }
+    }
+
     pub fn is_set(&self) -> bool {
         self.is_set
     }
@@ -95,6 +105,24 @@ impl<T> RowEntry<T> {
     }
 }
 
+impl<T: TableEntry> RowEntry<T> {
+    /// The metadata token of the row the entry was loaded from.
+    ///
+    /// Entries added after the module was loaded have a null token,
+    /// as they are only given a row when the module is written.
+    ///
+    /// ```
+    /// # use hao::{Module, dotnet::{entries::values::TypeDef, md::streams::tables_stream::TypeAttributes}};
+    /// let mut module = Module::default();
+    /// let ty = module.add_type(TypeDef::new(TypeAttributes::Public, "Example", "Program", None));
+    ///
+    /// assert!(ty.token().is_null());
+    /// ```
+    pub fn token(&self) -> MDToken {
+        MDToken::new(T::TABLE, self.row)
+    }
+}
+
 impl<T> Deref for RowEntry<T> {
     type Target = T;
 
@@ -127,11 +155,23 @@ impl<T> Ptr<T> {
         Self(Rc::new(RefCell::new(MaybeUnsetEntry::new_unset())))
     }
 
+    /// Creates an entry that was not loaded from a row.
+    pub fn new(value: T) -> Self {
+        let ptr = Self::new_unset();
+        ptr.set_value(0, value);
+        ptr
+    }
+
     #[inline(always)]
     pub fn is_set(&self) -> bool {
         self.0.borrow().is_set()
     }
 
+    #[inline(always)]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+
     pub fn _is_refrenced(&self) -> bool {
         Rc::strong_count(&self.0) > 1
     }
@@ -143,25 +183,25 @@ impl<T> Ptr<T> {
     }
 
     #[inline(always)]
-    pub fn value(&self) -> Ref<RowEntry<T>> {
+    pub fn value(&self) -> Ref<'_, RowEntry<T>> {
         let r = self.0.borrow();
         Ref::map(r, |x| x.as_ref())
     }
 
     #[inline(always)]
-    pub fn value_mut(&self) -> RefMut<T> {
+    pub fn value_mut(&self) -> RefMut<'_, T> {
         let r = self.0.borrow_mut();
         RefMut::map(r, |x| &mut x.as_mut().value)
     }
 
     #[inline(always)]
-    pub fn try_value(&self) -> Option<Ref<RowEntry<T>>> {
+    pub fn try_value(&self) -> Option<Ref<'_, RowEntry<T>>> {
         let r = self.0.try_borrow();
         r.ok().map(|r| Ref::map(r, |x| x.as_ref()))
     }
 
     #[inline(always)]
-    pub fn try_value_mut(&self) -> Option<RefMut<T>> {
+    pub fn try_value_mut(&self) -> Option<RefMut<'_, T>> {
         let r = self.0.try_borrow_mut();
         r.ok().map(|r| RefMut::map(r, |x| &mut x.as_mut().value))
     }
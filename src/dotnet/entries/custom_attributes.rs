@@ -0,0 +1,540 @@
+use std::fmt::Display;
+
+use super::{
+    resolve::find_forwarded_type,
+    signature::{ResolutionScope, TypeDefOrRef, TypeSignature, ValueType},
+    values::{Assembly, AssemblyRef, TypeDef},
+    EntList, Entry, MaybeUninitEntries, RowEntry,
+};
+use crate::{
+    dotnet::md::streams::{tables_stream::FieldFlags, BlobStream},
+    error::{HaoError, Result},
+    io::{DataReader, ReadData},
+};
+
+/// A decoded argument value of a [`CustomAttribute`](super::values::CustomAttribute).
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomAttributeValue {
+    Boolean(bool),
+    /// A UTF-16 code unit.
+    Char(u16),
+    SByte(i8),
+    Byte(u8),
+    Int16(i16),
+    UInt16(u16),
+    Int32(i32),
+    UInt32(u32),
+    Int64(i64),
+    UInt64(u64),
+    Single(f32),
+    Double(f64),
+    String(Option<String>),
+    /// A `System.Type`, stored as its serialized (possibly assembly qualified) type name.
+    Type(Option<String>),
+    Enum {
+        type_name: String,
+        value: Box<CustomAttributeValue>,
+    },
+    /// A value passed to an `object` parameter.
+    Boxed(Box<CustomAttributeValue>),
+    Array(Option<Vec<CustomAttributeValue>>),
+}
+
+impl Display for CustomAttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Boolean(v) => write!(f, "{}", v),
+            Self::Char(v) => match char::from_u32(*v as u32) {
+                Some(c) => write!(f, "{:?}", c),
+                None => write!(f, "'\\u{:04x}'", v),
+            },
+            Self::SByte(v) => write!(f, "{}", v),
+            Self::Byte(v) => write!(f, "{}", v),
+            Self::Int16(v) => write!(f, "{}", v),
+            Self::UInt16(v) => write!(f, "{}", v),
+            Self::Int32(v) => write!(f, "{}", v),
+            Self::UInt32(v) => write!(f, "{}", v),
+            Self::Int64(v) => write!(f, "{}", v),
+            Self::UInt64(v) => write!(f, "{}", v),
+            Self::Single(v) => write!(f, "{}", v),
+            Self::Double(v) => write!(f, "{}", v),
+            Self::String(Some(v)) => write!(f, "{:?}", v),
+            Self::Type(Some(v)) => write!(f, "typeof({})", v),
+            Self::String(None) | Self::Type(None) | Self::Array(None) => write!(f, "null"),
+            Self::Enum { type_name, value } => write!(f, "({}){}", type_name, value),
+            Self::Boxed(v) => write!(f, "{}", v),
+            Self::Array(Some(values)) => {
+                write!(f, "new[] {{ ")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, " }}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedArgumentKind {
+    Field,
+    Property,
+}
+
+/// A field or property set by a [`CustomAttribute`](super::values::CustomAttribute).
+#[derive(Debug, Clone)]
+pub struct CustomAttributeNamedArg {
+    pub(crate) kind: NamedArgumentKind,
+    pub(crate) name: String,
+    pub(crate) value: CustomAttributeValue,
+}
+
+impl CustomAttributeNamedArg {
+    pub fn kind(&self) -> NamedArgumentKind {
+        self.kind
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn value(&self) -> &CustomAttributeValue {
+        &self.value
+    }
+    pub fn is_field(&self) -> bool {
+        self.kind == NamedArgumentKind::Field
+    }
+    pub fn is_property(&self) -> bool {
+        self.kind == NamedArgumentKind::Property
+    }
+}
+
+impl Display for CustomAttributeNamedArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {}", self.name, self.value)
+    }
+}
+
+// The encoding of a single value in the blob. See ECMA-335 II.23.3.
+#[derive(Debug, Clone)]
+pub(crate) enum ElemType {
+    Boolean,
+    Char,
+    SByte,
+    Byte,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Int64,
+    UInt64,
+    Single,
+    Double,
+    String,
+    Type,
+    Boxed,
+    Enum {
+        type_name: String,
+        underlying: Box<ElemType>,
+    },
+    SZArray(Box<ElemType>),
+}
+
+// The entries that enums in custom attribute blobs are looked up in.
+// Attributes are decoded when they are read, after the assembly refrences
+// of the module may have been loaded.
+#[derive(Debug, Default)]
+pub(crate) struct AttributeSource {
+    pub(crate) type_defs: EntList<TypeDef>,
+    pub(crate) assembly: EntList<Assembly>,
+    pub(crate) assembly_refs: EntList<AssemblyRef>,
+}
+
+impl AttributeSource {
+    pub(crate) fn new(entries: &MaybeUninitEntries) -> Self {
+        Self {
+            type_defs: entries.type_defs.clone(),
+            assembly: entries.assembly.clone(),
+            assembly_refs: entries.assembly_ref.clone(),
+        }
+    }
+
+    // Finds a type from its serialized name, e.g. `Namespace.Outer+Inner, Assembly, Version=1.0.0.0`.
+    // Names without an assembly are looked for in this module, then in every loaded assembly refrence.
+    fn find_type(&self, type_name: &str) -> Result<Option<Entry<TypeDef>>> {
+        let (name, assembly) = split_assembly_name(type_name);
+        let mut names = name.split('+');
+        let top_level = names.next().unwrap_or(name);
+        let (namespace, top_level) = top_level.rsplit_once('.').unwrap_or(("", top_level));
+
+        let is_local = assembly.is_none_or(|assembly| {
+            self.assembly
+                .iter()
+                .any(|a| a.value().name().eq_ignore_ascii_case(assembly))
+        });
+        let mut ty = if is_local {
+            self.type_defs
+                .iter()
+                .find(|t| {
+                    let t = t.value();
+                    t.declaring_type.is_none() && t.full_name_is(namespace, top_level)
+                })
+                .map(|t| Entry(t.clone()))
+        } else {
+            None
+        };
+
+        if ty.is_none() {
+            for assembly_ref in self.assembly_refs.iter() {
+                let assembly_ref = assembly_ref.value();
+                if assembly.is_some_and(|a| !assembly_ref.name().eq_ignore_ascii_case(a)) {
+                    continue;
+                }
+                let Some(module) = &assembly_ref.refrenced_assembly else {
+                    continue;
+                };
+                ty = find_forwarded_type(module, namespace, top_level, 0)?.map(|(ty, _)| ty);
+                if ty.is_some() {
+                    break;
+                }
+            }
+        }
+
+        for nested_name in names {
+            ty = ty.and_then(|t| {
+                t.value()
+                    .nested_types
+                    .iter()
+                    .find(|n| n.value().name() == nested_name)
+                    .map(|n| Entry(n.clone()))
+            });
+        }
+        Ok(ty)
+    }
+}
+
+// Splits an assembly qualified type name into the type name and the assembly name.
+// Commas inside the brackets of generic arguments are part of the type name.
+fn split_assembly_name(type_name: &str) -> (&str, Option<&str>) {
+    let mut depth = 0usize;
+    for (index, c) in type_name.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                let assembly = type_name[index + 1..].split(',').next().unwrap_or("");
+                return (type_name[..index].trim(), Some(assembly.trim()));
+            }
+            _ => {}
+        }
+    }
+    (type_name.trim(), None)
+}
+
+pub(crate) struct CustomAttributeReader<'a> {
+    reader: BlobStream<'a>,
+    source: &'a AttributeSource,
+}
+
+impl<'a> CustomAttributeReader<'a> {
+    const PROLOG: u16 = 0x0001;
+    const NULL_STRING: u8 = 0xFF;
+    const NULL_ARRAY: u32 = 0xFFFF_FFFF;
+
+    const FIELD: u8 = 0x53;
+    const PROPERTY: u8 = 0x54;
+
+    const ELEMENT_TYPE_SZARRAY: u8 = 0x1D;
+    const ELEMENT_TYPE_TYPE: u8 = 0x50;
+    const ELEMENT_TYPE_BOXED: u8 = 0x51;
+    const ELEMENT_TYPE_ENUM: u8 = 0x55;
+
+    pub(crate) fn new(blob: &'a [u8], source: &'a AttributeSource) -> Result<Self> {
+        Ok(Self {
+            reader: BlobStream::from_reader(DataReader::new(blob))?,
+            source,
+        })
+    }
+
+    /// Reads the fixed arguments for the constructor parameters
+    /// followed by the named arguments.
+    pub(crate) fn read_arguments(
+        &mut self,
+        parameters: &[TypeSignature],
+    ) -> Result<(Vec<CustomAttributeValue>, Vec<CustomAttributeNamedArg>)> {
+        // A blob of 0 length has no arguments.
+        if self.reader.reader.remaning() == 0 {
+            return Ok((Vec::new(), Vec::new()));
+        }
+
+        let prolog: u16 = self.reader.read()?;
+        if prolog != Self::PROLOG {
+            return Err(HaoError::BadImageFormat(
+                "Invalid custom attribute blob prolog",
+            ));
+        }
+
+        let mut fixed_args = Vec::with_capacity(parameters.len());
+        for param in parameters {
+            let ty = self.elem_type_from_signature(param)?;
+            fixed_args.push(self.read_value(&ty)?);
+        }
+
+        let num_named: u16 = self.reader.read()?;
+        let mut named_args = Vec::with_capacity(num_named as usize);
+        for _ in 0..num_named {
+            let kind = match ReadData::<u8>::read(&mut self.reader)? {
+                Self::FIELD => NamedArgumentKind::Field,
+                Self::PROPERTY => NamedArgumentKind::Property,
+                _ => {
+                    return Err(HaoError::BadImageFormat(
+                        "Invalid custom attribute named argument kind",
+                    ))
+                }
+            };
+            let ty = self.read_field_or_prop_type()?;
+            let name = self.read_ser_string()?.ok_or(HaoError::BadImageFormat(
+                "Custom attribute named argument has no name",
+            ))?;
+            let value = self.read_value(&ty)?;
+            named_args.push(CustomAttributeNamedArg { kind, name, value });
+        }
+
+        Ok((fixed_args, named_args))
+    }
+
+    fn read_ser_string(&mut self) -> Result<Option<String>> {
+        let first = self.reader.reader.view_slice(1)?[0];
+        if first == Self::NULL_STRING {
+            self.reader.reader.offset(1)?;
+            return Ok(None);
+        }
+
+        let len = self.reader.read_compressed_u32()? as usize;
+        let data = self.reader.reader.read_slice(len)?;
+        std::str::from_utf8(data)
+            .map(|s| Some(s.to_string()))
+            .map_err(|_| HaoError::BadImageFormat("Invalid UTF8 string in custom attribute"))
+    }
+
+    fn read_value(&mut self, ty: &ElemType) -> Result<CustomAttributeValue> {
+        let value = match ty {
+            ElemType::Boolean => {
+                CustomAttributeValue::Boolean(ReadData::<u8>::read(&mut self.reader)? != 0)
+            }
+            ElemType::Char => CustomAttributeValue::Char(self.reader.read()?),
+            ElemType::SByte => {
+                CustomAttributeValue::SByte(ReadData::<u8>::read(&mut self.reader)? as i8)
+            }
+            ElemType::Byte => CustomAttributeValue::Byte(self.reader.read()?),
+            ElemType::Int16 => {
+                CustomAttributeValue::Int16(ReadData::<u16>::read(&mut self.reader)? as i16)
+            }
+            ElemType::UInt16 => CustomAttributeValue::UInt16(self.reader.read()?),
+            ElemType::Int32 => {
+                CustomAttributeValue::Int32(ReadData::<u32>::read(&mut self.reader)? as i32)
+            }
+            ElemType::UInt32 => CustomAttributeValue::UInt32(self.reader.read()?),
+            ElemType::Int64 => {
+                CustomAttributeValue::Int64(ReadData::<u64>::read(&mut self.reader)? as i64)
+            }
+            ElemType::UInt64 => CustomAttributeValue::UInt64(self.reader.read()?),
+            ElemType::Single => CustomAttributeValue::Single(f32::from_bits(self.reader.read()?)),
+            ElemType::Double => CustomAttributeValue::Double(f64::from_bits(self.reader.read()?)),
+            ElemType::String => CustomAttributeValue::String(self.read_ser_string()?),
+            ElemType::Type => CustomAttributeValue::Type(self.read_ser_string()?),
+            ElemType::Boxed => {
+                let ty = self.read_field_or_prop_type()?;
+                CustomAttributeValue::Boxed(Box::new(self.read_value(&ty)?))
+            }
+            ElemType::Enum {
+                type_name,
+                underlying,
+            } => CustomAttributeValue::Enum {
+                type_name: type_name.clone(),
+                value: Box::new(self.read_value(underlying)?),
+            },
+            ElemType::SZArray(element) => {
+                let count: u32 = self.reader.read()?;
+                if count == Self::NULL_ARRAY {
+                    CustomAttributeValue::Array(None)
+                } else {
+                    if count as usize > self.reader.reader.remaning() {
+                        return Err(HaoError::BadImageFormat(
+                            "Custom attribute array is larger than the blob",
+                        ));
+                    }
+                    let mut values = Vec::with_capacity(count as usize);
+                    for _ in 0..count {
+                        values.push(self.read_value(element)?);
+                    }
+                    CustomAttributeValue::Array(Some(values))
+                }
+            }
+        };
+        Ok(value)
+    }
+
+    fn read_field_or_prop_type(&mut self) -> Result<ElemType> {
+        let element_type: u8 = self.reader.read()?;
+        let ty = match element_type {
+            Self::ELEMENT_TYPE_SZARRAY => {
+                ElemType::SZArray(Box::new(self.read_field_or_prop_type()?))
+            }
+            Self::ELEMENT_TYPE_TYPE => ElemType::Type,
+            Self::ELEMENT_TYPE_BOXED => ElemType::Boxed,
+            Self::ELEMENT_TYPE_ENUM => {
+                let type_name = self.read_ser_string()?.ok_or(HaoError::BadImageFormat(
+                    "Custom attribute enum has no type name",
+                ))?;
+                let underlying = self.enum_underlying_type_from_name(&type_name)?;
+                ElemType::Enum {
+                    type_name,
+                    underlying: Box::new(underlying),
+                }
+            }
+            0x02 => ElemType::Boolean,
+            0x03 => ElemType::Char,
+            0x04 => ElemType::SByte,
+            0x05 => ElemType::Byte,
+            0x06 => ElemType::Int16,
+            0x07 => ElemType::UInt16,
+            0x08 => ElemType::Int32,
+            0x09 => ElemType::UInt32,
+            0x0A => ElemType::Int64,
+            0x0B => ElemType::UInt64,
+            0x0C => ElemType::Single,
+            0x0D => ElemType::Double,
+            0x0E => ElemType::String,
+            _ => {
+                return Err(HaoError::BadImageFormat(
+                    "Invalid custom attribute argument type",
+                ))
+            }
+        };
+        Ok(ty)
+    }
+
+    fn elem_type_from_signature(&self, sig: &TypeSignature) -> Result<ElemType> {
+        match sig {
+            TypeSignature::SZArray(ty) => Ok(ElemType::SZArray(Box::new(
+                self.elem_type_from_value_type(ty)?,
+            ))),
+            TypeSignature::Class(ty) | TypeSignature::ValueType(ty) => {
+                self.elem_type_from_type_def_or_ref(ty)
+            }
+            TypeSignature::Other(ty) => self.elem_type_from_value_type(ty),
+            _ => Err(HaoError::BadImageFormat(
+                "Unsupported custom attribute parameter type",
+            )),
+        }
+    }
+
+    fn elem_type_from_value_type(&self, ty: &ValueType) -> Result<ElemType> {
+        let ty = match ty {
+            ValueType::Boolean => ElemType::Boolean,
+            ValueType::Char => ElemType::Char,
+            ValueType::SByte => ElemType::SByte,
+            ValueType::Byte => ElemType::Byte,
+            ValueType::Int16 => ElemType::Int16,
+            ValueType::UInt16 => ElemType::UInt16,
+            ValueType::Int32 => ElemType::Int32,
+            ValueType::UInt32 => ElemType::UInt32,
+            ValueType::Int64 => ElemType::Int64,
+            ValueType::UInt64 => ElemType::UInt64,
+            ValueType::Single => ElemType::Single,
+            ValueType::Double => ElemType::Double,
+            ValueType::String => ElemType::String,
+            ValueType::Object => ElemType::Boxed,
+            ValueType::SZArray(ty) => {
+                ElemType::SZArray(Box::new(self.elem_type_from_value_type(ty)?))
+            }
+            ValueType::Class(ty) | ValueType::ValueType(ty) => {
+                self.elem_type_from_type_def_or_ref(ty)?
+            }
+            _ => {
+                return Err(HaoError::BadImageFormat(
+                    "Unsupported custom attribute parameter type",
+                ))
+            }
+        };
+        Ok(ty)
+    }
+
+    fn elem_type_from_type_def_or_ref(&self, ty: &TypeDefOrRef) -> Result<ElemType> {
+        match ty {
+            TypeDefOrRef::TypeRef(r) => {
+                let r = r.value();
+                if r.full_name_is("System", "Type") {
+                    Ok(ElemType::Type)
+                } else if r.full_name_is("System", "Object") {
+                    Ok(ElemType::Boxed)
+                } else {
+                    // Refrences to types in this module can not be resolved
+                    // without the module, so they are looked up by name.
+                    let definition = match (r.resolve(), r.resolution_scope()) {
+                        (Ok(definition), _) => Some(definition),
+                        (Err(_), ResolutionScope::Module(_) | ResolutionScope::TypeRef(_)) => {
+                            self.source.find_type(&r.reflection_full_name())?
+                        }
+                        (Err(_), _) => None,
+                    };
+                    match definition {
+                        Some(definition) => self.enum_elem_type(&definition.value()),
+                        None => Err(HaoError::UnresolvedEnum(r.reflection_full_name())),
+                    }
+                }
+            }
+            TypeDefOrRef::TypeDef(d) => {
+                let d = d.value();
+                if d.full_name_is("System", "Type") {
+                    Ok(ElemType::Type)
+                } else if d.full_name_is("System", "Object") {
+                    Ok(ElemType::Boxed)
+                } else {
+                    self.enum_elem_type(&d)
+                }
+            }
+            TypeDefOrRef::TypeSpec(_) => Err(HaoError::BadImageFormat(
+                "Unsupported custom attribute parameter type",
+            )),
+        }
+    }
+
+    fn enum_elem_type(&self, ty: &RowEntry<TypeDef>) -> Result<ElemType> {
+        let is_enum = match ty.extends() {
+            Some(TypeDefOrRef::TypeRef(r)) => r.value().full_name_is("System", "Enum"),
+            // Enums in the core library extend the definition of `System.Enum`.
+            Some(TypeDefOrRef::TypeDef(d)) => d.value().full_name_is("System", "Enum"),
+            _ => false,
+        };
+        if !is_enum {
+            return Err(HaoError::BadImageFormat(
+                "Custom attribute parameter is not an enum",
+            ));
+        }
+
+        // The underlying type of an enum is the type of its only instance field, `value__`.
+        let underlying = ty
+            .fields()
+            .values()
+            .find(|f| !f.flags().contains(FieldFlags::Static))
+            .map(|f| self.elem_type_from_value_type(f.signature()))
+            .transpose()?
+            .unwrap_or(ElemType::Int32);
+
+        Ok(ElemType::Enum {
+            type_name: ty.reflection_full_name(),
+            underlying: Box::new(underlying),
+        })
+    }
+
+    // The size of an enum value depends on its underlying type,
+    // so the rest of the blob can not be read if the enum is not found.
+    fn enum_underlying_type_from_name(&self, type_name: &str) -> Result<ElemType> {
+        match self.source.find_type(type_name)? {
+            Some(ty) => self.enum_elem_type(&ty.value()),
+            None => Err(HaoError::UnresolvedEnum(type_name.to_string())),
+        }
+    }
+}
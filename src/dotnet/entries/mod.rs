@@ -1,4 +1,11 @@
+pub mod body;
+pub mod comparer;
+pub mod constant;
 mod containers;
+pub mod custom_attributes;
+mod placeholder;
+pub mod resolve;
+pub(crate) use resolve::{resolve_member_ref, resolve_type_ref};
 pub mod signature;
 pub mod values;
 pub mod well_known;
@@ -6,11 +13,15 @@ use std::cell::{Ref, RefCell, RefMut};
 
 use super::md::streams::tables_stream::metadata::TableLocations;
 use crate::{
-    dotnet::md::streams::tables_stream::{TableLocation, TablesStreamReader},
+    dotnet::md::{
+        streams::tables_stream::{TableId, TableLocation, TablesStreamReader},
+        MDToken,
+    },
     error::Result,
     io::{EntryReader, ReadData},
 };
 pub use containers::*;
+pub(crate) use placeholder::Placeholder;
 use values::*;
 
 #[derive(Copy, Clone, Debug)]
@@ -27,6 +38,7 @@ impl<T> RowRange<T> {
 
 pub trait ReadEntry<T>: Sized {
     type RawRow;
+    #[allow(clippy::wrong_self_convention)]
     fn from_row(
         &self,
         index: usize,
@@ -35,6 +47,14 @@ pub trait ReadEntry<T>: Sized {
     ) -> Result<T>;
 }
 
+/// An entry that is loaded from a row of a metadata table.
+///
+/// The `ENCLog` and `ENCMap` entries are not included,
+/// as their `token` is the token they record.
+pub trait TableEntry {
+    const TABLE: TableId;
+}
+
 pub trait GetEntryField<T> {
     type EntryFieldValue: Clone;
     fn get_entry_field(&self, identifier: T) -> Result<Self::EntryFieldValue>;
@@ -48,19 +68,19 @@ impl<'a, T> EntryView<'a, T> {
         Entry(self.0.clone())
     }
 
-    pub fn value(&self) -> Ref<RowEntry<T>> {
+    pub fn value(&self) -> Ref<'_, RowEntry<T>> {
         self.0.value()
     }
 
-    pub fn value_mut(&self) -> RefMut<T> {
+    pub fn value_mut(&self) -> RefMut<'_, T> {
         self.0.value_mut()
     }
 
-    pub fn try_value(&self) -> Option<Ref<RowEntry<T>>> {
+    pub fn try_value(&self) -> Option<Ref<'_, RowEntry<T>>> {
         self.0.try_value()
     }
 
-    pub fn try_value_mut(&self) -> Option<RefMut<T>> {
+    pub fn try_value_mut(&self) -> Option<RefMut<'_, T>> {
         self.0.try_value_mut()
     }
 
@@ -69,6 +89,13 @@ impl<'a, T> EntryView<'a, T> {
     }
 }
 
+impl<'a, T: TableEntry> EntryView<'a, T> {
+    /// See [`RowEntry::token`].
+    pub fn token(&self) -> MDToken {
+        self.value().token()
+    }
+}
+
 /// Represents an owned copy of an entry.
 ///
 /// Internally, this is a [`std::rc::Rc`]. The strong
@@ -78,13 +105,13 @@ impl<'a, T> EntryView<'a, T> {
 #[derive(Debug, Clone)]
 pub struct Entry<T>(pub(crate) Ptr<T>);
 
-impl<'a, T> Entry<T> {
+impl<T> Entry<T> {
     /// Borrows the entry for viewing its values.
     ///
     /// This has the same semantics as borrowing
     /// from [`std::cell::RefCell::borrow()`] for this entry.
     #[inline(always)]
-    pub fn value(&self) -> Ref<RowEntry<T>> {
+    pub fn value(&self) -> Ref<'_, RowEntry<T>> {
         self.0.value()
     }
 
@@ -93,17 +120,17 @@ impl<'a, T> Entry<T> {
     /// This has the same semantics as borrowing
     /// from (RefCell::borrow_mut)[`std::cell::RefCell::borrow_mut`] for this entry.
     #[inline(always)]
-    pub fn value_mut(&self) -> RefMut<T> {
+    pub fn value_mut(&self) -> RefMut<'_, T> {
         self.0.value_mut()
     }
 
     #[inline(always)]
-    pub fn try_value(&self) -> Option<Ref<RowEntry<T>>> {
+    pub fn try_value(&self) -> Option<Ref<'_, RowEntry<T>>> {
         self.0.try_value()
     }
 
     #[inline(always)]
-    pub fn try_value_mut(&self) -> Option<RefMut<T>> {
+    pub fn try_value_mut(&self) -> Option<RefMut<'_, T>> {
         self.0.try_value_mut()
     }
 
@@ -113,6 +140,14 @@ impl<'a, T> Entry<T> {
     }
 }
 
+impl<T: TableEntry> Entry<T> {
+    /// See [`RowEntry::token`].
+    #[inline(always)]
+    pub fn token(&self) -> MDToken {
+        self.value().token()
+    }
+}
+
 pub struct EntryCollection<'a, T> {
     rows: &'a [Ptr<T>],
     position: usize,
@@ -223,11 +258,47 @@ pub(crate) struct MaybeUninitEntries {
     pub methods: EntList<Method>,
     pub params: EntList<Param>,
     pub interface_impls: Vec<RefCell<MaybeUnsetEntry<InterfaceImpl>>>,
+    pub nested_classes: Vec<RefCell<MaybeUnsetEntry<NestedClassRow>>>,
+    pub member_refs: EntList<MemberRef>,
+    pub method_specs: EntList<MethodSpec>,
 
     pub module_ref: EntList<ModuleRef>,
     pub type_specs: EntList<TypeSpec>,
+    pub stand_alone_sigs: EntList<StandAloneSig>,
 
+    pub assembly: EntList<Assembly>,
     pub assembly_ref: EntList<AssemblyRef>,
+    pub files: EntList<FileDef>,
+    pub manifest_resources: EntList<ManifestResource>,
+    pub exported_types: EntList<ExportedType>,
+
+    pub class_layouts: Vec<RefCell<MaybeUnsetEntry<ClassLayoutRow>>>,
+    pub field_layouts: Vec<RefCell<MaybeUnsetEntry<FieldLayoutRow>>>,
+    pub field_rvas: Vec<RefCell<MaybeUnsetEntry<FieldRvaRow>>>,
+    pub field_marshals: Vec<RefCell<MaybeUnsetEntry<FieldMarshalRow>>>,
+    pub impl_maps: Vec<RefCell<MaybeUnsetEntry<ImplMapRow>>>,
+    pub method_impls: Vec<RefCell<MaybeUnsetEntry<MethodImplRow>>>,
+
+    pub properties: EntList<Property>,
+    pub property_maps: Vec<RefCell<MaybeUnsetEntry<PropertyMapRow>>>,
+    pub events: EntList<Event>,
+    pub event_maps: Vec<RefCell<MaybeUnsetEntry<EventMapRow>>>,
+    pub method_semantics: Vec<RefCell<MaybeUnsetEntry<MethodSemanticsRow>>>,
+
+    pub generic_params: EntList<GenericParam>,
+    pub generic_param_constraints: Vec<RefCell<MaybeUnsetEntry<GenericParamConstraintRow>>>,
+
+    pub constants: Vec<RefCell<MaybeUnsetEntry<ConstantRow>>>,
+
+    pub custom_attributes: Vec<RefCell<MaybeUnsetEntry<CustomAttributeRow>>>,
+
+    pub field_ptrs: EntList<PointerRow<Field>>,
+    pub method_ptrs: EntList<PointerRow<Method>>,
+    pub param_ptrs: EntList<PointerRow<Param>>,
+    pub event_ptrs: EntList<PointerRow<Event>>,
+    pub property_ptrs: EntList<PointerRow<Property>>,
+    pub enc_log: EntList<EncLog>,
+    pub enc_map: EntList<EncMap>,
 }
 
 impl MaybeUninitEntries {
@@ -254,29 +325,71 @@ impl MaybeUninitEntries {
             methods: init_ent_list(locations.method),
             params: init_ent_list(locations.param),
             interface_impls: init_metadata_list(locations.interface_impl),
+            nested_classes: init_metadata_list(locations.nested_class),
+            member_refs: init_ent_list(locations.member_ref),
+            method_specs: init_ent_list(locations.method_spec),
 
             module_ref: init_ent_list(locations.module_ref),
             type_specs: init_ent_list(locations.type_spec),
+            stand_alone_sigs: init_ent_list(locations.stand_alone_sig),
 
+            assembly: init_ent_list(locations.assembly),
             assembly_ref: init_ent_list(locations.assembly_ref),
+            files: init_ent_list(locations.file),
+            manifest_resources: init_ent_list(locations.manifest_resource),
+            exported_types: init_ent_list(locations.exported_type),
+
+            class_layouts: init_metadata_list(locations.class_layout),
+            field_layouts: init_metadata_list(locations.field_layout),
+            field_rvas: init_metadata_list(locations.field_rva),
+            field_marshals: init_metadata_list(locations.field_marshal),
+            impl_maps: init_metadata_list(locations.impl_map),
+            method_impls: init_metadata_list(locations.method_impl),
+
+            properties: init_ent_list(locations.property),
+            property_maps: init_metadata_list(locations.property_map),
+            events: init_ent_list(locations.event),
+            event_maps: init_metadata_list(locations.event_map),
+            method_semantics: init_metadata_list(locations.method_semantics),
+
+            generic_params: init_ent_list(locations.generic_param),
+            generic_param_constraints: init_metadata_list(locations.generic_param_constraint),
+
+            constants: init_metadata_list(locations.constant),
+
+            custom_attributes: init_metadata_list(locations.custom_attribute),
+
+            field_ptrs: init_ent_list(locations.field_ptr),
+            method_ptrs: init_ent_list(locations.method_ptr),
+            param_ptrs: init_ent_list(locations.param_ptr),
+            event_ptrs: init_ent_list(locations.event_ptr),
+            property_ptrs: init_ent_list(locations.property_ptr),
+            enc_log: init_ent_list(locations.enc_log),
+            enc_map: init_ent_list(locations.enc_map),
         }
     }
 
     pub fn init_rows(&self, locations: &TableLocations, reader: &EntryReader) -> Result<()> {
-        fn write_ent<V>(entry: &Ptr<V>, value: V, index: usize) {
+        // A row that failed to load is replaced with a placeholder when loading leniently,
+        // so that anything refrencing it can still be loaded.
+        fn write_ent<V: Placeholder>(entry: &Ptr<V>, value: Option<V>, index: usize) {
             let row = (index + 1) as u32; // // 0 = none. Row id's start at 1.
-            entry.set_value(row, value);
+            entry.set_value(row, value.unwrap_or_else(V::placeholder));
         }
-        fn write_metadata<V>(entry: &RefCell<MaybeUnsetEntry<V>>, value: V, index: usize) {
+        // Metadata rows that failed to load are left unset and skipped.
+        fn write_metadata<V>(entry: &RefCell<MaybeUnsetEntry<V>>, value: Option<V>, index: usize) {
             let row = (index + 1) as u32; // // 0 = none. Row id's start at 1.
-            entry.borrow_mut().set_value(row, value);
+            if let Some(value) = value {
+                entry.borrow_mut().set_value(row, value);
+            }
         }
 
         fn init_ent_list<'a, T, V>(
-            uninit_rows: &Vec<T>,
+            table: &'static str,
+            uninit_rows: &[T],
             location: &TableLocation,
             reader: &EntryReader<'a>,
-            write_value: fn(entry: &T, V, usize),
+            write_value: fn(entry: &T, Option<V>, usize),
         ) -> Result<()>
         where
             EntryReader<'a>: ReadEntry<V>,
@@ -297,7 +410,14 @@ impl MaybeUninitEntries {
                     _ => None,
                 };
 
-                let val = reader.from_row(index, &row, next)?;
+                reader.set_current_row(table, (index + 1) as u32);
+                let val = match reader.from_row(index, &row, next) {
+                    Ok(val) => Some(val),
+                    Err(error) => {
+                        reader.report(None, error)?;
+                        None
+                    }
+                };
                 let entry = &uninit_rows[index];
                 write_value(entry, val, index);
             }
@@ -305,27 +425,273 @@ impl MaybeUninitEntries {
             Ok(())
         }
 
-        init_ent_list(&self.modules, &locations.module, reader, write_ent)?;
-        init_ent_list(&self.type_refs, &locations.type_ref, reader, write_ent)?;
-        init_ent_list(&self.type_defs, &locations.type_def, reader, write_ent)?;
-        init_ent_list(&self.fields, &locations.field, reader, write_ent)?;
-        init_ent_list(&self.methods, &locations.method, reader, write_ent)?;
-        init_ent_list(&self.params, &locations.param, reader, write_ent)?;
+        // The indirection tables are only present in uncompressed (#-) metadata.
+        // They need to be read before any member lists are resolved through them.
+        init_ent_list(
+            "FieldPtr",
+            &self.field_ptrs,
+            &locations.field_ptr,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "MethodPtr",
+            &self.method_ptrs,
+            &locations.method_ptr,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "ParamPtr",
+            &self.param_ptrs,
+            &locations.param_ptr,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "EventPtr",
+            &self.event_ptrs,
+            &locations.event_ptr,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "PropertyPtr",
+            &self.property_ptrs,
+            &locations.property_ptr,
+            reader,
+            write_ent,
+        )?;
+
+        init_ent_list(
+            "Module",
+            &self.modules,
+            &locations.module,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "TypeRef",
+            &self.type_refs,
+            &locations.type_ref,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "TypeDef",
+            &self.type_defs,
+            &locations.type_def,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list("Field", &self.fields, &locations.field, reader, write_ent)?;
+        init_ent_list(
+            "Method",
+            &self.methods,
+            &locations.method,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list("Param", &self.params, &locations.param, reader, write_ent)?;
         init_ent_list(
+            "InterfaceImpl",
             &self.interface_impls,
             &locations.interface_impl,
             reader,
             write_metadata,
         )?;
-        init_ent_list(&self.module_ref, &locations.module_ref, reader, write_ent)?;
-        init_ent_list(&self.type_specs, &locations.type_spec, reader, write_ent)?;
+        init_ent_list(
+            "NestedClass",
+            &self.nested_classes,
+            &locations.nested_class,
+            reader,
+            write_metadata,
+        )?;
+        init_ent_list(
+            "MemberRef",
+            &self.member_refs,
+            &locations.member_ref,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "MethodSpec",
+            &self.method_specs,
+            &locations.method_spec,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "ModuleRef",
+            &self.module_ref,
+            &locations.module_ref,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "TypeSpec",
+            &self.type_specs,
+            &locations.type_spec,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "StandAloneSig",
+            &self.stand_alone_sigs,
+            &locations.stand_alone_sig,
+            reader,
+            write_ent,
+        )?;
 
         init_ent_list(
+            "Assembly",
+            &self.assembly,
+            &locations.assembly,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "AssemblyRef",
             &self.assembly_ref,
             &locations.assembly_ref,
             reader,
             write_ent,
         )?;
+        init_ent_list("File", &self.files, &locations.file, reader, write_ent)?;
+        init_ent_list(
+            "ManifestResource",
+            &self.manifest_resources,
+            &locations.manifest_resource,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "ExportedType",
+            &self.exported_types,
+            &locations.exported_type,
+            reader,
+            write_ent,
+        )?;
+
+        init_ent_list(
+            "ClassLayout",
+            &self.class_layouts,
+            &locations.class_layout,
+            reader,
+            write_metadata,
+        )?;
+        init_ent_list(
+            "FieldLayout",
+            &self.field_layouts,
+            &locations.field_layout,
+            reader,
+            write_metadata,
+        )?;
+        init_ent_list(
+            "FieldRVA",
+            &self.field_rvas,
+            &locations.field_rva,
+            reader,
+            write_metadata,
+        )?;
+        init_ent_list(
+            "FieldMarshal",
+            &self.field_marshals,
+            &locations.field_marshal,
+            reader,
+            write_metadata,
+        )?;
+        init_ent_list(
+            "ImplMap",
+            &self.impl_maps,
+            &locations.impl_map,
+            reader,
+            write_metadata,
+        )?;
+        init_ent_list(
+            "MethodImpl",
+            &self.method_impls,
+            &locations.method_impl,
+            reader,
+            write_metadata,
+        )?;
+
+        init_ent_list(
+            "Property",
+            &self.properties,
+            &locations.property,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "PropertyMap",
+            &self.property_maps,
+            &locations.property_map,
+            reader,
+            write_metadata,
+        )?;
+        init_ent_list("Event", &self.events, &locations.event, reader, write_ent)?;
+        init_ent_list(
+            "EventMap",
+            &self.event_maps,
+            &locations.event_map,
+            reader,
+            write_metadata,
+        )?;
+        init_ent_list(
+            "MethodSemantics",
+            &self.method_semantics,
+            &locations.method_semantics,
+            reader,
+            write_metadata,
+        )?;
+
+        init_ent_list(
+            "GenericParam",
+            &self.generic_params,
+            &locations.generic_param,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "GenericParamConstraint",
+            &self.generic_param_constraints,
+            &locations.generic_param_constraint,
+            reader,
+            write_metadata,
+        )?;
+
+        init_ent_list(
+            "Constant",
+            &self.constants,
+            &locations.constant,
+            reader,
+            write_metadata,
+        )?;
+
+        init_ent_list(
+            "ENCLog",
+            &self.enc_log,
+            &locations.enc_log,
+            reader,
+            write_ent,
+        )?;
+        init_ent_list(
+            "ENCMap",
+            &self.enc_map,
+            &locations.enc_map,
+            reader,
+            write_ent,
+        )?;
+
+        // Needs to be read last. See `ReadEntry<CustomAttributeRow>`.
+        init_ent_list(
+            "CustomAttribute",
+            &self.custom_attributes,
+            &locations.custom_attribute,
+            reader,
+            write_metadata,
+        )?;
         Ok(())
     }
 }
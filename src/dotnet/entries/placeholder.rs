@@ -0,0 +1,368 @@
+use std::rc::Weak;
+
+use super::{
+    signature::{
+        CallingConvention, FieldSignature, Implementation, LocalVarSignature, MemberRefParent,
+        MemberRefSignature, MethodDefOrRef, MethodSignature, MethodSpecSignature,
+        PropertySignature, ResolutionScope, StandAloneSignature, TypeDefOrRef, TypeOrMethodDef,
+        TypeSignature, ValueType,
+    },
+    values::*,
+    Entry, Ptr,
+};
+use crate::dotnet::md::{
+    streams::tables_stream::{
+        AssemblyFlags, EventFlags, FieldFlags, FileFlags, GenericParamFlags, ManifestResourceFlags,
+        MethodFlags, MethodImplFlags, MethodSemanticsFlags, ParamFlags, PropertyFlags,
+        TypeAttributes,
+    },
+    MDToken,
+};
+
+/// A stand-in for a value that failed to load when loading leniently.
+///
+/// Placeholders have empty names and flags, so that they can be told apart
+/// from the entries that did load.
+pub(crate) trait Placeholder {
+    fn placeholder() -> Self;
+}
+
+// A placeholder entry that is not part of any table (row id 0).
+// Used where a placeholder needs to refrence an entry that can not be null.
+fn detached<T: Placeholder>() -> Entry<T> {
+    let ptr = Ptr::new_unset();
+    ptr.set_value(0, T::placeholder());
+    Entry(ptr)
+}
+
+fn void_type() -> TypeSignature {
+    TypeSignature::Other(ValueType::Void)
+}
+
+impl Placeholder for String {
+    fn placeholder() -> Self {
+        String::new()
+    }
+}
+
+impl<T> Placeholder for Vec<T> {
+    fn placeholder() -> Self {
+        Vec::new()
+    }
+}
+
+impl<T> Placeholder for Option<T> {
+    fn placeholder() -> Self {
+        None
+    }
+}
+
+impl Placeholder for ResolutionScopePtr {
+    fn placeholder() -> Self {
+        Self::None
+    }
+}
+
+impl Placeholder for TypeDefOrRefPtr {
+    fn placeholder() -> Self {
+        Self::None
+    }
+}
+
+impl Placeholder for MemberRefParentPtr {
+    fn placeholder() -> Self {
+        Self::None
+    }
+}
+
+impl Placeholder for MethodDefOrRefPtr {
+    fn placeholder() -> Self {
+        Self::None
+    }
+}
+
+impl Placeholder for TypeOrMethodDefPtr {
+    fn placeholder() -> Self {
+        Self::None
+    }
+}
+
+impl<T: Placeholder> Placeholder for PointerRow<T> {
+    fn placeholder() -> Self {
+        PointerRow(detached::<T>().0)
+    }
+}
+
+impl Placeholder for ModuleDef {
+    fn placeholder() -> Self {
+        Self {
+            generation: 0,
+            name: String::new(),
+            mvid: uuid::Uuid::nil(),
+            enc_id: uuid::Uuid::nil(),
+            enc_base_id: uuid::Uuid::nil(),
+            custom_attributes: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for TypeRef {
+    fn placeholder() -> Self {
+        Self {
+            resolution_scope: ResolutionScope::Module(detached()),
+            name: String::new(),
+            namespace: String::new(),
+        }
+    }
+}
+
+impl Placeholder for TypeDef {
+    fn placeholder() -> Self {
+        Self {
+            flags: TypeAttributes::empty(),
+            name: String::new(),
+            namespace: String::new(),
+            extends: None,
+            field_list: Vec::new(),
+            method_list: Vec::new(),
+            property_list: Vec::new(),
+            event_list: Vec::new(),
+            declaring_type: None,
+            nested_types: Vec::new(),
+            generic_params: Vec::new(),
+            interface_impl: Vec::new(),
+            class_layout: None,
+            method_impls: Vec::new(),
+            custom_attributes: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for Field {
+    fn placeholder() -> Self {
+        Self {
+            flags: FieldFlags::empty(),
+            name: String::new(),
+            signature: FieldSignature(ValueType::Void),
+            constant: None,
+            offset: None,
+            initial_value: None,
+            marshal: None,
+            custom_attributes: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for Method {
+    fn placeholder() -> Self {
+        Self {
+            rva: 0,
+            impl_flags: MethodImplFlags::empty(),
+            flags: MethodFlags::empty(),
+            name: String::new(),
+            signature: MethodSignature {
+                has_this: false,
+                explicit_this: false,
+                calling_convention: CallingConvention::Default,
+                return_type: Box::new(void_type()),
+                generic_params: None,
+                parameters: Vec::new(),
+                params_after_sentinel: None,
+            },
+            param_list: Vec::new(),
+            body_source: Weak::new(),
+            semantics: MethodSemanticsFlags::empty(),
+            generic_params: Vec::new(),
+            pinvoke: None,
+            custom_attributes: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for Param {
+    fn placeholder() -> Self {
+        Self {
+            flags: ParamFlags::empty(),
+            sequence: 0,
+            name: String::new(),
+            default_value: None,
+            marshal: None,
+            custom_attributes: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for MemberRef {
+    fn placeholder() -> Self {
+        Self {
+            parent: MemberRefParent::TypeRef(detached()),
+            name: String::new(),
+            signature: MemberRefSignature::Field(FieldSignature(ValueType::Void)),
+        }
+    }
+}
+
+impl Placeholder for MethodSpec {
+    fn placeholder() -> Self {
+        Self {
+            method: MethodDefOrRef::MemberRef(detached()),
+            instantiation: MethodSpecSignature(Vec::new()),
+        }
+    }
+}
+
+impl Placeholder for ModuleRef {
+    fn placeholder() -> Self {
+        Self {
+            name: String::new(),
+        }
+    }
+}
+
+impl Placeholder for TypeSpec {
+    fn placeholder() -> Self {
+        Self {
+            signature: void_type(),
+        }
+    }
+}
+
+impl Placeholder for StandAloneSig {
+    fn placeholder() -> Self {
+        Self {
+            signature: StandAloneSignature::LocalVar(LocalVarSignature(Vec::new())),
+        }
+    }
+}
+
+impl Placeholder for Assembly {
+    fn placeholder() -> Self {
+        Self {
+            hash_alg_id: 0,
+            major_version: 0,
+            minor_version: 0,
+            build_number: 0,
+            revision_number: 0,
+            flags: AssemblyFlags::empty(),
+            public_key: Vec::new(),
+            name: String::new(),
+            locale: String::new(),
+            custom_attributes: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for AssemblyRef {
+    fn placeholder() -> Self {
+        Self {
+            major_version: 0,
+            minor_version: 0,
+            build_number: 0,
+            revision_number: 0,
+            flags: AssemblyFlags::empty(),
+            public_key_or_token: Vec::new(),
+            name: String::new(),
+            locale: String::new(),
+            hash_value: Vec::new(),
+            refrenced_assembly: None,
+        }
+    }
+}
+
+impl Placeholder for FileDef {
+    fn placeholder() -> Self {
+        Self {
+            flags: FileFlags::empty(),
+            name: String::new(),
+            hash_value: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for ManifestResource {
+    fn placeholder() -> Self {
+        Self {
+            offset: 0,
+            flags: ManifestResourceFlags::empty(),
+            name: String::new(),
+            implementation: None,
+            data: None,
+        }
+    }
+}
+
+impl Placeholder for ExportedType {
+    fn placeholder() -> Self {
+        Self {
+            flags: TypeAttributes::empty(),
+            type_def_id: 0,
+            name: String::new(),
+            namespace: String::new(),
+            implementation: Implementation::File(detached()),
+        }
+    }
+}
+
+impl Placeholder for Property {
+    fn placeholder() -> Self {
+        Self {
+            flags: PropertyFlags::empty(),
+            name: String::new(),
+            signature: PropertySignature {
+                has_this: false,
+                property_type: void_type(),
+                parameters: Vec::new(),
+            },
+            getter: None,
+            setter: None,
+            other_methods: Vec::new(),
+            constant: None,
+            custom_attributes: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for Event {
+    fn placeholder() -> Self {
+        Self {
+            flags: EventFlags::empty(),
+            name: String::new(),
+            event_type: TypeDefOrRef::TypeRef(detached()),
+            adder: None,
+            remover: None,
+            raiser: None,
+            other_methods: Vec::new(),
+            custom_attributes: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for GenericParam {
+    fn placeholder() -> Self {
+        Self {
+            number: 0,
+            flags: GenericParamFlags::empty(),
+            owner: TypeOrMethodDef::TypeDef(detached()),
+            name: String::new(),
+            constraints: Vec::new(),
+            custom_attributes: Vec::new(),
+        }
+    }
+}
+
+impl Placeholder for EncLog {
+    fn placeholder() -> Self {
+        Self {
+            token: MDToken::default(),
+            func_code: 0,
+        }
+    }
+}
+
+impl Placeholder for EncMap {
+    fn placeholder() -> Self {
+        Self {
+            token: MDToken::default(),
+        }
+    }
+}
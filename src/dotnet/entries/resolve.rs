@@ -0,0 +1,304 @@
+use std::rc::Rc;
+
+use super::{
+    comparer::SigComparer,
+    signature::{
+        Implementation, MemberRefParent, MemberRefSignature, ResolutionScope, TypeDefOrRef,
+        TypeSignature, ValueType,
+    },
+    values::{ExportedType, Field, MemberRef, Method, TypeDef, TypeRef},
+    Entry,
+};
+use crate::{
+    error::{HaoError, ResolveError, Result},
+    Module,
+};
+
+// Malformed metadata can make refrences loop back on themselves.
+pub(crate) const MAX_RESOLVE_DEPTH: usize = 64;
+
+/// The definition a [`MemberRef`] refrences. See [`MemberRef::resolve`].
+#[derive(Debug, Clone)]
+pub enum ResolvedMember {
+    Method(Entry<Method>),
+    Field(Entry<Field>),
+}
+
+// A resolved type and the loaded assembly it was found in,
+// or `None` if it was found in the module the lookup started in.
+pub(crate) type FoundType = (Entry<TypeDef>, Option<Rc<Module>>);
+
+// A type looked up in `module` was found there, unless it was forwarded to another assembly.
+fn found_in(module: &Rc<Module>, (ty, forwarded_to): FoundType) -> FoundType {
+    (ty, forwarded_to.or_else(|| Some(module.clone())))
+}
+
+/// Finds the definition of a type refrence by following its resolution scope.
+///
+/// `own_module` is the module the refrence is in, used for refrences scoped to their own module.
+pub(crate) fn resolve_type_ref(
+    type_ref: &TypeRef,
+    own_module: Option<&Module>,
+    depth: usize,
+) -> Result<FoundType> {
+    if depth > MAX_RESOLVE_DEPTH {
+        return Err(HaoError::RecursionLimitReached);
+    }
+
+    match type_ref.resolution_scope() {
+        ResolutionScope::TypeRef(declaring) => {
+            let (declaring, module) = resolve_type_ref(&declaring.value(), own_module, depth + 1)?;
+            let declaring = declaring.value();
+            declaring
+                .nested_types
+                .iter()
+                .find(|t| {
+                    t.value()
+                        .full_name_is(type_ref.namespace(), type_ref.name())
+                })
+                .map(|t| (Entry(t.clone()), module))
+                .ok_or_else(|| {
+                    ResolveError::TypeNotFound(type_ref.full_name(), declaring.full_name()).into()
+                })
+        }
+        ResolutionScope::AssemblyRef(assembly) => {
+            let assembly = assembly.value();
+            let module = assembly
+                .refrenced_assembly
+                .as_ref()
+                .ok_or_else(|| ResolveError::AssemblyNotLoaded(assembly.full_name()))?;
+            find_type(module, type_ref, assembly.name(), depth).map(|found| found_in(module, found))
+        }
+        ResolutionScope::ModuleRef(module) => {
+            Err(ResolveError::ModuleNotLoaded(module.value().name().to_string()).into())
+        }
+        ResolutionScope::Module(_) => match own_module {
+            Some(module) => find_type(module, type_ref, module.module().value().name(), depth),
+            None => Err(ResolveError::OwnModule(type_ref.full_name()).into()),
+        },
+    }
+}
+
+fn find_type(
+    module: &Module,
+    type_ref: &TypeRef,
+    scope_name: &str,
+    depth: usize,
+) -> Result<FoundType> {
+    find_forwarded_type(module, type_ref.namespace(), type_ref.name(), depth)?.ok_or_else(|| {
+        ResolveError::TypeNotFound(type_ref.full_name(), scope_name.to_string()).into()
+    })
+}
+
+// Finds a type that is not nested in `module`,
+// following type forwarders to the assembly that defines it.
+pub(crate) fn find_forwarded_type(
+    module: &Module,
+    namespace: &str,
+    name: &str,
+    depth: usize,
+) -> Result<Option<FoundType>> {
+    if let Some(ty) = module.find_type(namespace, name) {
+        return Ok(Some((ty, None)));
+    }
+    let exported = module.exported_types.iter().find(|t| {
+        let t = t.value();
+        !t.is_nested() && t.full_name_is(namespace, name)
+    });
+    match exported {
+        Some(exported) => resolve_exported_type(&exported.value(), depth + 1).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn resolve_exported_type(exported: &ExportedType, depth: usize) -> Result<FoundType> {
+    if depth > MAX_RESOLVE_DEPTH {
+        return Err(HaoError::RecursionLimitReached);
+    }
+
+    match exported.implementation() {
+        Implementation::AssemblyRef(assembly) => {
+            let assembly = assembly.value();
+            let module = assembly
+                .refrenced_assembly
+                .as_ref()
+                .ok_or_else(|| ResolveError::AssemblyNotLoaded(assembly.full_name()))?;
+            find_forwarded_type(module, exported.namespace(), exported.name(), depth + 1)?
+                .map(|found| found_in(module, found))
+                .ok_or_else(|| {
+                    ResolveError::TypeNotFound(exported.full_name(), assembly.name().to_string())
+                        .into()
+                })
+        }
+        Implementation::File(file) => {
+            Err(ResolveError::ModuleNotLoaded(file.value().name().to_string()).into())
+        }
+        Implementation::ExportedType(declaring) => {
+            let (declaring, module) = resolve_exported_type(&declaring.value(), depth + 1)?;
+            let declaring = declaring.value();
+            declaring
+                .nested_types
+                .iter()
+                .find(|t| {
+                    t.value()
+                        .full_name_is(exported.namespace(), exported.name())
+                })
+                .map(|t| (Entry(t.clone()), module))
+                .ok_or_else(|| {
+                    ResolveError::TypeNotFound(exported.full_name(), declaring.full_name()).into()
+                })
+        }
+    }
+}
+
+pub(crate) fn resolve_type_def_or_ref(
+    ty: &TypeDefOrRef,
+    own_module: Option<&Module>,
+    depth: usize,
+) -> Result<FoundType> {
+    if depth > MAX_RESOLVE_DEPTH {
+        return Err(HaoError::RecursionLimitReached);
+    }
+
+    match ty {
+        TypeDefOrRef::TypeDef(t) => Ok((t.clone(), None)),
+        TypeDefOrRef::TypeRef(r) => resolve_type_ref(&r.value(), own_module, depth + 1),
+        TypeDefOrRef::TypeSpec(s) => {
+            let spec = s.value();
+            let ty = match spec.signature() {
+                TypeSignature::Class(ty) | TypeSignature::ValueType(ty) => ty,
+                TypeSignature::GenericInst {
+                    ty: ValueType::Class(ty) | ValueType::ValueType(ty),
+                    ..
+                } => ty,
+                sig => return Err(ResolveError::NotATypeDef(sig.to_string()).into()),
+            };
+            resolve_type_def_or_ref(ty, own_module, depth + 1)
+        }
+    }
+}
+
+impl TypeRef {
+    /// Finds the definition of the refrenced type.
+    ///
+    /// The assembly the type is in needs to have been loaded, either with
+    /// [`Module::from_path`] or [`Module::load_dependancies`].
+    /// Types forwarded to another assembly are found in the assembly they were forwarded to.
+    /// Refrences to a type in their own module can only be resolved with [`Module::resolve_type_ref`].
+    /// ```no_run
+    /// # use hao::Module;
+    /// let module = Module::from_path(r#"Example.Net.dll"#).unwrap();
+    ///
+    /// for type_ref in module.type_refs().values() {
+    ///     match type_ref.resolve() {
+    ///         Ok(ty) => println!("{} -> {}", type_ref.full_name(), ty.value().full_name()),
+    ///         Err(err) => println!("{} -> {}", type_ref.full_name(), err),
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve(&self) -> Result<Entry<TypeDef>> {
+        resolve_type_ref(self, None, 0).map(|(ty, _)| ty)
+    }
+}
+
+impl TypeDefOrRef {
+    /// Finds the definition of the type.
+    ///
+    /// Type specs are resolved to the definition of the generic type they instantiate.
+    /// See [`TypeRef::resolve`].
+    pub fn resolve(&self) -> Result<Entry<TypeDef>> {
+        resolve_type_def_or_ref(self, None, 0).map(|(ty, _)| ty)
+    }
+}
+
+impl ExportedType {
+    /// Finds the definition of the exported type,
+    /// following type forwarders to the assembly that defines it.
+    /// See [`TypeRef::resolve`].
+    pub fn resolve(&self) -> Result<Entry<TypeDef>> {
+        resolve_exported_type(self, 0).map(|(ty, _)| ty)
+    }
+}
+
+/// Finds the definition of a member refrence in the type its parent resolves to,
+/// or in the base types of that type.
+pub(crate) fn resolve_member_ref(
+    member_ref: &MemberRef,
+    own_module: Option<&Module>,
+) -> Result<ResolvedMember> {
+    let (ty, mut module) = match member_ref.parent() {
+        MemberRefParent::TypeDef(ty) => (ty.clone(), None),
+        MemberRefParent::TypeRef(ty) => resolve_type_ref(&ty.value(), own_module, 0)?,
+        MemberRefParent::TypeSpec(ty) => {
+            resolve_type_def_or_ref(&TypeDefOrRef::TypeSpec(ty.clone()), own_module, 0)?
+        }
+        MemberRefParent::ModuleRef(module) => {
+            return Err(ResolveError::ModuleNotLoaded(module.value().name().to_string()).into())
+        }
+        // The call site of a vararg method refrences the method itself.
+        MemberRefParent::Method(method) => return Ok(ResolvedMember::Method(method.clone())),
+    };
+
+    // Base types are refrenced from the module that defines the type that extends them.
+    let mut declaring = ty.clone();
+    for _ in 0..MAX_RESOLVE_DEPTH {
+        if let Some(member) = find_member(&declaring.value(), member_ref) {
+            return Ok(member);
+        }
+        let Some(base) = declaring.value().extends().clone() else {
+            let type_name = ty.value().full_name();
+            return Err(
+                ResolveError::MemberNotFound(member_ref.name().to_string(), type_name).into(),
+            );
+        };
+        let (base, base_module) =
+            resolve_type_def_or_ref(&base, module.as_deref().or(own_module), 0)?;
+        declaring = base;
+        module = base_module.or(module);
+    }
+    Err(HaoError::RecursionLimitReached)
+}
+
+fn find_member(ty: &TypeDef, member_ref: &MemberRef) -> Option<ResolvedMember> {
+    // Signatures from different modules refrence the same types with different entries.
+    let comparer = SigComparer::default();
+    match member_ref.signature() {
+        MemberRefSignature::Method(sig) => ty
+            .method_list
+            .iter()
+            .find(|m| {
+                let m = m.value();
+                m.name() == member_ref.name() && comparer.equals(m.signature(), sig)
+            })
+            .map(|m| ResolvedMember::Method(Entry(m.clone()))),
+        MemberRefSignature::Field(sig) => ty
+            .field_list
+            .iter()
+            .find(|f| {
+                let f = f.value();
+                f.name() == member_ref.name() && comparer.equals(f.signature(), sig)
+            })
+            .map(|f| ResolvedMember::Field(Entry(f.clone()))),
+    }
+}
+
+impl MemberRef {
+    /// Finds the method or field definition the member refrences.
+    ///
+    /// The declaring type is resolved as with [`TypeRef::resolve`],
+    /// using the generic type of an instantiated parent such as `List<int>`.
+    /// The member is then found by its name and signature in that type, or one of its base types.
+    /// ```no_run
+    /// # use hao::{Module, dotnet::entries::resolve::ResolvedMember};
+    /// let module = Module::from_path(r#"Example.Net.dll"#).unwrap();
+    ///
+    /// for member_ref in module.member_refs().values() {
+    ///     if let Ok(ResolvedMember::Method(method)) = member_ref.resolve() {
+    ///         println!("{} -> {}", member_ref, method.value().name());
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve(&self) -> Result<ResolvedMember> {
+        resolve_member_ref(self, None)
+    }
+}
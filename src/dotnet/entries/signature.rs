@@ -1,15 +1,16 @@
 use std::{cell::Ref, fmt::Display, ops::Deref};
 
-pub use crate::dotnet::md::streams::ArraySize;
+pub use crate::dotnet::md::streams::{ArraySize, CallingConvention};
 use crate::{
-    dotnet::md::streams::{SignatureCallingConvention, SignatureDef, TypeSigDef},
+    dotnet::md::streams::{SignatureCallingConvention, SignatureDef, SignatureFlags, TypeSigDef},
     error::{HaoError, Result},
 };
 
 use super::{
     values::{
-        AssemblyRef, ModuleDef, ModuleRef, ResolutionScopePtr, TypeDef, TypeDefOrRefPtr, TypeRef,
-        TypeSpec,
+        AssemblyRef, ExportedType, FileDef, ImplementationPtr, MemberRef, MemberRefParentPtr,
+        Method, MethodDefOrRefPtr, ModuleDef, ModuleRef, ResolutionScopePtr, TypeDef,
+        TypeDefOrRefPtr, TypeOrMethodDefPtr, TypeRef, TypeSpec,
     },
     Entry, RowEntry,
 };
@@ -49,6 +50,186 @@ impl Display for ResolutionScope {
     }
 }
 
+/// Where the data of a [`ManifestResource`](super::values::ManifestResource)
+/// or the definition of an [`ExportedType`] is found.
+#[derive(Debug, Clone)]
+pub enum Implementation {
+    File(Entry<FileDef>),
+    AssemblyRef(Entry<AssemblyRef>),
+    /// The exported type a nested exported type is declared in.
+    ExportedType(Entry<ExportedType>),
+}
+
+impl Implementation {
+    pub(crate) fn from_ent_pointer(ptr: ImplementationPtr) -> Option<Self> {
+        match ptr {
+            ImplementationPtr::File(d) => Some(Self::File(Entry(d))),
+            ImplementationPtr::AssemblyRef(d) => Some(Self::AssemblyRef(Entry(d))),
+            ImplementationPtr::ExportedType(d) => Some(Self::ExportedType(Entry(d))),
+            ImplementationPtr::None => None,
+        }
+    }
+    pub(crate) fn from_ent_ptr_must(ptr: ImplementationPtr) -> Result<Self> {
+        Self::from_ent_pointer(ptr)
+            .ok_or_else(|| HaoError::InvalidSignatureForEntry(std::any::type_name::<Self>()))
+    }
+}
+
+impl Display for Implementation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(e) => write!(f, "{}", e.value().name),
+            Self::AssemblyRef(e) => write!(f, "{}", e.value().name),
+            Self::ExportedType(e) => write!(f, "{}", e.value().full_name()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum MethodDefOrRef {
+    Method(Entry<Method>),
+    MemberRef(Entry<MemberRef>),
+}
+
+impl MethodDefOrRef {
+    pub(crate) fn from_ent_pointer(ptr: MethodDefOrRefPtr) -> Option<Self> {
+        match ptr {
+            MethodDefOrRefPtr::Method(d) => Some(Self::Method(Entry(d))),
+            MethodDefOrRefPtr::MemberRef(r) => Some(Self::MemberRef(Entry(r))),
+            MethodDefOrRefPtr::None => None,
+        }
+    }
+    pub(crate) fn from_ent_ptr_must(ptr: MethodDefOrRefPtr) -> Result<Self> {
+        Self::from_ent_pointer(ptr)
+            .ok_or_else(|| HaoError::InvalidSignatureForEntry(std::any::type_name::<Self>()))
+    }
+}
+
+// impl to stop infiniate reccursion on debug print
+impl std::fmt::Debug for MethodDefOrRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Method(e) => write!(f, "Self::Method(\"{}\")", e.value().name()),
+            Self::MemberRef(e) => write!(f, "Self::MemberRef(\"{}\")", e.value()),
+        }
+    }
+}
+
+impl Display for MethodDefOrRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Method(e) => write!(f, "{}", e.value().name()),
+            Self::MemberRef(e) => write!(f, "{}", e.value()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum MemberRefParent {
+    TypeDef(Entry<TypeDef>),
+    TypeRef(Entry<TypeRef>),
+    ModuleRef(Entry<ModuleRef>),
+    Method(Entry<Method>),
+    TypeSpec(Entry<TypeSpec>),
+}
+
+impl MemberRefParent {
+    pub(crate) fn from_ent_pointer(ptr: MemberRefParentPtr) -> Option<Self> {
+        match ptr {
+            MemberRefParentPtr::TypeDef(d) => Some(Self::TypeDef(Entry(d))),
+            MemberRefParentPtr::TypeRef(d) => Some(Self::TypeRef(Entry(d))),
+            MemberRefParentPtr::ModuleRef(d) => Some(Self::ModuleRef(Entry(d))),
+            MemberRefParentPtr::Method(d) => Some(Self::Method(Entry(d))),
+            MemberRefParentPtr::TypeSpec(d) => Some(Self::TypeSpec(Entry(d))),
+            MemberRefParentPtr::None => None,
+        }
+    }
+    pub(crate) fn from_ent_ptr_must(ptr: MemberRefParentPtr) -> Result<Self> {
+        Self::from_ent_pointer(ptr)
+            .ok_or_else(|| HaoError::InvalidSignatureForEntry(std::any::type_name::<Self>()))
+    }
+}
+
+// impl to stop infiniate reccursion on debug print
+impl std::fmt::Debug for MemberRefParent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeDef(e) => write!(
+                f,
+                "Self::TypeDef(\"{}.{}\")",
+                e.value().namespace(),
+                e.value().name()
+            ),
+            Self::TypeRef(e) => write!(
+                f,
+                "Self::TypeRef(\"{}.{}\")",
+                e.value().namespace(),
+                e.value().name()
+            ),
+            Self::ModuleRef(e) => write!(f, "Self::ModuleRef(\"{}\")", e.value().name()),
+            Self::Method(e) => write!(f, "Self::Method(\"{}\")", e.value().name()),
+            Self::TypeSpec(s) => write!(f, "{:?}", s.value().signature()),
+        }
+    }
+}
+
+impl Display for MemberRefParent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeDef(e) => write!(f, "{}", e.value().name()),
+            Self::TypeRef(e) => write!(f, "{}", e.value()),
+            Self::ModuleRef(e) => write!(f, "{}", e.value().name()),
+            Self::Method(e) => write!(f, "{}", e.value().name()),
+            Self::TypeSpec(s) => write!(f, "{}", s.value().signature()),
+        }
+    }
+}
+
+/// The owner of a [`GenericParam`](super::values::GenericParam).
+#[derive(Clone)]
+pub enum TypeOrMethodDef {
+    TypeDef(Entry<TypeDef>),
+    Method(Entry<Method>),
+}
+
+impl TypeOrMethodDef {
+    pub(crate) fn from_ent_pointer(ptr: TypeOrMethodDefPtr) -> Option<Self> {
+        match ptr {
+            TypeOrMethodDefPtr::TypeDef(d) => Some(Self::TypeDef(Entry(d))),
+            TypeOrMethodDefPtr::Method(m) => Some(Self::Method(Entry(m))),
+            TypeOrMethodDefPtr::None => None,
+        }
+    }
+    pub(crate) fn from_ent_ptr_must(ptr: TypeOrMethodDefPtr) -> Result<Self> {
+        Self::from_ent_pointer(ptr)
+            .ok_or_else(|| HaoError::InvalidSignatureForEntry(std::any::type_name::<Self>()))
+    }
+}
+
+// impl to stop infiniate reccursion on debug print
+impl std::fmt::Debug for TypeOrMethodDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeDef(e) => write!(
+                f,
+                "Self::TypeDef(\"{}.{}\")",
+                e.value().namespace(),
+                e.value().name()
+            ),
+            Self::Method(e) => write!(f, "Self::Method(\"{}\")", e.value().name()),
+        }
+    }
+}
+
+impl Display for TypeOrMethodDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeDef(e) => write!(f, "{}", e.value().name()),
+            Self::Method(e) => write!(f, "{}", e.value().name()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum TypeDefOrRef {
     TypeDef(Entry<TypeDef>),
@@ -116,6 +297,40 @@ impl Display for TypeDefOrRef {
     }
 }
 
+// The names of the generic parameters in scope of a signature.
+pub(crate) struct GenericNames<'a> {
+    pub type_params: &'a [String],
+    pub method_params: &'a [String],
+}
+
+impl<'a> GenericNames<'a> {
+    fn type_param(&self, index: u32) -> Option<String> {
+        self.type_params.get(index as usize).cloned()
+    }
+
+    fn method_param(&self, index: u32) -> Option<String> {
+        self.method_params.get(index as usize).cloned()
+    }
+}
+
+/// A type in a signature. See ECMA-335 II.23.2.12.
+///
+/// ```
+/// # use hao::dotnet::entries::signature::{CallingConvention, MethodSignature, TypeSignature, ValueType};
+/// let ty = ValueType::ByRef(Box::new(ValueType::Ptr(Box::new(ValueType::Int32))));
+/// assert_eq!(ty.to_string(), "ref int*");
+///
+/// let fn_ptr = ValueType::FnPtr(Box::new(MethodSignature {
+///     has_this: false,
+///     explicit_this: false,
+///     calling_convention: CallingConvention::StdCall,
+///     return_type: Box::new(TypeSignature::Other(ValueType::Void)),
+///     generic_params: None,
+///     parameters: vec![TypeSignature::Other(ValueType::IntPtr)],
+///     params_after_sentinel: None,
+/// }));
+/// assert_eq!(fn_ptr.to_string(), "delegate* unmanaged[Stdcall]<IntPtr, void>");
+/// ```
 #[derive(Debug, Clone)]
 pub enum ValueType {
     Void,
@@ -137,20 +352,35 @@ pub enum ValueType {
     UIntPtr,
     Object,
 
-    //Ptr(Box<SignatureType>),
-    //ByRef(Box<SignatureType>),
+    Ptr(Box<ValueType>),
+    ByRef(Box<ValueType>),
     ValueType(TypeDefOrRef),
     Class(TypeDefOrRef),
-    //FnPtr(Box<SignatureDef>),
+    /// A pointer to a function with the given signature.
+    FnPtr(Box<MethodSignature>),
     SZArray(Box<ValueType>),
-    CModReq(TypeDefOrRef),
-    // CModOpt(TypeDefOrRefSig),
-    //Pinned(Box<SignatureType>),
+    /// A required modifier on the inner type.
+    CModReq(TypeDefOrRef, Box<ValueType>),
+    /// An optional modifier on the inner type.
+    CModOpt(TypeDefOrRef, Box<ValueType>),
+    /// Marks the start of the variable arguments of a `vararg` call site.
+    Sentinel,
+    Pinned(Box<ValueType>),
+    /// A type internal to the runtime. Only found in the runtime's own modules.
+    Internal,
+    /// A generic parameter of the declaring type.
+    ///
+    /// `name` is set when the declaring [`GenericParam`](super::values::GenericParam) is known.
     Var {
         generic_param_index: u32,
+        name: Option<String>,
     },
+    /// A generic parameter of the declaring method.
+    ///
+    /// `name` is set when the declaring [`GenericParam`](super::values::GenericParam) is known.
     MVar {
         generic_param_index: u32,
+        name: Option<String>,
     },
     ValueArray {
         len: u32,
@@ -165,7 +395,6 @@ pub enum ValueType {
         ty: Box<ValueType>,
         size: ArraySize,
     },
-    NotDone(TypeSigDef),
 }
 
 impl ValueType {
@@ -187,16 +416,36 @@ impl ValueType {
             TypeSigDef::String => Self::String,
             TypeSigDef::TypedRefrence => Self::TypedRefrence,
             TypeSigDef::IntPtr => Self::IntPtr,
-            TypeSigDef::UIntPtr => Self::IntPtr,
+            TypeSigDef::UIntPtr => Self::UIntPtr,
             TypeSigDef::Object => Self::Object,
 
+            TypeSigDef::Ptr(ty) => Self::Ptr(Box::new(Self::from_type_sig(*ty)?)),
+            TypeSigDef::ByRef(ty) => Self::ByRef(Box::new(Self::from_type_sig(*ty)?)),
+            TypeSigDef::Pinned(ty) => Self::Pinned(Box::new(Self::from_type_sig(*ty)?)),
             TypeSigDef::ValueType(ptr) => Self::ValueType(TypeDefOrRef::from_ent_ptr_must(ptr.0)?),
             TypeSigDef::SZArray(ty) => Self::SZArray(Box::new(Self::from_type_sig(*ty)?)),
-            TypeSigDef::CModReq(ty) => Self::CModReq(TypeDefOrRef::from_ent_ptr_must(ty.0)?),
+            TypeSigDef::FnPtr(sig) => Self::FnPtr(Box::new(MethodSignature::from_sig_def(*sig)?)),
+            TypeSigDef::CModReq(modifier, ty) => Self::CModReq(
+                TypeDefOrRef::from_ent_ptr_must(modifier.0)?,
+                Box::new(Self::from_type_sig(*ty)?),
+            ),
+            TypeSigDef::CModOpt(modifier, ty) => Self::CModOpt(
+                TypeDefOrRef::from_ent_ptr_must(modifier.0)?,
+                Box::new(Self::from_type_sig(*ty)?),
+            ),
+            TypeSigDef::Sentinel => Self::Sentinel,
+            TypeSigDef::Internal => Self::Internal,
             TypeSigDef::Var {
                 generic_param_index,
             } => Self::Var {
                 generic_param_index,
+                name: None,
+            },
+            TypeSigDef::MVar {
+                generic_param_index,
+            } => Self::MVar {
+                generic_param_index,
+                name: None,
             },
             TypeSigDef::Class(ptr) => Self::Class(TypeDefOrRef::from_ent_ptr_must(ptr.0)?),
             TypeSigDef::ValueArray { len, next_sig: ty } => Self::ValueArray {
@@ -214,11 +463,49 @@ impl ValueType {
                 ty: Box::new(Self::from_type_sig(*ty)?),
                 size,
             },
+            TypeSigDef::Module { index, ty } => {
+                Self::Module(index, Box::new(Self::from_type_sig(*ty)?))
+            }
 
-            t => Self::NotDone(t), // return Err(HaoError::InvalidSignatureForEntry),
+            // `END` and the unused `R` are never the element type of a type.
+            TypeSigDef::Unknown(_) => {
+                return Err(HaoError::BadImageFormat(
+                    "Signature has an element type that is not a type",
+                ))
+            }
         };
         Ok(sig)
     }
+
+    pub(crate) fn set_generic_names(&mut self, names: &GenericNames) {
+        match self {
+            Self::Var {
+                generic_param_index,
+                name,
+            } => *name = names.type_param(*generic_param_index),
+            Self::MVar {
+                generic_param_index,
+                name,
+            } => *name = names.method_param(*generic_param_index),
+            Self::Ptr(ty)
+            | Self::ByRef(ty)
+            | Self::SZArray(ty)
+            | Self::Pinned(ty)
+            | Self::ValueArray { ty, .. }
+            | Self::Module(_, ty)
+            | Self::CModReq(_, ty)
+            | Self::CModOpt(_, ty)
+            | Self::Array { ty, .. } => ty.set_generic_names(names),
+            Self::FnPtr(method) => method.set_generic_names(names),
+            Self::GenericInst { ty, generic_args } => {
+                ty.set_generic_names(names);
+                for arg in generic_args {
+                    arg.set_generic_names(names);
+                }
+            }
+            _ => {}
+        }
+    }
 }
 
 impl Display for ValueType {
@@ -238,24 +525,35 @@ impl Display for ValueType {
             Self::Single => write!(f, "float"),
             Self::Double => write!(f, "double"),
             Self::String => write!(f, "string"),
-            Self::TypedRefrence => write!(f, "refrence"),
+            Self::TypedRefrence => write!(f, "TypedReference"),
             Self::IntPtr => write!(f, "IntPtr"),
             Self::UIntPtr => write!(f, "UIntPtr"),
             Self::Object => write!(f, "object"),
+            Self::Ptr(ty) => write!(f, "{}*", ty),
+            Self::ByRef(ty) => write!(f, "ref {}", ty),
+            Self::Pinned(ty) => write!(f, "pinned {}", ty),
             Self::ValueType(val) => write!(f, "{}", val),
+            Self::FnPtr(method) => method.fmt_fn_ptr(f),
             Self::SZArray(ty) => write!(f, "{}[]", ty),
-            Self::CModReq(ty) => match ty {
-                TypeDefOrRef::TypeDef(d) => write!(f, "CMOD(Def({:?}))", d.value().name()),
-                TypeDefOrRef::TypeRef(d) => write!(f, "CMOD(Ref({}))", d.value()),
-                TypeDefOrRef::TypeSpec(d) => write!(f, "CMOD(Spec({:?}))", d.value()),
-            },
+            Self::CModReq(modifier, ty) => write!(f, "modreq({}) {}", modifier, ty),
+            Self::CModOpt(modifier, ty) => write!(f, "modopt({}) {}", modifier, ty),
+            Self::Sentinel => write!(f, "..."),
+            Self::Internal => write!(f, "internal"),
             Self::Class(val) => write!(f, "{}", val),
-            Self::ValueArray { .. } => panic!("valuearray?"),
+            Self::ValueArray { len, ty } => write!(f, "{}[{}]", ty, len),
+            Self::Var {
+                name: Some(name), ..
+            }
+            | Self::MVar {
+                name: Some(name), ..
+            } => write!(f, "{}", name),
             Self::Var {
                 generic_param_index: generic_params,
+                ..
             } => write!(f, "T{}", generic_params),
             Self::MVar {
                 generic_param_index: generic_params,
+                ..
             } => write!(f, "M{}", generic_params),
             Self::GenericInst { ty, generic_args } => {
                 write!(f, "{}", ty)?;
@@ -274,9 +572,7 @@ impl Display for ValueType {
                     write!(f, "{}{:?}", ty, sizes.as_slice())
                 }
             },
-            Self::Module(_, _) => write!(f, "ValueType(Module)"),
-            Self::NotDone(x) => write!(f, "{:?}", x),
-            // _ => write!(f, "ValueType(Other)"),
+            Self::Module(index, ty) => write!(f, "[module {}] {}", index, ty),
         }
     }
 }
@@ -297,6 +593,10 @@ impl FieldSignature {
 
         Ok(Self(ValueType::from_type_sig(field_sig)?))
     }
+
+    pub(crate) fn set_generic_names(&mut self, names: &GenericNames) {
+        self.0.set_generic_names(names);
+    }
 }
 
 impl Display for FieldSignature {
@@ -306,6 +606,10 @@ impl Display for FieldSignature {
 }
 #[derive(Clone, Debug)]
 pub struct MethodSignature {
+    pub has_this: bool,
+    /// The `this` pointer is passed as the first parameter.
+    pub explicit_this: bool,
+    pub calling_convention: CallingConvention,
     pub return_type: Box<TypeSignature>,
     pub generic_params: Option<u32>,
     pub parameters: Vec<TypeSignature>,
@@ -324,6 +628,9 @@ impl MethodSignature {
         };
 
         Ok(Self {
+            has_this: sig.flags.contains(SignatureFlags::HasThis),
+            explicit_this: sig.flags.contains(SignatureFlags::ExplicitThis),
+            calling_convention: method_sig.calling_convention,
             return_type: Box::from(TypeSignature::from_sig_def(*method_sig.return_type)?),
             generic_params: method_sig.generic_params,
             parameters: method_sig
@@ -342,6 +649,42 @@ impl MethodSignature {
                 .transpose()?,
         })
     }
+
+    pub(crate) fn set_generic_names(&mut self, names: &GenericNames) {
+        self.return_type.set_generic_names(names);
+        for param in self.parameters.iter_mut() {
+            param.set_generic_names(names);
+        }
+        for param in self.params_after_sentinel.iter_mut().flatten() {
+            param.set_generic_names(names);
+        }
+    }
+}
+
+impl MethodSignature {
+    // Formats the signature as a C# function pointer type.
+    // e.g. `delegate* unmanaged[Stdcall]<int, string, void>`.
+    fn fmt_fn_ptr(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "delegate*")?;
+        match self.calling_convention {
+            CallingConvention::C => write!(f, " unmanaged[Cdecl]")?,
+            CallingConvention::StdCall => write!(f, " unmanaged[Stdcall]")?,
+            CallingConvention::ThisCall => write!(f, " unmanaged[Thiscall]")?,
+            CallingConvention::FastCall => write!(f, " unmanaged[Fastcall]")?,
+            CallingConvention::Unmanaged => write!(f, " unmanaged")?,
+            CallingConvention::VarArg | CallingConvention::NativeVarArg => write!(f, " vararg")?,
+            _ => {}
+        }
+        write!(f, "<")?;
+        let params = self
+            .parameters
+            .iter()
+            .chain(self.params_after_sentinel.iter().flatten());
+        for param in params {
+            write!(f, "{}, ", param)?;
+        }
+        write!(f, "{}>", self.return_type)
+    }
 }
 
 impl Display for MethodSignature {
@@ -377,6 +720,227 @@ impl Deref for FieldSignature {
     }
 }
 
+/// The signature of a [`MemberRef`](super::values::MemberRef).
+#[derive(Clone, Debug)]
+pub enum MemberRefSignature {
+    Method(MethodSignature),
+    Field(FieldSignature),
+}
+
+impl MemberRefSignature {
+    pub fn from_sig_def(sig: SignatureDef) -> Result<Self> {
+        match sig.calling_convention {
+            SignatureCallingConvention::Method(_) => {
+                Ok(Self::Method(MethodSignature::from_sig_def(sig)?))
+            }
+            SignatureCallingConvention::Field(_) => {
+                Ok(Self::Field(FieldSignature::from_sig_def(sig)?))
+            }
+            _ => Err(HaoError::InvalidSignatureForEntry(std::any::type_name::<
+                Self,
+            >())),
+        }
+    }
+}
+
+impl Display for MemberRefSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Method(method) => write!(f, "{}", method),
+            Self::Field(field) => write!(f, "{}", field),
+        }
+    }
+}
+
+/// The signature of a [`Property`](super::values::Property).
+///
+/// `parameters` is only non-empty for indexers.
+#[derive(Clone, Debug)]
+pub struct PropertySignature {
+    pub has_this: bool,
+    pub property_type: TypeSignature,
+    pub parameters: Vec<TypeSignature>,
+}
+
+impl PropertySignature {
+    pub fn from_sig_def(sig: SignatureDef) -> Result<Self> {
+        let property_sig = match sig.calling_convention {
+            SignatureCallingConvention::Property(property) => property,
+            _ => {
+                return Err(HaoError::InvalidSignatureForEntry(std::any::type_name::<
+                    Self,
+                >()))
+            }
+        };
+
+        Ok(Self {
+            has_this: sig.flags.contains(SignatureFlags::HasThis),
+            property_type: TypeSignature::from_sig_def(*property_sig.return_type)?,
+            parameters: property_sig
+                .parameters
+                .into_iter()
+                .map(TypeSignature::from_sig_def)
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    pub(crate) fn set_generic_names(&mut self, names: &GenericNames) {
+        self.property_type.set_generic_names(names);
+        for param in self.parameters.iter_mut() {
+            param.set_generic_names(names);
+        }
+    }
+}
+
+impl Display for PropertySignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.property_type)?;
+        if !self.parameters.is_empty() {
+            write!(f, "[")?;
+            for (index, param) in self.parameters.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", param)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+/// The type arguments of a [`MethodSpec`](super::values::MethodSpec).
+#[derive(Clone, Debug)]
+pub struct MethodSpecSignature(pub Vec<ValueType>);
+
+impl MethodSpecSignature {
+    pub fn from_sig_def(sig: SignatureDef) -> Result<Self> {
+        let inst_sig = match sig.calling_convention {
+            SignatureCallingConvention::GenericInstMethod(inst) => inst,
+            _ => {
+                return Err(HaoError::InvalidSignatureForEntry(std::any::type_name::<
+                    Self,
+                >()))
+            }
+        };
+
+        Ok(Self(
+            inst_sig
+                .generic_args
+                .into_iter()
+                .map(ValueType::from_type_sig)
+                .collect::<Result<_>>()?,
+        ))
+    }
+}
+
+impl Display for MethodSpecSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<")?;
+        for (index, arg) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", arg)?;
+        }
+        write!(f, ">")
+    }
+}
+
+impl Deref for MethodSpecSignature {
+    type Target = [ValueType];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The types of the local variables of a method body.
+#[derive(Clone, Debug)]
+pub struct LocalVarSignature(pub Vec<ValueType>);
+
+impl LocalVarSignature {
+    pub fn from_sig_def(sig: SignatureDef) -> Result<Self> {
+        let local_sig = match sig.calling_convention {
+            SignatureCallingConvention::LocalSig(locals) => locals,
+            _ => {
+                return Err(HaoError::InvalidSignatureForEntry(std::any::type_name::<
+                    Self,
+                >()))
+            }
+        };
+
+        Ok(Self(
+            local_sig
+                .locals
+                .into_iter()
+                .map(ValueType::from_type_sig)
+                .collect::<Result<_>>()?,
+        ))
+    }
+}
+
+impl Display for LocalVarSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "locals(")?;
+        for (index, local) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", local)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Deref for LocalVarSignature {
+    type Target = [ValueType];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The signature held by a [`StandAloneSig`](super::values::StandAloneSig).
+///
+/// These are used for the locals of a method body
+/// and for the call site of a `calli` instruction.
+#[derive(Clone, Debug)]
+pub enum StandAloneSignature {
+    LocalVar(LocalVarSignature),
+    Method(MethodSignature),
+    Field(FieldSignature),
+}
+
+impl StandAloneSignature {
+    pub fn from_sig_def(sig: SignatureDef) -> Result<Self> {
+        match sig.calling_convention {
+            SignatureCallingConvention::LocalSig(_) => {
+                Ok(Self::LocalVar(LocalVarSignature::from_sig_def(sig)?))
+            }
+            SignatureCallingConvention::Method(_) => {
+                Ok(Self::Method(MethodSignature::from_sig_def(sig)?))
+            }
+            SignatureCallingConvention::Field(_) => {
+                Ok(Self::Field(FieldSignature::from_sig_def(sig)?))
+            }
+            _ => Err(HaoError::InvalidSignatureForEntry(std::any::type_name::<
+                Self,
+            >())),
+        }
+    }
+}
+
+impl Display for StandAloneSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LocalVar(locals) => write!(f, "{}", locals),
+            Self::Method(method) => write!(f, "{}", method),
+            Self::Field(field) => write!(f, "{}", field),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum TypeSignature {
     GenericInst {
@@ -385,15 +949,17 @@ pub enum TypeSignature {
     },
     ClassVariable {
         generic_param_index: u32,
+        name: Option<String>,
     },
     MethodVariable {
         generic_param_index: u32,
+        name: Option<String>,
     },
     SZArray(ValueType),
     FnPtr(MethodSignature),
     Class(TypeDefOrRef),
     ValueType(TypeDefOrRef),
-    Other(TypeSigDef),
+    Other(ValueType),
 }
 
 impl TypeSignature {
@@ -410,11 +976,13 @@ impl TypeSignature {
                 generic_param_index,
             } => Ok(Self::ClassVariable {
                 generic_param_index,
+                name: None,
             }),
             TypeSigDef::MVar {
                 generic_param_index,
             } => Ok(Self::MethodVariable {
                 generic_param_index,
+                name: None,
             }),
             TypeSigDef::SZArray(ty) => Ok(Self::SZArray(ValueType::from_type_sig(*ty)?)),
             TypeSigDef::FnPtr(fn_ptr) => Ok(Self::FnPtr(MethodSignature::from_sig_def(*fn_ptr)?)),
@@ -422,12 +990,59 @@ impl TypeSignature {
             TypeSigDef::ValueType(valtype) => {
                 Ok(Self::ValueType(TypeDefOrRef::from_ent_ptr_must(valtype.0)?))
             }
-            e => {
-                Ok(Self::Other(e))
-                // /return Err(HaoError::InvalidSignatureForEntry(std::any::type_name::<
-                // /    Self,
-                // />()));
+            e => Ok(Self::Other(ValueType::from_type_sig(e)?)),
+        }
+    }
+
+    pub(crate) fn set_generic_names(&mut self, names: &GenericNames) {
+        match self {
+            Self::GenericInst { ty, generic_args } => {
+                ty.set_generic_names(names);
+                for arg in generic_args {
+                    arg.set_generic_names(names);
+                }
             }
+            Self::ClassVariable {
+                generic_param_index,
+                name,
+            } => *name = names.type_param(*generic_param_index),
+            Self::MethodVariable {
+                generic_param_index,
+                name,
+            } => *name = names.method_param(*generic_param_index),
+            Self::SZArray(ty) => ty.set_generic_names(names),
+            Self::FnPtr(method) => method.set_generic_names(names),
+            Self::Other(ty) => ty.set_generic_names(names),
+            _ => {}
+        }
+    }
+
+    /// Returns the same type as a [`ValueType`].
+    pub fn to_value_type(&self) -> ValueType {
+        match self {
+            Self::GenericInst { ty, generic_args } => ValueType::GenericInst {
+                ty: Box::new(ty.clone()),
+                generic_args: generic_args.clone(),
+            },
+            Self::ClassVariable {
+                generic_param_index,
+                name,
+            } => ValueType::Var {
+                generic_param_index: *generic_param_index,
+                name: name.clone(),
+            },
+            Self::MethodVariable {
+                generic_param_index,
+                name,
+            } => ValueType::MVar {
+                generic_param_index: *generic_param_index,
+                name: name.clone(),
+            },
+            Self::SZArray(ty) => ValueType::SZArray(Box::new(ty.clone())),
+            Self::FnPtr(method) => ValueType::FnPtr(Box::new(method.clone())),
+            Self::Class(ty) => ValueType::Class(ty.clone()),
+            Self::ValueType(ty) => ValueType::ValueType(ty.clone()),
+            Self::Other(ty) => ty.clone(),
         }
     }
 }
@@ -446,17 +1061,25 @@ impl Display for TypeSignature {
                 }
                 write!(f, ">")
             }
+            Self::ClassVariable {
+                name: Some(name), ..
+            }
+            | Self::MethodVariable {
+                name: Some(name), ..
+            } => write!(f, "{}", name),
             Self::ClassVariable {
                 generic_param_index,
+                ..
             } => write!(f, "GenericVar({})", generic_param_index),
             Self::MethodVariable {
                 generic_param_index,
+                ..
             } => write!(f, "GenericMethodVar({})", generic_param_index),
             Self::SZArray(ty) => write!(f, "{}[]", ty),
-            Self::FnPtr(fnptr) => write!(f, "&{}", fnptr),
+            Self::FnPtr(fnptr) => fnptr.fmt_fn_ptr(f),
             Self::Class(class) => write!(f, "{}", class),
             Self::ValueType(vtype) => write!(f, "{}", vtype),
-            Self::Other(t) => write!(f, "{:?}", t),
+            Self::Other(t) => write!(f, "{}", t),
         }
     }
 }
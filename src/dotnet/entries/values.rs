@@ -3,24 +3,50 @@ use num_traits::Zero;
 use super::{
     super::md::streams::{
         tables_stream::{
-            coded_tokens::{CodedToken, ResolutionScopeToken, TypeDefOrRefToken},
+            coded_tokens::{
+                CodedToken, CustomAttributeTypeToken, HasConstantToken, HasCustomAttributeToken,
+                HasFieldMarshalToken, HasSemanticToken, ImplementationToken, MemberForwardedToken,
+                MemberRefParentToken, MethodDefOrRefToken, ResolutionScopeToken, TypeDefOrRefToken,
+                TypeOrMethodDefToken,
+            },
             FieldFlags, FieldTableRow, MethodFlags, MethodImplFlags, MethodTableRow,
             ModulesTableRow, TypeAttributes, TypeDefTableRow, TypeRefTableRow,
         },
         SignatureDef,
     },
+    body::{BodySource, MethodBody},
+    constant::ConstantValue,
+    custom_attributes::{
+        AttributeSource, CustomAttributeNamedArg, CustomAttributeReader, CustomAttributeValue,
+    },
+    resolve::MAX_RESOLVE_DEPTH,
     signature::{
-        FieldSignature, MethodSignature, ResolutionScope, TypeDefOrRef, TypeSignature, ValueType,
+        FieldSignature, Implementation, MemberRefParent, MemberRefSignature, MethodDefOrRef,
+        MethodSignature, MethodSpecSignature, PropertySignature, ResolutionScope,
+        StandAloneSignature, TypeDefOrRef, TypeOrMethodDef, TypeSignature, ValueType,
     },
     well_known::{SystemType, WellKnown},
-    EntryCollection, EntryView, {Ptr, ReadEntry, RowRange},
+    Entry, EntryCollection, EntryView, {Ptr, ReadEntry, RowRange},
 };
 use crate::{
     dotnet::{
-        entries::{GetEntryField, MaybeUninitEntries},
-        md::streams::tables_stream::{
-            AssemblyFlags, AssemblyRefTableRow, InterfaceImplTableRow, ModuleRefTableRow,
-            ParamFlags, ParamTableRow, TypeSpecTableRow,
+        entries::{GetEntryField, MaybeUninitEntries, MaybeUnsetEntry, TableEntry},
+        md::{
+            streams::tables_stream::{
+                AssemblyFlags, AssemblyRefTableRow, AssemblyTableRow, ClassLayoutTableRow,
+                ConstantTableRow, CustomAttributeTableRow, ENCLogTableRow, ENCMapTableRow,
+                EventFlags, EventMapTableRow, EventPtrTableRow, EventTableRow,
+                ExportedTypeTableRow, FieldLayoutTableRow, FieldMarshalTableRow, FieldPtrTableRow,
+                FieldRVATableRow, FileFlags, FileTableRow, GenericParamConstraintTableRow,
+                GenericParamFlags, GenericParamTableRow, ImplMapTableRow, InterfaceImplTableRow,
+                ManifestResourceFlags, ManifestResourceTableRow, MemberRefTableRow,
+                MethodImplTableRow, MethodPtrTableRow, MethodSemanticsFlags,
+                MethodSemanticsTableRow, MethodSpecTableRow, ModuleRefTableRow,
+                NestedClassTableRow, PInvokeMapFlags, ParamFlags, ParamPtrTableRow, ParamTableRow,
+                PropertyFlags, PropertyMapTableRow, PropertyPtrTableRow, PropertyTableRow,
+                StandAloneSigTableRow, TableId, TypeSpecTableRow,
+            },
+            MDToken,
         },
     },
     error::{HaoError, Result},
@@ -28,8 +54,10 @@ use crate::{
     Module,
 };
 use std::{
+    cell::RefCell,
+    collections::HashMap,
     fmt::{Debug, Display},
-    rc::Rc,
+    rc::{Rc, Weak},
 };
 
 #[derive(Debug, Clone)]
@@ -39,12 +67,27 @@ pub struct ModuleDef {
     pub mvid: uuid::Uuid,
     pub enc_id: uuid::Uuid,
     pub enc_base_id: uuid::Uuid,
+
+    pub(crate) custom_attributes: Vec<Ptr<CustomAttribute>>,
 }
 
 impl ModuleDef {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Returns the custom attributes applied to the module.
+    /// ```no_run
+    /// # use hao::Module;
+    /// let module = Module::from_path(r#"Example.Net.dll"#).unwrap();
+    ///
+    /// for attribute in module.module().value().custom_attributes().values() {
+    ///     println!("[module: {}]", attribute);
+    /// }
+    /// ```
+    pub fn custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
 }
 
 impl<'a> ReadEntry<ModuleDef> for EntryReader<'a> {
@@ -57,10 +100,11 @@ impl<'a> ReadEntry<ModuleDef> for EntryReader<'a> {
     ) -> Result<ModuleDef> {
         Ok(ModuleDef {
             generation: self.read(row.generation)?,
-            name: self.read(row.name)?,
+            name: self.read_string("Name", row.name)?,
             mvid: self.read(row.mvid)?,
             enc_id: self.read(row.enc_id)?,
             enc_base_id: self.read(row.enc_base_id)?,
+            custom_attributes: Vec::new(),
         })
     }
 }
@@ -108,7 +152,10 @@ impl GetEntryField<CodedToken<ResolutionScopeToken>> for MaybeUninitEntries {
                 .cloned()
                 .map(ResolutionScopePtr::TypeRef),
         };
-        val.ok_or_else(|| HaoError::InvalidCodedTokenOffset(identifier.rid, "ResolutionScopeToken"))
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "ResolutionScopeToken",
+        ))
     }
 }
 
@@ -119,7 +166,49 @@ pub struct TypeRef {
     pub(crate) namespace: String,
 }
 
+// Joins the name of a type to the names of the types it is nested in, outermost first.
+// Declaring types that loop back on themselves are cut off after `MAX_RESOLVE_DEPTH`.
+fn nested_full_name<T>(
+    ty: &T,
+    separator: char,
+    name: impl Fn(&T) -> &str,
+    namespace: impl Fn(&T) -> &str,
+    declaring: impl Fn(&T) -> Option<Ptr<T>>,
+) -> String {
+    let mut full_name = name(ty).to_string();
+    let mut outer_namespace = namespace(ty).to_string();
+    let mut next = declaring(ty);
+    for _ in 0..MAX_RESOLVE_DEPTH {
+        let Some(outer) = next else {
+            break;
+        };
+        let outer = outer.value();
+        full_name = format!("{}{}{}", name(&outer), separator, full_name);
+        outer_namespace = namespace(&outer).to_string();
+        next = declaring(&outer);
+    }
+    if outer_namespace.is_empty() {
+        full_name
+    } else {
+        format!("{}.{}", outer_namespace, full_name)
+    }
+}
+
 impl TypeRef {
+    /// Creates a refrence to a type. Use [`Module::add_type_ref`](crate::Module::add_type_ref)
+    /// to add it to a module.
+    pub fn new(
+        resolution_scope: ResolutionScope,
+        namespace: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        Self {
+            resolution_scope,
+            name: name.into(),
+            namespace: namespace.into(),
+        }
+    }
+
     pub fn resolution_scope(&self) -> &ResolutionScope {
         &self.resolution_scope
     }
@@ -130,6 +219,16 @@ impl TypeRef {
         &self.namespace
     }
 
+    pub fn set_resolution_scope(&mut self, resolution_scope: ResolutionScope) {
+        self.resolution_scope = resolution_scope;
+    }
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+    pub fn set_namespace(&mut self, namespace: impl Into<String>) {
+        self.namespace = namespace.into();
+    }
+
     pub fn well_known(&self) -> Option<WellKnown> {
         match self.resolution_scope() {
             ResolutionScope::AssemblyRef(r) if r.value().is_corlib() => {
@@ -147,14 +246,55 @@ impl TypeRef {
         SystemType::from_full_name(self.namespace(), self.name()).is_some()
     }
 
+    /// Returns true if the type, or the type it is nested in, is refrenced from the corlib.
     pub fn is_corlib(&self) -> bool {
-        matches!(self.resolution_scope(), ResolutionScope::AssemblyRef(r) if r.value().is_corlib())
+        let mut scope = self.resolution_scope().clone();
+        for _ in 0..MAX_RESOLVE_DEPTH {
+            scope = match &scope {
+                ResolutionScope::AssemblyRef(r) => return r.value().is_corlib(),
+                ResolutionScope::TypeRef(r) => r.value().resolution_scope().clone(),
+                _ => return false,
+            };
+        }
+        false
+    }
+
+    /// The type this type is nested in, if this is a refrence to a nested type.
+    pub fn declaring_type(&self) -> Option<&Entry<TypeRef>> {
+        match self.resolution_scope() {
+            ResolutionScope::TypeRef(r) => Some(r),
+            _ => None,
+        }
+    }
+
+    pub fn is_nested(&self) -> bool {
+        self.declaring_type().is_some()
+    }
+
+    /// Returns the full name of the type with nested types seperated by `/`.
+    ///
+    /// e.g. `System.Collections.Generic.List`1/Enumerator`
+    pub fn full_name(&self) -> String {
+        self.full_name_with('/')
+    }
+
+    /// Returns the full name of the type as used by reflection,
+    /// with nested types seperated by `+`.
+    ///
+    /// e.g. `System.Collections.Generic.List`1+Enumerator`
+    pub fn reflection_full_name(&self) -> String {
+        self.full_name_with('+')
+    }
+
+    fn full_name_with(&self, separator: char) -> String {
+        nested_full_name(self, separator, Self::name, Self::namespace, |t| {
+            t.declaring_type().map(|d| d.0.clone())
+        })
     }
 
     pub fn is_system_type_instance(&self, system_type: SystemType) -> bool {
         SystemType::from_full_name(self.namespace(), self.name()) == Some(system_type)
     }
-
 }
 
 impl<'a> ReadEntry<TypeRef> for EntryReader<'a> {
@@ -167,8 +307,8 @@ impl<'a> ReadEntry<TypeRef> for EntryReader<'a> {
     ) -> Result<TypeRef> {
         Ok(TypeRef {
             resolution_scope: ResolutionScope::from_ent_ptr_must(self.read(row.resolution_scope)?)?,
-            name: self.read(row.name)?,
-            namespace: self.read(row.namespace)?,
+            name: self.read_string("TypeName", row.name)?,
+            namespace: self.read_string("TypeNamespace", row.namespace)?,
         })
     }
 }
@@ -178,7 +318,15 @@ impl Display for TypeRef {
         if let Some(well_known) = self.well_known() {
             write!(f, "{}", well_known.type_name())
         } else {
-            write!(f, "{}", self.name())
+            // Refrences are shown without their namespace.
+            let name = nested_full_name(
+                self,
+                '.',
+                Self::name,
+                |_| "",
+                |t| t.declaring_type().map(|d| d.0.clone()),
+            );
+            write!(f, "{}", name)
         }
     }
 }
@@ -220,11 +368,14 @@ impl GetEntryField<CodedToken<TypeDefOrRefToken>> for MaybeUninitEntries {
                 .cloned()
                 .map(TypeDefOrRefPtr::TypeSpec),
         };
-        val.ok_or_else(|| HaoError::InvalidCodedTokenOffset(identifier.rid, "TypeDefOrRefToken"))
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "TypeDefOrRefToken",
+        ))
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct TypeDef {
     pub(crate) flags: TypeAttributes,
     pub(crate) name: String,
@@ -232,11 +383,73 @@ pub struct TypeDef {
     pub(crate) extends: Option<TypeDefOrRef>,
     pub(crate) field_list: Vec<Ptr<Field>>,
     pub(crate) method_list: Vec<Ptr<Method>>,
+    pub(crate) property_list: Vec<Ptr<Property>>,
+    pub(crate) event_list: Vec<Ptr<Event>>,
+
+    pub(crate) declaring_type: Option<Ptr<TypeDef>>,
+    pub(crate) nested_types: Vec<Ptr<TypeDef>>,
+    pub(crate) generic_params: Vec<Ptr<GenericParam>>,
 
     pub(crate) interface_impl: Vec<TypeDefOrRef>,
+    pub(crate) class_layout: Option<ClassLayout>,
+    pub(crate) method_impls: Vec<MethodImpl>,
+    pub(crate) custom_attributes: Vec<Ptr<CustomAttribute>>,
+}
+
+// impl to stop infiniate reccursion on debug print
+impl Debug for TypeDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypeDef")
+            .field("flags", &self.flags)
+            .field("name", &self.name)
+            .field("namespace", &self.namespace)
+            .field("extends", &self.extends)
+            .field("field_list", &self.field_list)
+            .field("method_list", &self.method_list)
+            .field("property_list", &self.property_list)
+            .field("event_list", &self.event_list)
+            .field(
+                "declaring_type",
+                &self.declaring_type.as_ref().map(|d| d.value().full_name()),
+            )
+            .field("nested_types", &self.nested_types)
+            .field("generic_params", &self.generic_params)
+            .field("interface_impl", &self.interface_impl)
+            .field("class_layout", &self.class_layout)
+            .field("method_impls", &self.method_impls)
+            .field("custom_attributes", &self.custom_attributes)
+            .finish()
+    }
 }
 
 impl TypeDef {
+    /// Creates a type with no members. Use [`Module::add_type`](crate::Module::add_type)
+    /// to add it to a module.
+    pub fn new(
+        flags: TypeAttributes,
+        namespace: impl Into<String>,
+        name: impl Into<String>,
+        extends: Option<TypeDefOrRef>,
+    ) -> Self {
+        Self {
+            flags,
+            name: name.into(),
+            namespace: namespace.into(),
+            extends,
+            field_list: Vec::new(),
+            method_list: Vec::new(),
+            property_list: Vec::new(),
+            event_list: Vec::new(),
+            declaring_type: None,
+            nested_types: Vec::new(),
+            generic_params: Vec::new(),
+            interface_impl: Vec::new(),
+            class_layout: None,
+            method_impls: Vec::new(),
+            custom_attributes: Vec::new(),
+        }
+    }
+
     pub fn flags(&self) -> TypeAttributes {
         self.flags
     }
@@ -250,6 +463,79 @@ impl TypeDef {
         &self.extends
     }
 
+    pub fn set_flags(&mut self, flags: TypeAttributes) {
+        self.flags = flags;
+    }
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+    pub fn set_namespace(&mut self, namespace: impl Into<String>) {
+        self.namespace = namespace.into();
+    }
+    pub fn set_extends(&mut self, extends: Option<TypeDefOrRef>) {
+        self.extends = extends;
+    }
+
+    /// The type this type is nested in.
+    pub fn declaring_type(&self) -> Option<EntryView<'_, TypeDef>> {
+        self.declaring_type.as_ref().map(EntryView)
+    }
+
+    pub fn is_nested(&self) -> bool {
+        self.declaring_type.is_some()
+    }
+
+    /// Returns the types nested directly inside this type.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for ty in module.types().values().filter(|x| !x.is_nested()) {
+    ///    for nested in ty.nested_types().values() {
+    ///        println!("{}", nested.reflection_full_name());
+    ///    }
+    /// }
+    /// ```
+    pub fn nested_types(&self) -> EntryCollection<'_, TypeDef> {
+        EntryCollection::new(&self.nested_types)
+    }
+
+    /// Returns the generic parameters of this type, ordered by their index.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for ty in module.types().values() {
+    ///    for param in ty.generic_params().values() {
+    ///        println!("{}<{}>", ty.name(), param.name());
+    ///    }
+    /// }
+    /// ```
+    pub fn generic_params(&self) -> EntryCollection<'_, GenericParam> {
+        EntryCollection::new(&self.generic_params)
+    }
+
+    /// Returns the full name of the type with nested types seperated by `/`.
+    ///
+    /// e.g. `System.Collections.Generic.List`1/Enumerator`
+    pub fn full_name(&self) -> String {
+        self.full_name_with('/')
+    }
+
+    /// Returns the full name of the type as used by reflection,
+    /// with nested types seperated by `+`.
+    ///
+    /// e.g. `System.Collections.Generic.List`1+Enumerator`
+    pub fn reflection_full_name(&self) -> String {
+        self.full_name_with('+')
+    }
+
+    fn full_name_with(&self, separator: char) -> String {
+        nested_full_name(self, separator, Self::name, Self::namespace, |t| {
+            t.declaring_type.clone()
+        })
+    }
+
     /// Returns an [`EntryCollection`] of [`Field`] with all the fields
     /// associated with this type.
     ///
@@ -263,7 +549,7 @@ impl TypeDef {
     ///    }
     /// }
     /// ```
-    pub fn fields(&self) -> EntryCollection<Field> {
+    pub fn fields(&self) -> EntryCollection<'_, Field> {
         EntryCollection::new(&self.field_list)
     }
 
@@ -280,10 +566,55 @@ impl TypeDef {
     ///    }
     /// }
     /// ```
-    pub fn methods(&self) -> EntryCollection<Method> {
+    pub fn methods(&self) -> EntryCollection<'_, Method> {
         EntryCollection::new(&self.method_list)
     }
 
+    /// Returns the properties declared by this type.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for ty in module.types().values() {
+    ///    for property in ty.properties().values() {
+    ///        println!("{}", property);
+    ///    }
+    /// }
+    /// ```
+    pub fn properties(&self) -> EntryCollection<'_, Property> {
+        EntryCollection::new(&self.property_list)
+    }
+
+    /// Returns the events declared by this type.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for ty in module.types().values() {
+    ///    for event in ty.events().values() {
+    ///        println!("{}", event);
+    ///    }
+    /// }
+    /// ```
+    pub fn events(&self) -> EntryCollection<'_, Event> {
+        EntryCollection::new(&self.event_list)
+    }
+
+    /// Returns the custom attributes applied to this type.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for ty in module.types().values() {
+    ///    for attribute in ty.custom_attributes().values() {
+    ///        println!("[{}] {}", attribute, ty.name());
+    ///    }
+    /// }
+    /// ```
+    pub fn custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
+
     pub fn extends_system_type(&self, system_type: SystemType) -> bool {
         match self.extends() {
             Some(TypeDefOrRef::TypeRef(tref)) => tref.value().is_system_type_instance(system_type),
@@ -293,7 +624,7 @@ impl TypeDef {
     pub fn full_name_is(&self, namespace: &str, name: &str) -> bool {
         (namespace, name) == (self.namespace(), self.name())
     }
-    
+
     pub fn is_static(&self) -> bool {
         self.flags.contains(TypeAttributes::AutoLayout)
             && self.flags.contains(TypeAttributes::Class)
@@ -341,12 +672,28 @@ impl<'a> ReadEntry<TypeDef> for EntryReader<'a> {
     ) -> Result<TypeDef> {
         Ok(TypeDef {
             flags: row.flags,
-            name: self.read(row.name)?,
-            namespace: self.read(row.namespace)?,
-            extends: TypeDefOrRef::from_ent_pointer(self.read(row.extends)?),
-            field_list: self.read(RowRange::new(row.field_list, _next.map(|x| x.field_list)))?,
-            method_list: self.read(RowRange::new(row.method_list, _next.map(|x| x.method_list)))?,
+            name: self.read_string("TypeName", row.name)?,
+            namespace: self.read_string("TypeNamespace", row.namespace)?,
+            extends: TypeDefOrRef::from_ent_pointer(
+                self.recover("Extends", self.read(row.extends))?,
+            ),
+            field_list: self.recover(
+                "FieldList",
+                self.read(RowRange::new(row.field_list, _next.map(|x| x.field_list))),
+            )?,
+            method_list: self.recover(
+                "MethodList",
+                self.read(RowRange::new(row.method_list, _next.map(|x| x.method_list))),
+            )?,
+            property_list: Vec::new(),
+            event_list: Vec::new(),
+            declaring_type: None,
+            nested_types: Vec::new(),
+            generic_params: Vec::new(),
             interface_impl: Vec::new(),
+            class_layout: None,
+            method_impls: Vec::new(),
+            custom_attributes: Vec::new(),
         })
     }
 }
@@ -387,10 +734,7 @@ impl Display for TypeDef {
             write!(f, "class ")?;
         }
 
-        if !self.namespace().is_empty() {
-            write!(f, "{}.", self.namespace())?;
-        }
-        write!(f, "{}", self.name())?;
+        write!(f, "{}", self.full_name_with('.'))?;
 
         let has_base_class = match self.extends() {
             Some(extend) if !extend.is_type_ref_and(|c| c.is_system_type()) => {
@@ -423,9 +767,29 @@ pub struct Field {
     pub(crate) flags: FieldFlags,
     pub(crate) name: String,
     pub(crate) signature: FieldSignature,
+    pub(crate) constant: Option<ConstantValue>,
+    pub(crate) offset: Option<u32>,
+    pub(crate) initial_value: Option<Vec<u8>>,
+    pub(crate) marshal: Option<Vec<u8>>,
+    pub(crate) custom_attributes: Vec<Ptr<CustomAttribute>>,
 }
 
 impl Field {
+    /// Creates a field. Use [`Module::add_field`](crate::Module::add_field)
+    /// to add it to a type.
+    pub fn new(flags: FieldFlags, name: impl Into<String>, signature: FieldSignature) -> Self {
+        Self {
+            flags,
+            name: name.into(),
+            signature,
+            constant: None,
+            offset: None,
+            initial_value: None,
+            marshal: None,
+            custom_attributes: Vec::new(),
+        }
+    }
+
     pub fn flags(&self) -> FieldFlags {
         self.flags
     }
@@ -435,6 +799,49 @@ impl Field {
     pub fn signature(&self) -> &FieldSignature {
         &self.signature
     }
+
+    pub fn set_flags(&mut self, flags: FieldFlags) {
+        self.flags = flags;
+    }
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+    pub fn set_signature(&mut self, signature: FieldSignature) {
+        self.signature = signature;
+    }
+    pub fn set_constant(&mut self, constant: Option<ConstantValue>) {
+        self.constant = constant;
+    }
+
+    /// The value of a `const` field or enum member.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for field in module.all_fields().values() {
+    ///     if let Some(value) = field.constant() {
+    ///         println!("{} = {}", field.name(), value);
+    ///     }
+    /// }
+    /// ```
+    pub fn constant(&self) -> Option<&ConstantValue> {
+        self.constant.as_ref()
+    }
+
+    /// Returns the custom attributes applied to this field.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for field in module.all_fields().values() {
+    ///     for attribute in field.custom_attributes().values() {
+    ///         println!("[{}] {}", attribute, field.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
 }
 
 impl<'a> ReadEntry<Field> for EntryReader<'a> {
@@ -449,8 +856,13 @@ impl<'a> ReadEntry<Field> for EntryReader<'a> {
 
         Ok(Field {
             flags: row.flags,
-            name: self.read(row.name)?,
+            name: self.read_string("Name", row.name)?,
             signature: FieldSignature::from_sig_def(signature)?,
+            constant: None,
+            offset: None,
+            initial_value: None,
+            marshal: None,
+            custom_attributes: Vec::new(),
         })
     }
 }
@@ -466,7 +878,11 @@ impl Display for Field {
         if self.flags.contains(FieldFlags::Static) {
             write!(f, "static ")?;
         }
-        write!(f, "{} {}", self.signature(), self.name())
+        write!(f, "{} {}", self.signature(), self.name())?;
+        if let Some(value) = self.constant() {
+            write!(f, " = {}", value)?;
+        }
+        Ok(())
     }
 }
 
@@ -478,11 +894,39 @@ pub struct Method {
     pub(crate) name: String,
     pub(crate) signature: MethodSignature,
     pub(crate) param_list: Vec<Ptr<Param>>,
+    pub(crate) body_source: Weak<BodySource>,
+    pub(crate) semantics: MethodSemanticsFlags,
+    pub(crate) generic_params: Vec<Ptr<GenericParam>>,
+    pub(crate) pinvoke: Option<ImplMap>,
+    pub(crate) custom_attributes: Vec<Ptr<CustomAttribute>>,
 }
 
 impl Method {
+    /// Creates a method without a body. Use [`Module::add_method`](crate::Module::add_method)
+    /// to add it to a type.
+    pub fn new(
+        flags: MethodFlags,
+        impl_flags: MethodImplFlags,
+        name: impl Into<String>,
+        signature: MethodSignature,
+    ) -> Self {
+        Self {
+            rva: 0,
+            impl_flags,
+            flags,
+            name: name.into(),
+            signature,
+            param_list: Vec::new(),
+            body_source: Weak::new(),
+            semantics: MethodSemanticsFlags::empty(),
+            generic_params: Vec::new(),
+            pinvoke: None,
+            custom_attributes: Vec::new(),
+        }
+    }
+
     pub fn rva(&self) -> u32 {
-        todo!("{:?}", self.rva)
+        self.rva
     }
     pub fn impl_flags(&self) -> MethodImplFlags {
         self.impl_flags
@@ -497,6 +941,26 @@ impl Method {
         &self.signature
     }
 
+    pub fn set_impl_flags(&mut self, impl_flags: MethodImplFlags) {
+        self.impl_flags = impl_flags;
+    }
+    pub fn set_flags(&mut self, flags: MethodFlags) {
+        self.flags = flags;
+    }
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+    pub fn set_signature(&mut self, signature: MethodSignature) {
+        self.signature = signature;
+    }
+
+    /// How the method is used by a [`Property`] or [`Event`].
+    ///
+    /// This is empty for methods that are not accessors.
+    pub fn semantics(&self) -> MethodSemanticsFlags {
+        self.semantics
+    }
+
     pub fn is_ctor(&self) -> bool {
         self.flags.contains(MethodFlags::SpecialName) && self.name == ".ctor"
     }
@@ -518,9 +982,79 @@ impl Method {
     ///    }
     /// }
     /// ```
-    pub fn params(&self) -> EntryCollection<Param> {
+    pub fn params(&self) -> EntryCollection<'_, Param> {
         EntryCollection::new(self.param_list.as_slice())
     }
+
+    /// Decodes the IL body of this method from its RVA.
+    ///
+    /// This will be `None` for methods without an IL body, such as abstract,
+    /// extern (pinvoke / internal call) and runtime implemented methods.
+    /// The body is read from the module the method was loaded from, so this
+    /// will fail if that [`Module`](crate::Module) has been dropped.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for method in module.all_methods().values() {
+    ///     if let Ok(Some(body)) = method.body() {
+    ///         println!("{} is {} bytes", method.name(), body.code().len());
+    ///     }
+    /// }
+    /// ```
+    pub fn body(&self) -> Result<Option<MethodBody>> {
+        if !self.has_body() {
+            return Ok(None);
+        }
+        let source = self
+            .body_source
+            .upgrade()
+            .ok_or(HaoError::InvalidMethodBody(
+                self.rva,
+                "The module the method was loaded from has been dropped",
+            ))?;
+        MethodBody::from_source(&source, self.rva).map(Some)
+    }
+
+    // Abstract, pinvoke, internal call and runtime implemented methods
+    // do not have an IL body, even if their RVA is set.
+    pub(crate) fn has_body(&self) -> bool {
+        self.rva != 0
+            && !self.flags.contains(MethodFlags::Abstract)
+            && !self.flags.contains(MethodFlags::PinvokeImpl)
+            && !self.impl_flags.contains(MethodImplFlags::InternalCall)
+            && (self.impl_flags & MethodImplFlags::CodeTypeMask) == MethodImplFlags::IL
+    }
+
+    /// Returns the generic parameters of this method, ordered by their index.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for method in module.all_methods().values() {
+    ///     for param in method.generic_params().values() {
+    ///         println!("{}<{}>", method.name(), param.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn generic_params(&self) -> EntryCollection<'_, GenericParam> {
+        EntryCollection::new(&self.generic_params)
+    }
+
+    /// Returns the custom attributes applied to this method.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for method in module.all_methods().values() {
+    ///     for attribute in method.custom_attributes().values() {
+    ///         println!("[{}] {}", attribute, method.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
 }
 
 impl<'a> ReadEntry<Method> for EntryReader<'a> {
@@ -535,9 +1069,17 @@ impl<'a> ReadEntry<Method> for EntryReader<'a> {
             rva: row.rva,
             impl_flags: row.impl_flags,
             flags: row.flags,
-            name: self.read(row.name)?,
+            name: self.read_string("Name", row.name)?,
             signature: MethodSignature::from_sig_def(self.read(row.signature)?)?,
-            param_list: self.read(RowRange::new(row.param_list, next.map(|x| x.param_list)))?,
+            param_list: self.recover(
+                "ParamList",
+                self.read(RowRange::new(row.param_list, next.map(|x| x.param_list))),
+            )?,
+            body_source: Weak::new(),
+            semantics: MethodSemanticsFlags::empty(),
+            generic_params: Vec::new(),
+            pinvoke: None,
+            custom_attributes: Vec::new(),
         })
     }
 }
@@ -562,7 +1104,10 @@ impl Display for Method {
                 if !i.is_zero() {
                     write!(f, ", ")?;
                 }
-                write!(f, "M{}", i)?;
+                match self.generic_params.get(i as usize) {
+                    Some(param) => write!(f, "{}", param.value())?,
+                    None => write!(f, "M{}", i)?,
+                }
             }
             write!(f, ">")?;
         }
@@ -584,9 +1129,29 @@ pub struct Param {
     pub flags: ParamFlags,
     pub sequence: u16,
     pub name: String,
+
+    pub(crate) default_value: Option<ConstantValue>,
+    pub(crate) marshal: Option<Vec<u8>>,
+    pub(crate) custom_attributes: Vec<Ptr<CustomAttribute>>,
 }
 
 impl Param {
+    /// Creates a parameter. Use [`Module::add_param`](crate::Module::add_param)
+    /// to add it to a method.
+    ///
+    /// The sequence is the position of the parameter starting at 1,
+    /// with 0 refering to the return value.
+    pub fn new(flags: ParamFlags, sequence: u16, name: impl Into<String>) -> Self {
+        Self {
+            flags,
+            sequence,
+            name: name.into(),
+            default_value: None,
+            marshal: None,
+            custom_attributes: Vec::new(),
+        }
+    }
+
     pub fn flags(&self) -> ParamFlags {
         self.flags
     }
@@ -596,6 +1161,26 @@ impl Param {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// The value used when an optional parameter is omitted.
+    pub fn default_value(&self) -> Option<&ConstantValue> {
+        self.default_value.as_ref()
+    }
+
+    /// Returns the custom attributes applied to this parameter.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for param in module.all_params().values() {
+    ///     for attribute in param.custom_attributes().values() {
+    ///         println!("[{}] {}", attribute, param.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
 }
 
 impl<'a> ReadEntry<Param> for EntryReader<'a> {
@@ -609,7 +1194,10 @@ impl<'a> ReadEntry<Param> for EntryReader<'a> {
         Ok(Param {
             flags: row.flags,
             sequence: row.sequence,
-            name: self.read(row.name)?,
+            name: self.read_string("Name", row.name)?,
+            default_value: None,
+            marshal: None,
+            custom_attributes: Vec::new(),
         })
     }
 }
@@ -621,7 +1209,7 @@ pub struct InterfaceImpl {
 }
 
 impl InterfaceImpl {
-    pub fn class(&self) -> EntryView<TypeDef> {
+    pub fn class(&self) -> EntryView<'_, TypeDef> {
         EntryView(&self.class)
     }
     pub fn interface(&self) -> &TypeDefOrRef {
@@ -644,79 +1232,1747 @@ impl<'a> ReadEntry<InterfaceImpl> for EntryReader<'a> {
     }
 }
 
+// A type along with the type it is nested in.
+// The nested type is moved into its enclosing type after the module is loaded.
 #[derive(Debug, Clone)]
-pub struct ModuleRef {
-    pub(crate) name: String,
+pub(crate) struct NestedClassRow {
+    pub(crate) nested_class: Ptr<TypeDef>,
+    pub(crate) enclosing_class: Ptr<TypeDef>,
 }
 
-impl ModuleRef {
-    pub fn name(&self) -> &str {
-        &self.name
+impl<'a> ReadEntry<NestedClassRow> for EntryReader<'a> {
+    type RawRow = NestedClassTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<NestedClassRow> {
+        if row.nested_class.0 == row.enclosing_class.0 {
+            return Err(HaoError::BadImageFormat("Type is nested in itself"));
+        }
+        Ok(NestedClassRow {
+            nested_class: self.read(row.nested_class)?,
+            enclosing_class: self.read(row.enclosing_class)?,
+        })
     }
 }
 
-impl<'a> ReadEntry<ModuleRef> for EntryReader<'a> {
-    type RawRow = ModuleRefTableRow;
+// The explicit packing and size of a type from the `ClassLayout` table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ClassLayout {
+    pub(crate) packing_size: u16,
+    pub(crate) class_size: u32,
+}
+
+// A class layout along with the type it belongs to.
+// The layout is moved into its type after the module is loaded.
+#[derive(Debug, Clone)]
+pub(crate) struct ClassLayoutRow {
+    pub(crate) parent: Ptr<TypeDef>,
+    pub(crate) layout: ClassLayout,
+}
+
+impl<'a> ReadEntry<ClassLayoutRow> for EntryReader<'a> {
+    type RawRow = ClassLayoutTableRow;
     fn from_row(
         &self,
         _: usize,
         row: &Self::RawRow,
         _next: Option<&Self::RawRow>,
-    ) -> Result<ModuleRef> {
-        Ok(ModuleRef {
-            name: self.read(row.name)?,
+    ) -> Result<ClassLayoutRow> {
+        Ok(ClassLayoutRow {
+            parent: self.read(row.parent)?,
+            layout: ClassLayout {
+                packing_size: row.packing_size,
+                class_size: row.class_size,
+            },
         })
     }
 }
 
+// The offset of a field in a type with an explicit layout.
 #[derive(Debug, Clone)]
-pub struct TypeSpec {
-    pub(crate) signature: TypeSignature,
+pub(crate) struct FieldLayoutRow {
+    pub(crate) field: Ptr<Field>,
+    pub(crate) offset: u32,
 }
 
-impl TypeSpec {
-    pub fn signature(&self) -> &TypeSignature {
-        &self.signature
+impl<'a> ReadEntry<FieldLayoutRow> for EntryReader<'a> {
+    type RawRow = FieldLayoutTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<FieldLayoutRow> {
+        Ok(FieldLayoutRow {
+            field: self.read(row.field)?,
+            offset: row.offset,
+        })
     }
 }
 
-impl<'a> ReadEntry<TypeSpec> for EntryReader<'a> {
-    type RawRow = TypeSpecTableRow;
+// The location of the initial value of a field.
+// The data itself is copied out of the image after the module is loaded,
+// once the size of the field type is known.
+#[derive(Debug, Clone)]
+pub(crate) struct FieldRvaRow {
+    pub(crate) field: Ptr<Field>,
+    pub(crate) rva: u32,
+}
+
+impl<'a> ReadEntry<FieldRvaRow> for EntryReader<'a> {
+    type RawRow = FieldRVATableRow;
     fn from_row(
         &self,
         _: usize,
         row: &Self::RawRow,
         _next: Option<&Self::RawRow>,
-    ) -> Result<TypeSpec> {
-        Ok(TypeSpec {
-            signature: TypeSignature::from_sig_def(self.read(row.signature)?)?,
+    ) -> Result<FieldRvaRow> {
+        Ok(FieldRvaRow {
+            field: self.read(row.field)?,
+            rva: row.rva,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct AssemblyRef {
-    pub(crate) major_version: u16,
-    pub(crate) minor_version: u16,
-    pub(crate) build_number: u16,
-    pub(crate) revision_number: u16,
-    pub(crate) flags: AssemblyFlags,
-    //pub public_key_or_token: Vec<u8>,
-    pub(crate) name: String,
-    pub(crate) locale: String,
-    //pub hash_value: Vec<u8>,
-    pub(crate) refrenced_assembly: Option<Rc<Module>>,
+pub(crate) enum HasFieldMarshalPtr {
+    Field(Ptr<Field>),
+    Param(Ptr<Param>),
 }
 
-impl AssemblyRef {
-    const KNOWN_CORLIB_NAMES: &[&'static str] = &[
-        "System.Private.CoreLib",
-        "netstandard",
-        "mscorlib",
-        "corefx",
-        "System.Runtime",
+impl GetEntryField<CodedToken<HasFieldMarshalToken>> for MaybeUninitEntries {
+    type EntryFieldValue = HasFieldMarshalPtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<HasFieldMarshalToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => {
+                return Err(HaoError::InvalidCodedTokenOffset(
+                    identifier.rid,
+                    "HasFieldMarshalToken",
+                ))
+            }
+        };
+
+        let val = match identifier.target {
+            HasFieldMarshalToken::Field => self
+                .fields
+                .get(index)
+                .cloned()
+                .map(HasFieldMarshalPtr::Field),
+            HasFieldMarshalToken::Param => self
+                .params
+                .get(index)
+                .cloned()
+                .map(HasFieldMarshalPtr::Param),
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "HasFieldMarshalToken",
+        ))
+    }
+}
+
+// A native type blob along with the field or parameter it belongs to.
+#[derive(Debug, Clone)]
+pub(crate) struct FieldMarshalRow {
+    pub(crate) parent: HasFieldMarshalPtr,
+    pub(crate) native_type: Vec<u8>,
+}
+
+impl<'a> ReadEntry<FieldMarshalRow> for EntryReader<'a> {
+    type RawRow = FieldMarshalTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<FieldMarshalRow> {
+        Ok(FieldMarshalRow {
+            parent: self.read(row.parent)?,
+            native_type: self.read_blob(row.native_type)?,
+        })
+    }
+}
+
+// Where a pinvoke method is imported from, from the `ImplMap` table.
+#[derive(Debug, Clone)]
+pub(crate) struct ImplMap {
+    pub(crate) flags: PInvokeMapFlags,
+    pub(crate) import_name: String,
+    pub(crate) import_scope: Ptr<ModuleRef>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum MemberForwardedPtr {
+    Field,
+    Method(Ptr<Method>),
+}
+
+impl GetEntryField<CodedToken<MemberForwardedToken>> for MaybeUninitEntries {
+    type EntryFieldValue = MemberForwardedPtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<MemberForwardedToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => {
+                return Err(HaoError::InvalidCodedTokenOffset(
+                    identifier.rid,
+                    "MemberForwardedToken",
+                ))
+            }
+        };
+
+        let val = match identifier.target {
+            MemberForwardedToken::Field => {
+                self.fields.get(index).map(|_| MemberForwardedPtr::Field)
+            }
+            MemberForwardedToken::Method => self
+                .methods
+                .get(index)
+                .cloned()
+                .map(MemberForwardedPtr::Method),
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "MemberForwardedToken",
+        ))
+    }
+}
+
+// A pinvoke import along with the member it belongs to.
+// Only methods can be pinvoke imports, so imports on fields are dropped when loaded.
+#[derive(Debug, Clone)]
+pub(crate) struct ImplMapRow {
+    pub(crate) member: MemberForwardedPtr,
+    pub(crate) map: ImplMap,
+}
+
+impl<'a> ReadEntry<ImplMapRow> for EntryReader<'a> {
+    type RawRow = ImplMapTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<ImplMapRow> {
+        Ok(ImplMapRow {
+            member: self.read(row.member_forwarded)?,
+            map: ImplMap {
+                flags: row.mapping_flags,
+                import_name: self.read_string("ImportName", row.import_name)?,
+                import_scope: self.read(row.import_scope)?,
+            },
+        })
+    }
+}
+
+// A method that explicitly implements an interface or base class method,
+// from the `MethodImpl` table.
+#[derive(Debug, Clone)]
+pub(crate) struct MethodImpl {
+    pub(crate) body: MethodDefOrRef,
+    pub(crate) declaration: MethodDefOrRef,
+}
+
+// A method impl along with the type it belongs to.
+#[derive(Debug, Clone)]
+pub(crate) struct MethodImplRow {
+    pub(crate) class: Ptr<TypeDef>,
+    pub(crate) method_impl: MethodImpl,
+}
+
+impl<'a> ReadEntry<MethodImplRow> for EntryReader<'a> {
+    type RawRow = MethodImplTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<MethodImplRow> {
+        Ok(MethodImplRow {
+            class: self.read(row.class)?,
+            method_impl: MethodImpl {
+                body: MethodDefOrRef::from_ent_ptr_must(self.read(row.method_body)?)?,
+                declaration: MethodDefOrRef::from_ent_ptr_must(self.read(row.method_declaration)?)?,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuleRef {
+    pub(crate) name: String,
+}
+
+impl ModuleRef {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<'a> ReadEntry<ModuleRef> for EntryReader<'a> {
+    type RawRow = ModuleRefTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<ModuleRef> {
+        Ok(ModuleRef {
+            name: self.read_string("Name", row.name)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeSpec {
+    pub(crate) signature: TypeSignature,
+}
+
+impl TypeSpec {
+    pub fn signature(&self) -> &TypeSignature {
+        &self.signature
+    }
+}
+
+impl<'a> ReadEntry<TypeSpec> for EntryReader<'a> {
+    type RawRow = TypeSpecTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<TypeSpec> {
+        Ok(TypeSpec {
+            signature: TypeSignature::from_sig_def(self.read(row.signature)?)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StandAloneSig {
+    pub(crate) signature: StandAloneSignature,
+}
+
+impl StandAloneSig {
+    pub fn signature(&self) -> &StandAloneSignature {
+        &self.signature
+    }
+}
+
+impl<'a> ReadEntry<StandAloneSig> for EntryReader<'a> {
+    type RawRow = StandAloneSigTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<StandAloneSig> {
+        Ok(StandAloneSig {
+            signature: StandAloneSignature::from_sig_def(self.read(row.signature)?)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum MemberRefParentPtr {
+    TypeDef(Ptr<TypeDef>),
+    TypeRef(Ptr<TypeRef>),
+    ModuleRef(Ptr<ModuleRef>),
+    Method(Ptr<Method>),
+    TypeSpec(Ptr<TypeSpec>),
+    None,
+}
+
+impl GetEntryField<CodedToken<MemberRefParentToken>> for MaybeUninitEntries {
+    type EntryFieldValue = MemberRefParentPtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<MemberRefParentToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => return Ok(MemberRefParentPtr::None),
+        };
+
+        let val = match identifier.target {
+            MemberRefParentToken::TypeDef => self
+                .type_defs
+                .get(index)
+                .cloned()
+                .map(MemberRefParentPtr::TypeDef),
+            MemberRefParentToken::TypeRef => self
+                .type_refs
+                .get(index)
+                .cloned()
+                .map(MemberRefParentPtr::TypeRef),
+            MemberRefParentToken::ModuleRef => self
+                .module_ref
+                .get(index)
+                .cloned()
+                .map(MemberRefParentPtr::ModuleRef),
+            MemberRefParentToken::Method => self
+                .methods
+                .get(index)
+                .cloned()
+                .map(MemberRefParentPtr::Method),
+            MemberRefParentToken::TypeSpec => self
+                .type_specs
+                .get(index)
+                .cloned()
+                .map(MemberRefParentPtr::TypeSpec),
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "MemberRefParentToken",
+        ))
+    }
+}
+
+/// A refrence to a field or method, usually defined in another module.
+#[derive(Debug, Clone)]
+pub struct MemberRef {
+    pub(crate) parent: MemberRefParent,
+    pub(crate) name: String,
+    pub(crate) signature: MemberRefSignature,
+}
+
+impl MemberRef {
+    /// The type (or module for global members) that declares the member.
+    ///
+    /// This is a [`MemberRefParent::Method`] for the call site of a vararg method.
+    pub fn parent(&self) -> &MemberRefParent {
+        &self.parent
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn signature(&self) -> &MemberRefSignature {
+        &self.signature
+    }
+
+    pub fn is_method(&self) -> bool {
+        matches!(self.signature, MemberRefSignature::Method(_))
+    }
+
+    pub fn is_field(&self) -> bool {
+        matches!(self.signature, MemberRefSignature::Field(_))
+    }
+}
+
+impl<'a> ReadEntry<MemberRef> for EntryReader<'a> {
+    type RawRow = MemberRefTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<MemberRef> {
+        Ok(MemberRef {
+            parent: MemberRefParent::from_ent_ptr_must(self.read(row.class)?)?,
+            name: self.read_string("Name", row.name)?,
+            signature: MemberRefSignature::from_sig_def(self.read(row.signature)?)?,
+        })
+    }
+}
+
+impl Display for MemberRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}::{}", self.parent, self.name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum MethodDefOrRefPtr {
+    Method(Ptr<Method>),
+    MemberRef(Ptr<MemberRef>),
+    None,
+}
+
+impl GetEntryField<CodedToken<CustomAttributeTypeToken>> for MaybeUninitEntries {
+    type EntryFieldValue = MethodDefOrRefPtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<CustomAttributeTypeToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => return Ok(MethodDefOrRefPtr::None),
+        };
+
+        let val = match identifier.target {
+            CustomAttributeTypeToken::Method => self
+                .methods
+                .get(index)
+                .cloned()
+                .map(MethodDefOrRefPtr::Method),
+            CustomAttributeTypeToken::MemberRef => self
+                .member_refs
+                .get(index)
+                .cloned()
+                .map(MethodDefOrRefPtr::MemberRef),
+            _ => None,
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "CustomAttributeTypeToken",
+        ))
+    }
+}
+
+impl GetEntryField<CodedToken<MethodDefOrRefToken>> for MaybeUninitEntries {
+    type EntryFieldValue = MethodDefOrRefPtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<MethodDefOrRefToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => return Ok(MethodDefOrRefPtr::None),
+        };
+
+        let val = match identifier.target {
+            MethodDefOrRefToken::Method => self
+                .methods
+                .get(index)
+                .cloned()
+                .map(MethodDefOrRefPtr::Method),
+            MethodDefOrRefToken::MemberRef => self
+                .member_refs
+                .get(index)
+                .cloned()
+                .map(MethodDefOrRefPtr::MemberRef),
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "MethodDefOrRefToken",
+        ))
+    }
+}
+
+/// An instantiation of a generic method.
+///
+/// Calls to generic methods refrence these rather than the method itself.
+#[derive(Debug, Clone)]
+pub struct MethodSpec {
+    pub(crate) method: MethodDefOrRef,
+    pub(crate) instantiation: MethodSpecSignature,
+}
+
+impl MethodSpec {
+    /// The generic method being instantiated.
+    pub fn method(&self) -> &MethodDefOrRef {
+        &self.method
+    }
+
+    /// The type arguments of the instantiation.
+    pub fn instantiation(&self) -> &MethodSpecSignature {
+        &self.instantiation
+    }
+}
+
+impl<'a> ReadEntry<MethodSpec> for EntryReader<'a> {
+    type RawRow = MethodSpecTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<MethodSpec> {
+        Ok(MethodSpec {
+            method: MethodDefOrRef::from_ent_ptr_must(self.read(row.method)?)?,
+            instantiation: MethodSpecSignature::from_sig_def(self.read(row.instantiation)?)?,
+        })
+    }
+}
+
+impl Display for MethodSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.method, self.instantiation)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum HasConstantPtr {
+    Field(Ptr<Field>),
+    Param(Ptr<Param>),
+    Property(Ptr<Property>),
+}
+
+impl GetEntryField<CodedToken<HasConstantToken>> for MaybeUninitEntries {
+    type EntryFieldValue = HasConstantPtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<HasConstantToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => {
+                return Err(HaoError::InvalidCodedTokenOffset(
+                    identifier.rid,
+                    "HasConstantToken",
+                ))
+            }
+        };
+
+        let val = match identifier.target {
+            HasConstantToken::Field => self.fields.get(index).cloned().map(HasConstantPtr::Field),
+            HasConstantToken::Param => self.params.get(index).cloned().map(HasConstantPtr::Param),
+            HasConstantToken::Property => self
+                .properties
+                .get(index)
+                .cloned()
+                .map(HasConstantPtr::Property),
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "HasConstantToken",
+        ))
+    }
+}
+
+// A constant along with the entry it belongs to.
+// The value is moved into its parent after the module is loaded.
+#[derive(Debug, Clone)]
+pub(crate) struct ConstantRow {
+    pub(crate) parent: HasConstantPtr,
+    pub(crate) value: ConstantValue,
+}
+
+impl<'a> ReadEntry<ConstantRow> for EntryReader<'a> {
+    type RawRow = ConstantTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<ConstantRow> {
+        let blob = self.streams.blob_stream.get_blob(row.value.0)?;
+        Ok(ConstantRow {
+            parent: self.read(row.parent)?,
+            value: ConstantValue::from_blob(row.ty, blob.reader.remaning_slice())?,
+        })
+    }
+}
+
+// The parents that hold their custom attributes have their own variant,
+// the others are kept on the attribute itself.
+#[derive(Debug, Clone)]
+pub(crate) enum HasCustomAttributePtr {
+    Module(Ptr<ModuleDef>),
+    TypeDef(Ptr<TypeDef>),
+    Field(Ptr<Field>),
+    Method(Ptr<Method>),
+    Param(Ptr<Param>),
+    Property(Ptr<Property>),
+    Event(Ptr<Event>),
+    GenericParam(Ptr<GenericParam>),
+    Assembly(Ptr<Assembly>),
+    Other(OtherAttributeParent),
+}
+
+// The parent of an attribute on an entry that does not hold its custom attributes.
+// These attributes are only listed in `Module::custom_attributes`.
+#[derive(Debug, Clone)]
+pub(crate) enum OtherAttributeParent {
+    TypeRef(Ptr<TypeRef>),
+    MemberRef(Ptr<MemberRef>),
+    TypeSpec(Ptr<TypeSpec>),
+    ModuleRef(Ptr<ModuleRef>),
+    AssemblyRef(Ptr<AssemblyRef>),
+    StandAloneSig(Ptr<StandAloneSig>),
+    MethodSpec(Ptr<MethodSpec>),
+    File(Ptr<FileDef>),
+    ExportedType(Ptr<ExportedType>),
+    ManifestResource(Ptr<ManifestResource>),
+    // The interface at the index in the interfaces of the type.
+    InterfaceImpl(Ptr<TypeDef>, usize),
+    // The constraint at the index in the constraints of the generic parameter.
+    GenericParamConstraint(Ptr<GenericParam>, usize),
+    // DeclSecurity rows are not loaded.
+    DeclSecurity,
+}
+
+// The owner of the row at `index` and its position in the rows of that owner.
+fn position_in_owner<T, O>(
+    rows: &[RefCell<MaybeUnsetEntry<T>>],
+    index: usize,
+    owner: impl Fn(&T) -> &Ptr<O>,
+) -> Option<(Ptr<O>, usize)> {
+    let row = rows.get(index)?.borrow();
+    if !row.is_set() {
+        return None;
+    }
+    let row_owner = owner(row.as_ref()).clone();
+    let position = rows[..index]
+        .iter()
+        .filter(|r| {
+            let r = r.borrow();
+            r.is_set() && owner(r.as_ref()).ptr_eq(&row_owner)
+        })
+        .count();
+    Some((row_owner, position))
+}
+
+impl GetEntryField<CodedToken<HasCustomAttributeToken>> for MaybeUninitEntries {
+    type EntryFieldValue = HasCustomAttributePtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<HasCustomAttributeToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => {
+                return Err(HaoError::InvalidCodedTokenOffset(
+                    identifier.rid,
+                    "HasCustomAttributeToken",
+                ))
+            }
+        };
+
+        let val = match identifier.target {
+            HasCustomAttributeToken::Module => self
+                .modules
+                .get(index)
+                .cloned()
+                .map(HasCustomAttributePtr::Module),
+            HasCustomAttributeToken::TypeDef => self
+                .type_defs
+                .get(index)
+                .cloned()
+                .map(HasCustomAttributePtr::TypeDef),
+            HasCustomAttributeToken::Field => self
+                .fields
+                .get(index)
+                .cloned()
+                .map(HasCustomAttributePtr::Field),
+            HasCustomAttributeToken::Method => self
+                .methods
+                .get(index)
+                .cloned()
+                .map(HasCustomAttributePtr::Method),
+            HasCustomAttributeToken::Param => self
+                .params
+                .get(index)
+                .cloned()
+                .map(HasCustomAttributePtr::Param),
+            HasCustomAttributeToken::Property => self
+                .properties
+                .get(index)
+                .cloned()
+                .map(HasCustomAttributePtr::Property),
+            HasCustomAttributeToken::Event => self
+                .events
+                .get(index)
+                .cloned()
+                .map(HasCustomAttributePtr::Event),
+            HasCustomAttributeToken::GenericParam => self
+                .generic_params
+                .get(index)
+                .cloned()
+                .map(HasCustomAttributePtr::GenericParam),
+            HasCustomAttributeToken::Assembly => self
+                .assembly
+                .get(index)
+                .cloned()
+                .map(HasCustomAttributePtr::Assembly),
+            HasCustomAttributeToken::TypeRef => self
+                .type_refs
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::TypeRef)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::MemberRef => self
+                .member_refs
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::MemberRef)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::TypeSpec => self
+                .type_specs
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::TypeSpec)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::ModuleRef => self
+                .module_ref
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::ModuleRef)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::AssemblyRef => self
+                .assembly_ref
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::AssemblyRef)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::StandAloneSig => self
+                .stand_alone_sigs
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::StandAloneSig)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::MethodSpec => self
+                .method_specs
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::MethodSpec)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::File => self
+                .files
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::File)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::ExportedType => self
+                .exported_types
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::ExportedType)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::ManifestResource => self
+                .manifest_resources
+                .get(index)
+                .cloned()
+                .map(OtherAttributeParent::ManifestResource)
+                .map(HasCustomAttributePtr::Other),
+            HasCustomAttributeToken::InterfaceImpl => {
+                position_in_owner(&self.interface_impls, index, |i| &i.class)
+                    .map(|(class, position)| OtherAttributeParent::InterfaceImpl(class, position))
+                    .map(HasCustomAttributePtr::Other)
+            }
+            HasCustomAttributeToken::GenericParamConstraint => {
+                position_in_owner(&self.generic_param_constraints, index, |c| &c.owner)
+                    .map(|(owner, position)| {
+                        OtherAttributeParent::GenericParamConstraint(owner, position)
+                    })
+                    .map(HasCustomAttributePtr::Other)
+            }
+            HasCustomAttributeToken::DeclSecurity => Some(HasCustomAttributePtr::Other(
+                OtherAttributeParent::DeclSecurity,
+            )),
+            HasCustomAttributeToken::Unused1 | HasCustomAttributeToken::Unused2 => None,
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "HasCustomAttributeToken",
+        ))
+    }
+}
+
+/// An attribute applied to a type, member, parameter or module.
+///
+/// The arguments are decoded from the attribute blob each time they are read.
+#[derive(Debug, Clone)]
+pub struct CustomAttribute {
+    pub(crate) constructor: MethodDefOrRef,
+    pub(crate) attribute_type: TypeDefOrRef,
+    pub(crate) value: Vec<u8>,
+    pub(crate) other_parent: Option<OtherAttributeParent>,
+    pub(crate) source: Weak<AttributeSource>,
+}
+
+impl CustomAttribute {
+    /// The constructor of the attribute.
+    pub fn constructor(&self) -> &MethodDefOrRef {
+        &self.constructor
+    }
+
+    /// The type that declares the constructor of the attribute.
+    pub fn attribute_type(&self) -> &TypeDefOrRef {
+        &self.attribute_type
+    }
+
+    /// The raw blob the arguments are decoded from.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Decodes the values passed to the constructor, in parameter order.
+    ///
+    /// Enums defined in another assembly can only be decoded once that assembly
+    /// is loaded, see [`Module::load_dependancies`](crate::Module::load_dependancies).
+    /// Otherwise [`HaoError::UnresolvedEnum`] is returned, as the size of the value is not known.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for attribute in module.all_custom_attributes().values() {
+    ///     match attribute.fixed_args() {
+    ///         Ok(args) => println!("{} has {} arguments", attribute.attribute_type(), args.len()),
+    ///         Err(err) => println!("{} could not be decoded. {}", attribute.attribute_type(), err),
+    ///     }
+    /// }
+    /// ```
+    pub fn fixed_args(&self) -> Result<Vec<CustomAttributeValue>> {
+        self.arguments().map(|(fixed_args, _)| fixed_args)
+    }
+
+    /// Decodes the fields and properties set by the attribute.
+    ///
+    /// See [`fixed_args`](Self::fixed_args).
+    pub fn named_args(&self) -> Result<Vec<CustomAttributeNamedArg>> {
+        self.arguments().map(|(_, named_args)| named_args)
+    }
+
+    fn arguments(&self) -> Result<(Vec<CustomAttributeValue>, Vec<CustomAttributeNamedArg>)> {
+        let parameters = match &self.constructor {
+            MethodDefOrRef::Method(m) => m.value().signature().parameters.clone(),
+            MethodDefOrRef::MemberRef(m) => match m.value().signature() {
+                MemberRefSignature::Method(sig) => sig.parameters.clone(),
+                MemberRefSignature::Field(_) => {
+                    return Err(HaoError::InvalidSignatureForEntry(std::any::type_name::<
+                        CustomAttribute,
+                    >()))
+                }
+            },
+        };
+        // Without the module the attribute was loaded from, only enums
+        // refrenced through the constructor signature can be found.
+        let source = self.source.upgrade().unwrap_or_default();
+        CustomAttributeReader::new(&self.value, &source)?.read_arguments(&parameters)
+    }
+
+    /// Checks if the attribute type has the given namespace and name.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for ty in module.types().values() {
+    ///     let obfuscated = ty
+    ///         .custom_attributes()
+    ///         .values()
+    ///         .any(|a| a.attribute_type_is("System.Reflection", "ObfuscationAttribute"));
+    ///     println!("{} {}", ty.name(), obfuscated);
+    /// }
+    /// ```
+    pub fn attribute_type_is(&self, namespace: &str, name: &str) -> bool {
+        match &self.attribute_type {
+            TypeDefOrRef::TypeDef(d) => d.value().full_name_is(namespace, name),
+            TypeDefOrRef::TypeRef(r) => r.value().full_name_is(namespace, name),
+            TypeDefOrRef::TypeSpec(_) => false,
+        }
+    }
+}
+
+impl Display for CustomAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.attribute_type)?;
+        let Ok((fixed_args, named_args)) = self.arguments() else {
+            return write!(f, "(..)");
+        };
+        if fixed_args.is_empty() && named_args.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "(")?;
+        let args = fixed_args
+            .iter()
+            .map(|a| a as &dyn Display)
+            .chain(named_args.iter().map(|a| a as &dyn Display));
+        for (index, arg) in args.enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", arg)?;
+        }
+        write!(f, ")")
+    }
+}
+
+// A custom attribute along with the entry it is applied to.
+// The attribute is moved into its parent after the module is loaded.
+#[derive(Debug, Clone)]
+pub(crate) struct CustomAttributeRow {
+    pub(crate) parent: HasCustomAttributePtr,
+    pub(crate) attribute: CustomAttribute,
+}
+
+impl<'a> EntryReader<'a> {
+    fn custom_attribute_type(&self, constructor: &MethodDefOrRef) -> Result<TypeDefOrRef> {
+        match constructor {
+            MethodDefOrRef::MemberRef(member) => match member.value().parent() {
+                MemberRefParent::TypeDef(d) => Ok(TypeDefOrRef::TypeDef(d.clone())),
+                MemberRefParent::TypeRef(r) => Ok(TypeDefOrRef::TypeRef(r.clone())),
+                MemberRefParent::TypeSpec(s) => Ok(TypeDefOrRef::TypeSpec(s.clone())),
+                _ => Err(HaoError::BadImageFormat(
+                    "Custom attribute constructor is not declared on a type",
+                )),
+            },
+            MethodDefOrRef::Method(method) => self
+                .method_owners()
+                .get(&Rc::as_ptr(&method.0 .0))
+                .map(|ty| TypeDefOrRef::TypeDef(Entry(ty.clone())))
+                .ok_or(HaoError::BadImageFormat(
+                    "Custom attribute constructor is not declared on a type",
+                )),
+        }
+    }
+
+    // The type that declares each method, built the first time it is needed.
+    fn method_owners(&self) -> &HashMap<*const RefCell<MaybeUnsetEntry<Method>>, Ptr<TypeDef>> {
+        self.method_owners.get_or_init(|| {
+            let mut owners = HashMap::new();
+            for ty in self.entries.type_defs.iter() {
+                let Some(value) = ty.try_value() else {
+                    continue;
+                };
+                for method in value.method_list.iter() {
+                    owners.insert(Rc::as_ptr(&method.0), ty.clone());
+                }
+            }
+            owners
+        })
+    }
+}
+
+// Custom attributes are read after every other table, as finding
+// the attribute type needs the method lists of every type.
+impl<'a> ReadEntry<CustomAttributeRow> for EntryReader<'a> {
+    type RawRow = CustomAttributeTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<CustomAttributeRow> {
+        let constructor = MethodDefOrRef::from_ent_ptr_must(self.read(row.ty)?)?;
+        let attribute_type = self.custom_attribute_type(&constructor)?;
+        let parent = self.read(row.parent)?;
+        let other_parent = match &parent {
+            HasCustomAttributePtr::Other(other) => Some(other.clone()),
+            _ => None,
+        };
+
+        Ok(CustomAttributeRow {
+            parent,
+            attribute: CustomAttribute {
+                constructor,
+                attribute_type,
+                value: self.read_blob(row.value)?,
+                other_parent,
+                source: Weak::new(),
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Property {
+    pub(crate) flags: PropertyFlags,
+    pub(crate) name: String,
+    pub(crate) signature: PropertySignature,
+    pub(crate) getter: Option<Ptr<Method>>,
+    pub(crate) setter: Option<Ptr<Method>>,
+    pub(crate) other_methods: Vec<Ptr<Method>>,
+    pub(crate) constant: Option<ConstantValue>,
+    pub(crate) custom_attributes: Vec<Ptr<CustomAttribute>>,
+}
+
+impl Property {
+    pub fn flags(&self) -> PropertyFlags {
+        self.flags
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn signature(&self) -> &PropertySignature {
+        &self.signature
+    }
+
+    /// The `get` accessor of the property.
+    pub fn getter(&self) -> Option<EntryView<'_, Method>> {
+        self.getter.as_ref().map(EntryView)
+    }
+
+    /// The `set` accessor of the property.
+    pub fn setter(&self) -> Option<EntryView<'_, Method>> {
+        self.setter.as_ref().map(EntryView)
+    }
+
+    /// Any other methods associated with the property.
+    pub fn other_methods(&self) -> EntryCollection<'_, Method> {
+        EntryCollection::new(&self.other_methods)
+    }
+
+    /// The default value of the property.
+    /// This is only set when [`PropertyFlags::HasDefault`] is.
+    pub fn constant(&self) -> Option<&ConstantValue> {
+        self.constant.as_ref()
+    }
+
+    /// Returns the custom attributes applied to this property.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for property in module.all_properties().values() {
+    ///     for attribute in property.custom_attributes().values() {
+    ///         println!("[{}] {}", attribute, property.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
+}
+
+impl<'a> ReadEntry<Property> for EntryReader<'a> {
+    type RawRow = PropertyTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<Property> {
+        Ok(Property {
+            flags: row.prop_flags,
+            name: self.read_string("Name", row.name)?,
+            signature: PropertySignature::from_sig_def(self.read(row.ty)?)?,
+            getter: None,
+            setter: None,
+            other_methods: Vec::new(),
+            constant: None,
+            custom_attributes: Vec::new(),
+        })
+    }
+}
+
+impl Display for Property {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(accessor) = self.getter.as_ref().or(self.setter.as_ref()) {
+            let flags = accessor.value().flags;
+            if flags.contains(MethodFlags::Public) {
+                write!(f, "public ")?;
+            } else if flags.contains(MethodFlags::Private) {
+                write!(f, "private ")?;
+            }
+            if flags.contains(MethodFlags::Static) {
+                write!(f, "static ")?;
+            }
+        }
+
+        write!(f, "{} {}", self.signature.property_type, self.name)?;
+        if !self.signature.parameters.is_empty() {
+            write!(f, "[")?;
+            for (index, param) in self.signature.parameters.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", param)?;
+            }
+            write!(f, "]")?;
+        }
+
+        write!(f, " {{ ")?;
+        if self.getter.is_some() {
+            write!(f, "get; ")?;
+        }
+        if self.setter.is_some() {
+            write!(f, "set; ")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub(crate) flags: EventFlags,
+    pub(crate) name: String,
+    pub(crate) event_type: TypeDefOrRef,
+    pub(crate) adder: Option<Ptr<Method>>,
+    pub(crate) remover: Option<Ptr<Method>>,
+    pub(crate) raiser: Option<Ptr<Method>>,
+    pub(crate) other_methods: Vec<Ptr<Method>>,
+    pub(crate) custom_attributes: Vec<Ptr<CustomAttribute>>,
+}
+
+impl Event {
+    pub fn flags(&self) -> EventFlags {
+        self.flags
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The delegate type of the event.
+    pub fn event_type(&self) -> &TypeDefOrRef {
+        &self.event_type
+    }
+
+    /// The `add` accessor of the event.
+    pub fn adder(&self) -> Option<EntryView<'_, Method>> {
+        self.adder.as_ref().map(EntryView)
+    }
+
+    /// The `remove` accessor of the event.
+    pub fn remover(&self) -> Option<EntryView<'_, Method>> {
+        self.remover.as_ref().map(EntryView)
+    }
+
+    /// The method used to fire the event.
+    /// C# does not emit this, but other languages may.
+    pub fn raiser(&self) -> Option<EntryView<'_, Method>> {
+        self.raiser.as_ref().map(EntryView)
+    }
+
+    /// Any other methods associated with the event.
+    pub fn other_methods(&self) -> EntryCollection<'_, Method> {
+        EntryCollection::new(&self.other_methods)
+    }
+
+    /// Returns the custom attributes applied to this event.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for event in module.all_events().values() {
+    ///     for attribute in event.custom_attributes().values() {
+    ///         println!("[{}] {}", attribute, event.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
+}
+
+impl<'a> ReadEntry<Event> for EntryReader<'a> {
+    type RawRow = EventTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<Event> {
+        Ok(Event {
+            flags: row.event_flags,
+            name: self.read_string("Name", row.name)?,
+            event_type: TypeDefOrRef::from_ent_ptr_must(self.read(row.event_type)?)?,
+            adder: None,
+            remover: None,
+            raiser: None,
+            other_methods: Vec::new(),
+            custom_attributes: Vec::new(),
+        })
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(accessor) = self.adder.as_ref().or(self.remover.as_ref()) {
+            let flags = accessor.value().flags;
+            if flags.contains(MethodFlags::Public) {
+                write!(f, "public ")?;
+            } else if flags.contains(MethodFlags::Private) {
+                write!(f, "private ")?;
+            }
+            if flags.contains(MethodFlags::Static) {
+                write!(f, "static ")?;
+            }
+        }
+        write!(f, "event {} {}", self.event_type, self.name)
+    }
+}
+
+// The properties owned by a type.
+// These are moved into the type after the module is loaded.
+#[derive(Debug, Clone)]
+pub(crate) struct PropertyMapRow {
+    pub(crate) parent: Ptr<TypeDef>,
+    pub(crate) properties: Vec<Ptr<Property>>,
+}
+
+impl<'a> ReadEntry<PropertyMapRow> for EntryReader<'a> {
+    type RawRow = PropertyMapTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        next: Option<&Self::RawRow>,
+    ) -> Result<PropertyMapRow> {
+        Ok(PropertyMapRow {
+            parent: self.read(row.parent)?,
+            properties: self.recover(
+                "PropertyList",
+                self.read(RowRange::new(
+                    row.property_list,
+                    next.map(|x| x.property_list),
+                )),
+            )?,
+        })
+    }
+}
+
+// The events owned by a type.
+// These are moved into the type after the module is loaded.
+#[derive(Debug, Clone)]
+pub(crate) struct EventMapRow {
+    pub(crate) parent: Ptr<TypeDef>,
+    pub(crate) events: Vec<Ptr<Event>>,
+}
+
+impl<'a> ReadEntry<EventMapRow> for EntryReader<'a> {
+    type RawRow = EventMapTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        next: Option<&Self::RawRow>,
+    ) -> Result<EventMapRow> {
+        Ok(EventMapRow {
+            parent: self.read(row.parent)?,
+            events: self.recover(
+                "EventList",
+                self.read(RowRange::new(row.event_list, next.map(|x| x.event_list))),
+            )?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum HasSemanticPtr {
+    Event(Ptr<Event>),
+    Property(Ptr<Property>),
+}
+
+impl GetEntryField<CodedToken<HasSemanticToken>> for MaybeUninitEntries {
+    type EntryFieldValue = HasSemanticPtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<HasSemanticToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => {
+                return Err(HaoError::InvalidCodedTokenOffset(
+                    identifier.rid,
+                    "HasSemanticToken",
+                ))
+            }
+        };
+
+        let val = match identifier.target {
+            HasSemanticToken::Event => self.events.get(index).cloned().map(HasSemanticPtr::Event),
+            HasSemanticToken::Property => self
+                .properties
+                .get(index)
+                .cloned()
+                .map(HasSemanticPtr::Property),
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "HasSemanticToken",
+        ))
+    }
+}
+
+// Links an accessor method to its property or event.
+// The method is moved into its association after the module is loaded.
+#[derive(Debug, Clone)]
+pub(crate) struct MethodSemanticsRow {
+    pub(crate) semantic: MethodSemanticsFlags,
+    pub(crate) method: Ptr<Method>,
+    pub(crate) association: HasSemanticPtr,
+}
+
+impl<'a> ReadEntry<MethodSemanticsRow> for EntryReader<'a> {
+    type RawRow = MethodSemanticsTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<MethodSemanticsRow> {
+        Ok(MethodSemanticsRow {
+            semantic: row.semantic,
+            method: self.read(row.method)?,
+            association: self.read(row.association)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum TypeOrMethodDefPtr {
+    TypeDef(Ptr<TypeDef>),
+    Method(Ptr<Method>),
+    None,
+}
+
+impl GetEntryField<CodedToken<TypeOrMethodDefToken>> for MaybeUninitEntries {
+    type EntryFieldValue = TypeOrMethodDefPtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<TypeOrMethodDefToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => return Ok(TypeOrMethodDefPtr::None),
+        };
+
+        let val = match identifier.target {
+            TypeOrMethodDefToken::TypeDef => self
+                .type_defs
+                .get(index)
+                .cloned()
+                .map(TypeOrMethodDefPtr::TypeDef),
+            TypeOrMethodDefToken::Method => self
+                .methods
+                .get(index)
+                .cloned()
+                .map(TypeOrMethodDefPtr::Method),
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "TypeOrMethodDefToken",
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GenericParamVariance {
+    NonVariant,
+    Covariant,
+    Contravariant,
+}
+
+/// A generic parameter of a type or method.
+#[derive(Debug, Clone)]
+pub struct GenericParam {
+    pub(crate) number: u16,
+    pub(crate) flags: GenericParamFlags,
+    pub(crate) owner: TypeOrMethodDef,
+    pub(crate) name: String,
+    pub(crate) constraints: Vec<TypeDefOrRef>,
+    pub(crate) custom_attributes: Vec<Ptr<CustomAttribute>>,
+}
+
+impl GenericParam {
+    /// The index of the parameter in the generic parameters of its owner.
+    pub fn number(&self) -> u16 {
+        self.number
+    }
+    pub fn flags(&self) -> GenericParamFlags {
+        self.flags
+    }
+    /// The type or method that declares this parameter.
+    pub fn owner(&self) -> &TypeOrMethodDef {
+        &self.owner
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The variance of the parameter.
+    /// Only generic interfaces and delegates can have variant parameters.
+    pub fn variance(&self) -> GenericParamVariance {
+        match self.flags & GenericParamFlags::VarianceMask {
+            GenericParamFlags::Covariant => GenericParamVariance::Covariant,
+            GenericParamFlags::Contravariant => GenericParamVariance::Contravariant,
+            _ => GenericParamVariance::NonVariant,
+        }
+    }
+
+    /// The `class`, `struct` and `new()` constraints of the parameter.
+    pub fn special_constraints(&self) -> GenericParamFlags {
+        self.flags & GenericParamFlags::SpecialConstraintMask
+    }
+
+    /// The types the parameter is constrained to.
+    pub fn constraints(&self) -> &[TypeDefOrRef] {
+        &self.constraints
+    }
+
+    /// Returns the custom attributes applied to this generic parameter.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for param in module.all_generic_params().values() {
+    ///     for attribute in param.custom_attributes().values() {
+    ///         println!("[{}] {}", attribute, param.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
+}
+
+impl<'a> ReadEntry<GenericParam> for EntryReader<'a> {
+    type RawRow = GenericParamTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<GenericParam> {
+        Ok(GenericParam {
+            number: row.number,
+            flags: row.flags,
+            owner: TypeOrMethodDef::from_ent_ptr_must(self.read(row.owner)?)?,
+            name: self.read_string("Name", row.name)?,
+            constraints: Vec::new(),
+            custom_attributes: Vec::new(),
+        })
+    }
+}
+
+impl Display for GenericParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.variance() {
+            GenericParamVariance::Covariant => write!(f, "out ")?,
+            GenericParamVariance::Contravariant => write!(f, "in ")?,
+            GenericParamVariance::NonVariant => {}
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+// A type constraint along with the generic parameter it applies to.
+// The constraint is moved into its parameter after the module is loaded.
+#[derive(Debug, Clone)]
+pub(crate) struct GenericParamConstraintRow {
+    pub(crate) owner: Ptr<GenericParam>,
+    pub(crate) constraint: TypeDefOrRef,
+}
+
+impl<'a> ReadEntry<GenericParamConstraintRow> for EntryReader<'a> {
+    type RawRow = GenericParamConstraintTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<GenericParamConstraintRow> {
+        Ok(GenericParamConstraintRow {
+            owner: self.read(row.owner)?,
+            constraint: TypeDefOrRef::from_ent_ptr_must(self.read(row.constraint)?)?,
+        })
+    }
+}
+
+/// Computes the public key token of a strong name public key.
+///
+/// This is the last 8 bytes of the SHA-1 hash of the key, in reverse order.
+/// ```
+/// # use hao::dotnet::entries::values::public_key_token;
+/// // The ECMA standard public key.
+/// let key = [0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0];
+///
+/// assert_eq!(public_key_token(&key), [0xb7, 0x7a, 0x5c, 0x56, 0x19, 0x34, 0xe0, 0x89]);
+/// ```
+pub fn public_key_token(public_key: &[u8]) -> [u8; 8] {
+    let hash = sha1_smol::Sha1::from(public_key).digest().bytes();
+    let mut token = [0; 8];
+    token.copy_from_slice(&hash[hash.len() - 8..]);
+    token.reverse();
+    token
+}
+
+// Writes the display name of an assembly, escaping the
+// characters that would otherwise be read as part of the syntax.
+fn write_display_name(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    version: (u16, u16, u16, u16),
+    locale: &str,
+    public_key_token: &[u8],
+    flags: AssemblyFlags,
+) -> std::fmt::Result {
+    for c in name.chars() {
+        if matches!(c, ',' | '=' | '"' | '\'' | '\\') {
+            write!(f, "\\")?;
+        }
+        write!(f, "{c}")?;
+    }
+
+    let (major, minor, build, revision) = version;
+    write!(f, ", Version={major}.{minor}.{build}.{revision}")?;
+    write!(
+        f,
+        ", Culture={}",
+        if locale.is_empty() { "neutral" } else { locale }
+    )?;
+
+    write!(f, ", PublicKeyToken=")?;
+    if public_key_token.is_empty() {
+        write!(f, "null")?;
+    }
+    for b in public_key_token {
+        write!(f, "{b:02x}")?;
+    }
+
+    if flags.contains(AssemblyFlags::Retargetable) {
+        write!(f, ", Retargetable=Yes")?;
+    }
+    Ok(())
+}
+
+/// The assembly manifest of the module, from the `Assembly` table.
+#[derive(Debug, Clone)]
+pub struct Assembly {
+    pub(crate) hash_alg_id: u32,
+    pub(crate) major_version: u16,
+    pub(crate) minor_version: u16,
+    pub(crate) build_number: u16,
+    pub(crate) revision_number: u16,
+    pub(crate) flags: AssemblyFlags,
+    pub(crate) public_key: Vec<u8>,
+    pub(crate) name: String,
+    pub(crate) locale: String,
+    pub(crate) custom_attributes: Vec<Ptr<CustomAttribute>>,
+}
+
+impl Assembly {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn version(&self) -> (u16, u16, u16, u16) {
+        (
+            self.major_version,
+            self.minor_version,
+            self.build_number,
+            self.revision_number,
+        )
+    }
+    pub fn flags(&self) -> AssemblyFlags {
+        self.flags
+    }
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+    /// The algorithm used to hash the files of the assembly.
+    pub fn hash_alg_id(&self) -> u32 {
+        self.hash_alg_id
+    }
+    /// The public key the assembly is signed with. Empty if the assembly has no strong name.
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+    /// The public key token of the assembly. Empty if the assembly has no strong name.
+    pub fn public_key_token(&self) -> Vec<u8> {
+        if self.public_key.is_empty() {
+            Vec::new()
+        } else {
+            public_key_token(&self.public_key).to_vec()
+        }
+    }
+
+    /// The display name of the assembly.
+    /// e.g. `Example, Version=1.0.0.0, Culture=neutral, PublicKeyToken=null`.
+    pub fn full_name(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the custom attributes applied to the assembly.
+    pub fn custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
+}
+
+impl Display for Assembly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_display_name(
+            f,
+            &self.name,
+            self.version(),
+            &self.locale,
+            &self.public_key_token(),
+            self.flags,
+        )
+    }
+}
+
+impl<'a> ReadEntry<Assembly> for EntryReader<'a> {
+    type RawRow = AssemblyTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<Assembly> {
+        Ok(Assembly {
+            hash_alg_id: row.hash_alg_id,
+            major_version: row.major_version,
+            minor_version: row.minor_version,
+            build_number: row.build_number,
+            revision_number: row.revision_number,
+            flags: row.flags,
+            public_key: self.read_blob(row.public_key)?,
+            name: self.read_string("Name", row.name)?,
+            locale: self.read_string("Culture", row.locale)?,
+            custom_attributes: Vec::new(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AssemblyRef {
+    pub(crate) major_version: u16,
+    pub(crate) minor_version: u16,
+    pub(crate) build_number: u16,
+    pub(crate) revision_number: u16,
+    pub(crate) flags: AssemblyFlags,
+    pub(crate) public_key_or_token: Vec<u8>,
+    pub(crate) name: String,
+    pub(crate) locale: String,
+    pub(crate) hash_value: Vec<u8>,
+    pub(crate) refrenced_assembly: Option<Rc<Module>>,
+}
+
+impl AssemblyRef {
+    const KNOWN_CORLIB_NAMES: &[&'static str] = &[
+        "System.Private.CoreLib",
+        "netstandard",
+        "mscorlib",
+        "corefx",
+        "System.Runtime",
     ];
 
+    /// Creates a refrence to an assembly. Use [`Module::add_assembly_ref`](crate::Module::add_assembly_ref)
+    /// to add it to a module.
+    ///
+    /// `public_key_or_token` is the public key token, or the full public key
+    /// if [`AssemblyFlags::PublicKey`] is set.
+    pub fn new(
+        name: impl Into<String>,
+        version: (u16, u16, u16, u16),
+        flags: AssemblyFlags,
+        public_key_or_token: Vec<u8>,
+    ) -> Self {
+        let (major_version, minor_version, build_number, revision_number) = version;
+        Self {
+            major_version,
+            minor_version,
+            build_number,
+            revision_number,
+            flags,
+            public_key_or_token,
+            name: name.into(),
+            locale: String::new(),
+            hash_value: Vec::new(),
+            refrenced_assembly: None,
+        }
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -735,6 +2991,57 @@ impl AssemblyRef {
         &self.locale
     }
 
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+    pub fn set_version(&mut self, version: (u16, u16, u16, u16)) {
+        (
+            self.major_version,
+            self.minor_version,
+            self.build_number,
+            self.revision_number,
+        ) = version;
+    }
+    pub fn set_locale(&mut self, locale: impl Into<String>) {
+        self.locale = locale.into();
+    }
+
+    /// The full public key if [`AssemblyFlags::PublicKey`] is set, otherwise the public key token.
+    pub fn public_key_or_token(&self) -> &[u8] {
+        &self.public_key_or_token
+    }
+
+    /// The public key token of the refrenced assembly, computed from
+    /// the public key if the refrence holds the full key.
+    /// Empty if the refrenced assembly has no strong name.
+    pub fn public_key_token(&self) -> Vec<u8> {
+        if self.flags.contains(AssemblyFlags::PublicKey) && !self.public_key_or_token.is_empty() {
+            public_key_token(&self.public_key_or_token).to_vec()
+        } else {
+            self.public_key_or_token.clone()
+        }
+    }
+
+    /// The display name of the refrenced assembly.
+    ///
+    /// ```
+    /// # use hao::dotnet::{entries::values::AssemblyRef, md::streams::tables_stream::AssemblyFlags};
+    /// let token = vec![0xb0, 0x3f, 0x5f, 0x7f, 0x11, 0xd5, 0x0a, 0x3a];
+    /// let corlib = AssemblyRef::new("System.Runtime", (8, 0, 0, 0), AssemblyFlags::empty(), token);
+    ///
+    /// assert_eq!(
+    ///     corlib.full_name(),
+    ///     "System.Runtime, Version=8.0.0.0, Culture=neutral, PublicKeyToken=b03f5f7f11d50a3a"
+    /// );
+    /// ```
+    pub fn full_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn hash_value(&self) -> &[u8] {
+        &self.hash_value
+    }
+
     pub fn is_corlib(&self) -> bool {
         Self::KNOWN_CORLIB_NAMES
             .iter()
@@ -742,6 +3049,19 @@ impl AssemblyRef {
     }
 }
 
+impl Display for AssemblyRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_display_name(
+            f,
+            &self.name,
+            self.version(),
+            &self.locale,
+            &self.public_key_token(),
+            self.flags,
+        )
+    }
+}
+
 impl<'a> ReadEntry<AssemblyRef> for EntryReader<'a> {
     type RawRow = AssemblyRefTableRow;
     fn from_row(
@@ -756,10 +3076,437 @@ impl<'a> ReadEntry<AssemblyRef> for EntryReader<'a> {
             build_number: row.build_number,
             revision_number: row.revision_number,
             flags: row.flags,
-            name: self.read(row.name)?,
-            locale: self.read(row.locale)?,
+            public_key_or_token: self.read_blob(row.public_key_or_token)?,
+            name: self.read_string("Name", row.name)?,
+            locale: self.read_string("Culture", row.locale)?,
+            hash_value: self.read_blob(row.hash_value)?,
 
             refrenced_assembly: None,
         })
     }
 }
+
+/// A file of a multi-file assembly, from the `File` table.
+#[derive(Debug, Clone)]
+pub struct FileDef {
+    pub(crate) flags: FileFlags,
+    pub(crate) name: String,
+    pub(crate) hash_value: Vec<u8>,
+}
+
+impl FileDef {
+    pub fn flags(&self) -> FileFlags {
+        self.flags
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn hash_value(&self) -> &[u8] {
+        &self.hash_value
+    }
+    /// Returns true if the file is a module with its own metadata,
+    /// rather than a resource file.
+    pub fn contains_metadata(&self) -> bool {
+        !self.flags.contains(FileFlags::ContainsNoMetaData)
+    }
+}
+
+impl<'a> ReadEntry<FileDef> for EntryReader<'a> {
+    type RawRow = FileTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<FileDef> {
+        Ok(FileDef {
+            flags: row.flags,
+            name: self.read_string("Name", row.name)?,
+            hash_value: self.read_blob(row.hash_value)?,
+        })
+    }
+}
+
+impl Display for FileDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum ImplementationPtr {
+    File(Ptr<FileDef>),
+    AssemblyRef(Ptr<AssemblyRef>),
+    ExportedType(Ptr<ExportedType>),
+    None,
+}
+
+impl GetEntryField<CodedToken<ImplementationToken>> for MaybeUninitEntries {
+    type EntryFieldValue = ImplementationPtr;
+
+    fn get_entry_field(
+        &self,
+        identifier: CodedToken<ImplementationToken>,
+    ) -> Result<Self::EntryFieldValue> {
+        let index = match (identifier.rid as usize).checked_sub(1) {
+            Some(v) => v,
+            None => return Ok(ImplementationPtr::None),
+        };
+
+        let val = match identifier.target {
+            ImplementationToken::File => {
+                self.files.get(index).cloned().map(ImplementationPtr::File)
+            }
+            ImplementationToken::AssemblyRef => self
+                .assembly_ref
+                .get(index)
+                .cloned()
+                .map(ImplementationPtr::AssemblyRef),
+            ImplementationToken::ExportedType => self
+                .exported_types
+                .get(index)
+                .cloned()
+                .map(ImplementationPtr::ExportedType),
+        };
+        val.ok_or(HaoError::InvalidCodedTokenOffset(
+            identifier.rid,
+            "ImplementationToken",
+        ))
+    }
+}
+
+/// A resource of the assembly, from the `ManifestResource` table.
+///
+/// The data of a resource is either embedded in the module,
+/// or is found in another file or assembly given by [`ManifestResource::implementation`].
+#[derive(Debug, Clone)]
+pub struct ManifestResource {
+    pub(crate) offset: u32,
+    pub(crate) flags: ManifestResourceFlags,
+    pub(crate) name: String,
+    pub(crate) implementation: Option<Implementation>,
+    pub(crate) data: Option<Vec<u8>>,
+}
+
+impl ManifestResource {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn flags(&self) -> ManifestResourceFlags {
+        self.flags
+    }
+    /// Either [`ManifestResourceFlags::Public`] or [`ManifestResourceFlags::Private`].
+    pub fn visibility(&self) -> ManifestResourceFlags {
+        self.flags & ManifestResourceFlags::VisibilityMask
+    }
+    pub fn is_public(&self) -> bool {
+        self.visibility() == ManifestResourceFlags::Public
+    }
+    /// The offset of the resource in the resources of the module, or in the linked file.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+    /// The file or assembly that holds the resource.
+    /// `None` if the resource is embedded in the module.
+    pub fn implementation(&self) -> Option<&Implementation> {
+        self.implementation.as_ref()
+    }
+    pub fn is_embedded(&self) -> bool {
+        self.implementation.is_none()
+    }
+    /// The data of an embedded resource.
+    ///
+    /// `None` if the resource is not embedded, or its data is outside of the image.
+    /// ```no_run
+    /// # use hao::Module;
+    /// let module = Module::from_path_no_resolve(r#"Example.Net.dll"#).unwrap();
+    ///
+    /// for resource in module.resources().values() {
+    ///     if let Some(data) = resource.data() {
+    ///         std::fs::write(resource.name(), data).unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn data(&self) -> Option<&[u8]> {
+        self.data.as_deref()
+    }
+}
+
+impl<'a> ReadEntry<ManifestResource> for EntryReader<'a> {
+    type RawRow = ManifestResourceTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<ManifestResource> {
+        Ok(ManifestResource {
+            offset: row.offset,
+            flags: row.flags,
+            name: self.read_string("Name", row.name)?,
+            implementation: Implementation::from_ent_pointer(self.read(row.implementation)?),
+            // Read once the module is loaded, as it needs the resources directory.
+            data: None,
+        })
+    }
+}
+
+impl Display for ManifestResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A type of the assembly that is defined in another of its files,
+/// or forwarded to another assembly, from the `ExportedType` table.
+#[derive(Debug, Clone)]
+pub struct ExportedType {
+    pub(crate) flags: TypeAttributes,
+    pub(crate) type_def_id: u32,
+    pub(crate) name: String,
+    pub(crate) namespace: String,
+    pub(crate) implementation: Implementation,
+}
+
+impl ExportedType {
+    pub fn flags(&self) -> TypeAttributes {
+        self.flags
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+    /// The row of the type in the module that defines it. Only a hint, and may be 0.
+    pub fn type_def_id(&self) -> u32 {
+        self.type_def_id
+    }
+    /// The file or assembly that defines the type,
+    /// or the exported type it is nested in.
+    pub fn implementation(&self) -> &Implementation {
+        &self.implementation
+    }
+
+    /// Returns true if the type has been moved to another assembly.
+    pub fn is_forwarder(&self) -> bool {
+        self.flags.contains(TypeAttributes::Forwarder)
+    }
+
+    /// The exported type this type is nested in.
+    pub fn declaring_type(&self) -> Option<&Entry<ExportedType>> {
+        match self.implementation() {
+            Implementation::ExportedType(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    pub fn is_nested(&self) -> bool {
+        self.declaring_type().is_some()
+    }
+
+    pub fn full_name_is(&self, namespace: &str, name: &str) -> bool {
+        (namespace, name) == (self.namespace(), self.name())
+    }
+
+    /// Returns the full name of the type with nested types seperated by `/`.
+    pub fn full_name(&self) -> String {
+        self.full_name_with('/')
+    }
+
+    /// Returns the full name of the type as used by reflection,
+    /// with nested types seperated by `+`.
+    pub fn reflection_full_name(&self) -> String {
+        self.full_name_with('+')
+    }
+
+    fn full_name_with(&self, separator: char) -> String {
+        nested_full_name(self, separator, Self::name, Self::namespace, |t| {
+            t.declaring_type().map(|d| d.0.clone())
+        })
+    }
+}
+
+impl<'a> ReadEntry<ExportedType> for EntryReader<'a> {
+    type RawRow = ExportedTypeTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<ExportedType> {
+        Ok(ExportedType {
+            flags: row.flags,
+            type_def_id: row.type_def_id.0,
+            name: self.read_string("TypeName", row.type_name)?,
+            namespace: self.read_string("TypeNamespace", row.type_namespace)?,
+            implementation: Implementation::from_ent_ptr_must(self.read(row.implementation)?)?,
+        })
+    }
+}
+
+impl Display for ExportedType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.full_name())
+    }
+}
+
+// A row of one of the `FieldPtr`, `MethodPtr`, `ParamPtr`, `EventPtr` or `PropertyPtr`
+// indirection tables found in uncompressed (#-) metadata.
+// Member lists of a parent row index into these rather than the member table itself.
+#[derive(Debug, Clone)]
+pub(crate) struct PointerRow<T>(pub(crate) Ptr<T>);
+
+impl<'a> ReadEntry<PointerRow<Field>> for EntryReader<'a> {
+    type RawRow = FieldPtrTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<PointerRow<Field>> {
+        Ok(PointerRow(self.read(row.field)?))
+    }
+}
+
+impl<'a> ReadEntry<PointerRow<Method>> for EntryReader<'a> {
+    type RawRow = MethodPtrTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<PointerRow<Method>> {
+        Ok(PointerRow(self.read(row.method)?))
+    }
+}
+
+impl<'a> ReadEntry<PointerRow<Param>> for EntryReader<'a> {
+    type RawRow = ParamPtrTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<PointerRow<Param>> {
+        Ok(PointerRow(self.read(row.param)?))
+    }
+}
+
+impl<'a> ReadEntry<PointerRow<Event>> for EntryReader<'a> {
+    type RawRow = EventPtrTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<PointerRow<Event>> {
+        Ok(PointerRow(self.read(row.event)?))
+    }
+}
+
+impl<'a> ReadEntry<PointerRow<Property>> for EntryReader<'a> {
+    type RawRow = PropertyPtrTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<PointerRow<Property>> {
+        Ok(PointerRow(self.read(row.property)?))
+    }
+}
+
+/// An edit-and-continue log record from the `ENCLog` table.
+#[derive(Debug, Clone)]
+pub struct EncLog {
+    pub(crate) token: MDToken,
+    pub(crate) func_code: u32,
+}
+
+impl EncLog {
+    /// The metadata token of the row that was edited.
+    pub fn token(&self) -> MDToken {
+        self.token
+    }
+
+    /// The kind of edit that was made.
+    pub fn func_code(&self) -> u32 {
+        self.func_code
+    }
+}
+
+impl<'a> ReadEntry<EncLog> for EntryReader<'a> {
+    type RawRow = ENCLogTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<EncLog> {
+        Ok(EncLog {
+            token: MDToken::from_raw(row.token),
+            func_code: row.func_code,
+        })
+    }
+}
+
+/// An edit-and-continue token mapping from the `ENCMap` table.
+#[derive(Debug, Clone)]
+pub struct EncMap {
+    pub(crate) token: MDToken,
+}
+
+impl EncMap {
+    /// The metadata token of the mapped row.
+    pub fn token(&self) -> MDToken {
+        self.token
+    }
+}
+
+impl<'a> ReadEntry<EncMap> for EntryReader<'a> {
+    type RawRow = ENCMapTableRow;
+    fn from_row(
+        &self,
+        _: usize,
+        row: &Self::RawRow,
+        _next: Option<&Self::RawRow>,
+    ) -> Result<EncMap> {
+        Ok(EncMap {
+            token: MDToken::from_raw(row.token),
+        })
+    }
+}
+
+macro_rules! table_entries {
+    ($($entry:ident => $table:ident),* $(,)?) => {
+        $(
+            impl TableEntry for $entry {
+                const TABLE: TableId = TableId::$table;
+            }
+        )*
+    };
+}
+
+table_entries! {
+    ModuleDef => Module,
+    TypeRef => TypeRef,
+    TypeDef => TypeDef,
+    Field => Field,
+    Method => Method,
+    Param => Param,
+    MemberRef => MemberRef,
+    CustomAttribute => CustomAttribute,
+    StandAloneSig => StandAloneSig,
+    Event => Event,
+    Property => Property,
+    ModuleRef => ModuleRef,
+    TypeSpec => TypeSpec,
+    Assembly => Assembly,
+    AssemblyRef => AssemblyRef,
+    FileDef => File,
+    ManifestResource => ManifestResource,
+    ExportedType => ExportedType,
+    GenericParam => GenericParam,
+    MethodSpec => MethodSpec,
+}
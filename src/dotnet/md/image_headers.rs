@@ -1,3 +1,5 @@
+use bitflags::bitflags;
+
 use crate::{
     error::Result,
     io::{DataReader, ReadData},
@@ -16,4 +18,18 @@ impl ImageDataDirectory {
             data_size: r.read()?,
         })
     }
-}
\ No newline at end of file
+}
+
+bitflags! {
+    /// The flags of the cor20 (CLI) header. See ECMA-335 II.25.3.3.1.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct ComImageFlags: u32 {
+        const ILOnly            =   0x00000001;
+        const Requires32Bit     =   0x00000002;
+        const ILLibrary         =   0x00000004;
+        const StrongNameSigned  =   0x00000008;
+        const NativeEntryPoint  =   0x00000010;
+        const TrackDebugData    =   0x00010000;
+        const Prefers32Bit      =   0x00020000;
+    }
+}
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use crate::{
     error::{HaoError, Result},
-    io::{DataReader, ReadData},
+    io::{DataReader, DataWriter, ReadData, Writable, WriteData},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,7 +23,7 @@ impl<'a> MetadataHeader<'a> {
     pub fn from_reader(r: &mut DataReader<'a>) -> Result<Self> {
         let signature = r.read()?;
 
-        if signature != 0x424A5342 {
+        if signature != Self::SIGNATURE {
             return Err(crate::error::HaoError::BadImageFormat(
                 "Invalid metadata header signature",
             ));
@@ -65,6 +65,8 @@ impl<'a> MetadataHeader<'a> {
         })
     }
 
+    pub const SIGNATURE: u32 = 0x424A5342;
+
     pub fn metadata_type(&self) -> Result<MetadataType> {
         self.streams
             .iter()
@@ -78,6 +80,29 @@ impl<'a> MetadataHeader<'a> {
     }
 }
 
+impl<'a> Writable for MetadataHeader<'a> {
+    fn to_writer(&self, writer: &mut DataWriter) -> Result<()> {
+        writer.write(self.signature)?;
+        writer.write(self.major_version)?;
+        writer.write(self.minor_version)?;
+        writer.write(0u32)?;
+
+        // The version string is null terminated and padded to a multiple of 4 bytes.
+        let str_len = (self.version.len() + 1 + 3) & !3;
+        writer.write(str_len as u32)?;
+        writer.write_slice(self.version.as_bytes());
+        writer.write_zeros(str_len - self.version.len());
+
+        writer.write(self.flags)?;
+        writer.write(0u8)?;
+        writer.write(self.streams.len() as u16)?;
+        for stream in self.streams.iter() {
+            writer.write(stream)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct StreamHeader<'a> {
     pub offset: u32,
@@ -118,3 +143,15 @@ impl<'a> StreamHeader<'a> {
         })
     }
 }
+
+impl<'a> Writable for StreamHeader<'a> {
+    fn to_writer(&self, writer: &mut DataWriter) -> Result<()> {
+        writer.write(self.offset)?;
+        writer.write(self.stream_size)?;
+
+        let name_len = (self.name.len() + 1 + 3) & !3;
+        writer.write_slice(self.name.as_bytes());
+        writer.write_zeros(name_len - self.name.len());
+        Ok(())
+    }
+}
@@ -2,11 +2,100 @@ mod calculator;
 pub mod image_headers;
 pub mod metadata_header;
 pub mod streams;
+use std::fmt::Display;
+
 use bitflags::bitflags;
+use num_traits::FromPrimitive;
+
+use self::streams::tables_stream::TableId;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A metadata token, refrencing a row of a metadata table or a string in the `#US` heap.
+///
+/// The high byte is the table and the low 3 bytes are the row id (RID).
+/// Row id's start at 1, so a RID of 0 is a null token.
+/// See ECMA-335 II.22 and III.1.9.
+///
+/// ```
+/// # use hao::dotnet::md::{MDToken, streams::tables_stream::TableId};
+/// let token = MDToken::new(TableId::Method, 1);
+///
+/// assert_eq!(token.raw(), 0x06000001);
+/// assert_eq!(token.table(), Some(TableId::Method));
+/// assert_eq!(token.rid(), 1);
+/// assert_eq!(token.to_string(), "0x06000001");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct MDToken(u32);
 
+impl MDToken {
+    /// The table byte of a token refrencing the `#US` heap.
+    pub const USER_STRING: u32 = 0x70;
+    const RID_MASK: u32 = 0x00FF_FFFF;
+
+    /// A token for the row `rid` of `table`.
+    pub fn new(table: TableId, rid: u32) -> Self {
+        Self(((table as u32) << 24) | (rid & Self::RID_MASK))
+    }
+
+    /// A token for the string at `offset` in the `#US` heap.
+    pub fn user_string(offset: u32) -> Self {
+        Self((Self::USER_STRING << 24) | (offset & Self::RID_MASK))
+    }
+
+    #[inline(always)]
+    pub fn from_raw(token: u32) -> Self {
+        Self(token)
+    }
+
+    #[inline(always)]
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+
+    /// The table the token refrences.
+    ///
+    /// `None` for user string tokens and unknown tables.
+    pub fn table(&self) -> Option<TableId> {
+        TableId::from_u32(self.0 >> 24)
+    }
+
+    /// Returns true if the token refrences a string in the `#US` heap.
+    #[inline(always)]
+    pub fn is_user_string(&self) -> bool {
+        self.0 >> 24 == Self::USER_STRING
+    }
+
+    /// The row id of the token, or the heap offset for a user string token.
+    #[inline(always)]
+    pub fn rid(&self) -> u32 {
+        self.0 & Self::RID_MASK
+    }
+
+    /// Returns true if the token does not refrence a row.
+    #[inline(always)]
+    pub fn is_null(&self) -> bool {
+        self.rid() == 0
+    }
+}
+
+impl From<u32> for MDToken {
+    fn from(token: u32) -> Self {
+        Self(token)
+    }
+}
+
+impl From<MDToken> for u32 {
+    fn from(token: MDToken) -> Self {
+        token.0
+    }
+}
+
+impl Display for MDToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#010x}", self.0)
+    }
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct MDStreamFlags: u8 {
@@ -42,11 +42,8 @@ impl<'a> BlobStream<'a> {
         })
     }
 
-    pub(crate) fn get_signature_reader(
-        &self,
-        offset: u32,
-        entries: &'a MaybeUninitEntries,
-    ) -> Result<SignatureReader> {
+    /// Returns a stream over the contents of the blob at `offset`.
+    pub fn get_blob(&self, offset: u32) -> Result<BlobStream<'a>> {
         let offset = offset as usize;
         let mut new_reader = self.split_from(offset)?;
         let len = new_reader
@@ -61,8 +58,15 @@ impl<'a> BlobStream<'a> {
             ));
         }
 
-        new_reader
-            .slice(0..len)
+        new_reader.slice(0..len)
+    }
+
+    pub(crate) fn get_signature_reader(
+        &self,
+        offset: u32,
+        entries: &'a MaybeUninitEntries,
+    ) -> Result<SignatureReader<'a>> {
+        self.get_blob(offset)
             .map(|r| SignatureReader::new(r, entries))
     }
 
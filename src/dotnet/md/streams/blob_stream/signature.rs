@@ -100,7 +100,7 @@ impl<'a> ReadData<SignatureDef> for SignatureReader<'a> {
         let sig_type: u8 = self.read()?;
 
         let calling_convention = CallingConvention::from_u8(sig_type & CALLING_CONVENTION_MASK)
-            .ok_or_else(|| {
+            .ok_or({
                 HaoError::InvalidSignatureCallingConvention(sig_type & CALLING_CONVENTION_MASK)
             })?;
 
@@ -140,11 +140,13 @@ impl SignatureCallingConvention {
             | CallingConvention::VarArg
             | CallingConvention::Unmanaged
             | CallingConvention::NativeVarArg => {
-                Self::Method(MethodSig::from_reader(reader, flags)?)
+                Self::Method(MethodSig::from_reader(reader, calling_convention, flags)?)
             }
             CallingConvention::Field => Self::Field(reader.read()?),
             CallingConvention::LocalSig => Self::LocalSig(reader.read()?),
-            CallingConvention::Property => Self::Property(MethodSig::from_reader(reader, flags)?),
+            CallingConvention::Property => {
+                Self::Property(MethodSig::from_reader(reader, calling_convention, flags)?)
+            }
             CallingConvention::GenericInst => Self::GenericInstMethod(reader.read()?),
         };
 
@@ -189,7 +191,7 @@ impl From<TypeDefOrRefPtr> for TypeDefOrRefSig {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ArraySize {
     SingleDimention,
     MultiDimention {
@@ -226,8 +228,10 @@ pub enum TypeSigDef {
     Class(TypeDefOrRefSig),
     FnPtr(Box<SignatureDef>),
     SZArray(Box<TypeSigDef>),
-    CModReq(TypeDefOrRefSig),
-    CModOpt(TypeDefOrRefSig),
+    /// A required modifier followed by the type it modifies.
+    CModReq(TypeDefOrRefSig, Box<TypeSigDef>),
+    /// An optional modifier followed by the type it modifies.
+    CModOpt(TypeDefOrRefSig, Box<TypeSigDef>),
     Sentinel,
     Pinned(Box<TypeSigDef>),
 
@@ -270,8 +274,8 @@ impl<'a> ReadData<TypeSigDef> for SignatureReader<'a> {
             ElementType::Void => TypeSigDef::Void,
             ElementType::Boolean => TypeSigDef::Boolean,
             ElementType::Char => TypeSigDef::Char,
-            ElementType::I1 => TypeSigDef::Byte,
-            ElementType::U1 => TypeSigDef::SByte,
+            ElementType::I1 => TypeSigDef::SByte,
+            ElementType::U1 => TypeSigDef::Byte,
             ElementType::I2 => TypeSigDef::Int16,
             ElementType::U2 => TypeSigDef::UInt16,
             ElementType::I4 => TypeSigDef::Int32,
@@ -300,11 +304,13 @@ impl<'a> ReadData<TypeSigDef> for SignatureReader<'a> {
             ElementType::SZArray => TypeSigDef::SZArray(Box::new(self.read()?)),
             ElementType::CModReqd => {
                 let token: CodedToken<TypeDefOrRefToken> = self.read()?;
-                TypeSigDef::CModReq(self.entries.get_entry_field(token)?.into())
+                let modifier = self.entries.get_entry_field(token)?.into();
+                TypeSigDef::CModReq(modifier, Box::new(self.read()?))
             }
             ElementType::CModOpt => {
                 let token: CodedToken<TypeDefOrRefToken> = self.read()?;
-                TypeSigDef::CModOpt(self.entries.get_entry_field(token)?.into())
+                let modifier = self.entries.get_entry_field(token)?.into();
+                TypeSigDef::CModOpt(modifier, Box::new(self.read()?))
             }
             ElementType::Sentinel => TypeSigDef::Sentinel,
             ElementType::Pinned => TypeSigDef::Pinned(Box::new(self.read()?)),
@@ -377,6 +383,7 @@ impl<'a> ReadData<TypeSigDef> for SignatureReader<'a> {
 
 #[derive(Debug, Clone)]
 pub struct MethodSig {
+    pub calling_convention: CallingConvention,
     pub return_type: Box<TypeSigDef>,
     pub generic_params: Option<u32>,
     pub parameters: Vec<TypeSigDef>,
@@ -384,7 +391,11 @@ pub struct MethodSig {
 }
 
 impl MethodSig {
-    pub fn from_reader(reader: &mut SignatureReader, flags: SignatureFlags) -> Result<Self> {
+    pub fn from_reader(
+        reader: &mut SignatureReader,
+        calling_convention: CallingConvention,
+        flags: SignatureFlags,
+    ) -> Result<Self> {
         let generic_params = (flags.contains(SignatureFlags::Generic))
             .then(|| reader.reader.read_compressed_u32())
             .transpose()?;
@@ -394,6 +405,7 @@ impl MethodSig {
 
         if num_params as usize > reader.reader.reader.remaning() {
             return Ok(Self {
+                calling_convention,
                 generic_params: None,
                 parameters: Vec::new(),
                 params_after_sentinel: None,
@@ -405,16 +417,12 @@ impl MethodSig {
         let mut params_after_sentinel: Option<Vec<TypeSigDef>> = None;
 
         for _ in 0..num_params {
-            let ty = reader.read()?;
+            let mut ty = reader.read()?;
             if matches!(ty, TypeSigDef::Sentinel) {
-                let senti_vec = if let Some(v) = &mut params_after_sentinel {
-                    v
-                } else {
-                    params_after_sentinel =
-                        Some(Vec::with_capacity((num_params as usize) - parameters.len()));
-                    params_after_sentinel.as_mut().unwrap()
-                };
-                senti_vec.push(reader.read()?);
+                // The sentinel marks the start of the variable arguments
+                // of a call site, it is not counted as a parameter.
+                params_after_sentinel.get_or_insert_with(Vec::new);
+                ty = reader.read()?;
             }
 
             if let Some(senti_params) = &mut params_after_sentinel {
@@ -425,6 +433,7 @@ impl MethodSig {
         }
 
         Ok(Self {
+            calling_convention,
             return_type,
             generic_params,
             parameters,
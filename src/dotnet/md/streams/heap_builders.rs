@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use crate::{
+    dotnet::md::MDToken,
+    error::{HaoError, Result},
+    io::DataWriter,
+};
+
+// Heaps are padded to a 4 byte boundary when they are written to the metadata.
+fn aligned(mut data: Vec<u8>) -> Vec<u8> {
+    data.resize((data.len() + 3) & !3, 0);
+    data
+}
+
+/// Builds a `#Strings` heap. Identical strings share the same offset.
+#[derive(Debug, Clone)]
+pub struct StringsHeapBuilder {
+    heap_data: Vec<u8>,
+    offsets: HashMap<String, u32>,
+}
+
+impl Default for StringsHeapBuilder {
+    fn default() -> Self {
+        // Offset 0 is always the empty string.
+        Self {
+            heap_data: vec![0],
+            offsets: HashMap::new(),
+        }
+    }
+}
+
+impl StringsHeapBuilder {
+    /// Adds `value` to the heap and returns its offset.
+    pub fn add(&mut self, value: &str) -> u32 {
+        if value.is_empty() {
+            return 0;
+        }
+        if let Some(offset) = self.offsets.get(value) {
+            return *offset;
+        }
+
+        let offset = self.heap_data.len() as u32;
+        self.heap_data.extend_from_slice(value.as_bytes());
+        self.heap_data.push(0);
+        self.offsets.insert(value.to_string(), offset);
+        offset
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap_data.len()
+    }
+
+    pub fn into_heap(self) -> Vec<u8> {
+        aligned(self.heap_data)
+    }
+}
+
+/// Builds a `#Blob` heap. Identical blobs share the same offset.
+#[derive(Debug, Clone)]
+pub struct BlobHeapBuilder {
+    heap_data: DataWriter,
+    offsets: HashMap<Vec<u8>, u32>,
+}
+
+impl Default for BlobHeapBuilder {
+    fn default() -> Self {
+        // Offset 0 is always the empty blob.
+        let mut heap_data = DataWriter::new();
+        heap_data.write_slice(&[0]);
+        Self {
+            heap_data,
+            offsets: HashMap::new(),
+        }
+    }
+}
+
+impl BlobHeapBuilder {
+    /// Adds `value` to the heap and returns its offset.
+    pub fn add(&mut self, value: &[u8]) -> Result<u32> {
+        if value.is_empty() {
+            return Ok(0);
+        }
+        if let Some(offset) = self.offsets.get(value) {
+            return Ok(*offset);
+        }
+
+        let offset = self.heap_data.position() as u32;
+        self.heap_data.write_compressed_u32(value.len() as u32)?;
+        self.heap_data.write_slice(value);
+        self.offsets.insert(value.to_vec(), offset);
+        Ok(offset)
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap_data.position()
+    }
+
+    pub fn into_heap(self) -> Vec<u8> {
+        aligned(self.heap_data.into_inner())
+    }
+}
+
+/// Builds a `#GUID` heap. Identical guids share the same index.
+#[derive(Debug, Clone, Default)]
+pub struct GuidHeapBuilder {
+    heap_data: Vec<u8>,
+    indexes: HashMap<uuid::Uuid, u32>,
+}
+
+impl GuidHeapBuilder {
+    /// Adds `value` to the heap and returns its index.
+    /// Indexes start at 1, the nil guid is always index 0.
+    pub fn add(&mut self, value: uuid::Uuid) -> u32 {
+        if value.is_nil() {
+            return 0;
+        }
+        if let Some(index) = self.indexes.get(&value) {
+            return *index;
+        }
+
+        self.heap_data.extend_from_slice(&value.to_bytes_le());
+        let index = (self.heap_data.len() / 16) as u32;
+        self.indexes.insert(value, index);
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap_data.len()
+    }
+
+    pub fn into_heap(self) -> Vec<u8> {
+        self.heap_data
+    }
+}
+
+/// Builds a `#US` heap. Identical strings share the same offset.
+#[derive(Debug, Clone)]
+pub struct UserStringsHeapBuilder {
+    heap_data: DataWriter,
+    offsets: HashMap<String, u32>,
+}
+
+impl Default for UserStringsHeapBuilder {
+    fn default() -> Self {
+        let mut heap_data = DataWriter::new();
+        heap_data.write_slice(&[0]);
+        Self {
+            heap_data,
+            offsets: HashMap::new(),
+        }
+    }
+}
+
+impl UserStringsHeapBuilder {
+    /// Adds `value` to the heap and returns its offset.
+    pub fn add(&mut self, value: &str) -> Result<u32> {
+        if let Some(offset) = self.offsets.get(value) {
+            return Ok(*offset);
+        }
+
+        let units = value.encode_utf16().collect::<Vec<_>>();
+        let offset = self.heap_data.position() as u32;
+        self.heap_data
+            .write_compressed_u32(units.len() as u32 * 2 + 1)?;
+        for unit in units.iter() {
+            self.heap_data.write_slice(&unit.to_le_bytes());
+        }
+        self.heap_data
+            .write_slice(&[units.iter().any(|u| Self::is_special_char(*u)) as u8]);
+
+        self.offsets.insert(value.to_string(), offset);
+        Ok(offset)
+    }
+
+    /// Adds `value` to the heap and returns the `ldstr` token that refers to it.
+    pub fn add_token(&mut self, value: &str) -> Result<MDToken> {
+        let offset = self.add(value)?;
+        if offset > 0x00FF_FFFF {
+            return Err(HaoError::BadImageFormat(
+                "#US heap is too large to be referenced by a token",
+            ));
+        }
+        Ok(MDToken::user_string(offset))
+    }
+
+    // The final byte of an entry is set when the string contains a character that
+    // needs more than a single byte, or one of the characters listed in ECMA-335 II.24.2.4.
+    fn is_special_char(unit: u16) -> bool {
+        matches!(unit, 0x01..=0x08 | 0x0E..=0x1F | 0x27 | 0x2D | 0x7F) || unit > 0xFF
+    }
+
+    pub fn into_heap(self) -> Vec<u8> {
+        aligned(self.heap_data.into_inner())
+    }
+}
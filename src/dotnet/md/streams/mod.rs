@@ -1,7 +1,9 @@
 mod blob_stream;
+mod heap_builders;
 pub mod tables_stream;
 mod values_streams;
 pub use blob_stream::*;
+pub(crate) use heap_builders::*;
 pub use values_streams::*;
 
 use super::metadata_header::StreamHeader;
@@ -23,6 +25,7 @@ impl Version {
 pub struct MetadataStreams<'a> {
     pub tables_stream: TablesStreams<'a>,
     pub strings_stream: StringsStream<'a>,
+    pub user_strings_stream: UserStringsStream<'a>,
     pub guid_stream: GuidStream<'a>,
     pub blob_stream: BlobStream<'a>,
 }
@@ -34,6 +37,7 @@ impl<'a> MetadataStreams<'a> {
         metadata_virtual_address: u32,
     ) -> Result<Self> {
         let mut strings_stream = None;
+        let mut user_strings_stream = None;
         let mut tables_stream = None;
         let mut blob_stream = None;
         let mut guid_stream = None;
@@ -46,10 +50,10 @@ impl<'a> MetadataStreams<'a> {
 
             match stream_header.name.as_ref() {
                 "#Strings" => strings_stream = Some(StringsStream::from_reader(stream_reader)?),
-                "#US" => continue,
+                "#US" => user_strings_stream = Some(UserStringsStream::from_reader(stream_reader)?),
                 "#Blob" => blob_stream = Some(BlobStream::from_reader(stream_reader)?),
                 "#GUID" => guid_stream = Some(GuidStream::from_reader(stream_reader)?),
-                "#~" => tables_stream = Some(TablesStreams::from_reader(stream_reader)?),
+                "#~" | "#-" => tables_stream = Some(TablesStreams::from_reader(stream_reader)?),
                 "#Pdb" => continue,
                 _ => {}
             }
@@ -57,13 +61,14 @@ impl<'a> MetadataStreams<'a> {
 
         Ok(Self {
             tables_stream: tables_stream
-                .ok_or_else(|| HaoError::BadImageFormat("No .net tables stream (#~)"))?,
+                .ok_or(HaoError::BadImageFormat("No .net tables stream (#~ or #-)"))?,
             strings_stream: strings_stream
-                .ok_or_else(|| HaoError::BadImageFormat("No strings stream (#Strings)"))?,
-            guid_stream: guid_stream
-                .ok_or_else(|| HaoError::BadImageFormat("No guid stream (#GUID)"))?,
-            blob_stream: blob_stream
-                .ok_or_else(|| HaoError::BadImageFormat("No blob stream (#Blob)"))?,
+                .ok_or(HaoError::BadImageFormat("No strings stream (#Strings)"))?,
+            // Assemblies without any user strings can omit the #US heap.
+            user_strings_stream: user_strings_stream
+                .unwrap_or(UserStringsStream { heap_data: &[] }),
+            guid_stream: guid_stream.ok_or(HaoError::BadImageFormat("No guid stream (#GUID)"))?,
+            blob_stream: blob_stream.ok_or(HaoError::BadImageFormat("No blob stream (#Blob)"))?,
         })
     }
 }
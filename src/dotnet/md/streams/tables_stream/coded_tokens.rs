@@ -1,15 +1,15 @@
 use std::fmt::Debug;
 
 use num_derive::{FromPrimitive, ToPrimitive};
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 
 use crate::{
     dotnet::md::streams::tables_stream::metadata::TableRowCount,
     error::{HaoError, Result},
-    io::ReadData,
+    io::{ReadData, WriteData},
 };
 
-use super::{TableRows, TablesStreamReader, ValueSize};
+use super::{TableRows, TablesStreamReader, TablesStreamWriter, ValueSize};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct CodedToken<T: CodedTokenTarget> {
@@ -18,6 +18,26 @@ pub struct CodedToken<T: CodedTokenTarget> {
 }
 
 impl<T: CodedTokenTarget> CodedToken<T> {
+    pub fn new(target: T, rid: u32) -> Self {
+        Self { rid, target }
+    }
+
+    /// A token that does not refer to any row.
+    pub fn null(target: T) -> Self {
+        Self::new(target, 0)
+    }
+
+    pub fn encode(&self) -> Result<u32> {
+        let target = self.target.to_u32().unwrap_or_default();
+        if self.rid > (u32::MAX >> T::BITS) {
+            return Err(HaoError::InvalidCodedTokenOffset(
+                self.rid,
+                std::any::type_name::<T>(),
+            ));
+        }
+        Ok((self.rid << T::BITS) | target)
+    }
+
     pub fn decode(coded_value: u32) -> Result<Self> {
         let offset_mask = 0xFFFFFFFF << T::BITS;
         let target_mask = !offset_mask;
@@ -48,6 +68,16 @@ impl<'a, T: CodedTokenTarget> ReadData<CodedToken<T>> for TablesStreamReader<'a>
     }
 }
 
+impl<'a, T: CodedTokenTarget> WriteData<CodedToken<T>> for TablesStreamWriter<'a> {
+    fn write(&mut self, token: CodedToken<T>) -> Result<()> {
+        let coded_value = token.encode()?;
+        match T::token_size(&self.header.coded_token_sizes) {
+            ValueSize::Big => self.write(coded_value),
+            ValueSize::Small => self.write(coded_value as u16),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct CodedTokenSizes {
     pub type_def_or_ref: ValueSize,
@@ -218,7 +248,7 @@ impl CodedTokenSizes {
     }
 }
 
-pub trait CodedTokenTarget: FromPrimitive {
+pub trait CodedTokenTarget: FromPrimitive + ToPrimitive {
     const BITS: usize;
     fn token_size(sizes: &CodedTokenSizes) -> ValueSize;
 }
@@ -3,7 +3,7 @@ use super::{tables::*, TableLocation, ValueSize};
 use crate::dotnet::md::calculator::{SizeCalculator, TablePositionCalculator};
 use crate::dotnet::md::streams::Version;
 use crate::dotnet::md::MDStreamFlags;
-use crate::io::ReadData;
+use crate::io::{DataWriter, ReadData, WriteData};
 use crate::{error::Result, io::DataReader};
 use bitflags::bitflags;
 
@@ -67,7 +67,7 @@ bitflags! {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub struct TableRowCount(pub u32);
 
 impl TableRowCount {
@@ -90,7 +90,7 @@ impl PartialEq<usize> for TableRowCount {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TableRows {
     pub module: TableRowCount,
     pub type_ref: TableRowCount,
@@ -245,6 +245,95 @@ impl TableRows {
             )?,
         })
     }
+
+    fn counts(&self) -> [(TableExistsFlags, TableRowCount); 53] {
+        [
+            (TableExistsFlags::Module, self.module),
+            (TableExistsFlags::TypeRef, self.type_ref),
+            (TableExistsFlags::TypeDef, self.type_def),
+            (TableExistsFlags::FieldPtr, self.field_ptr),
+            (TableExistsFlags::Field, self.field),
+            (TableExistsFlags::MethodPtr, self.method_ptr),
+            (TableExistsFlags::Method, self.method),
+            (TableExistsFlags::ParamPtr, self.param_ptr),
+            (TableExistsFlags::Param, self.param),
+            (TableExistsFlags::InterfaceImpl, self.interface_impl),
+            (TableExistsFlags::MemberRef, self.member_ref),
+            (TableExistsFlags::Constant, self.constant),
+            (TableExistsFlags::CustomAttribute, self.custom_attribute),
+            (TableExistsFlags::FieldMarshal, self.field_marshal),
+            (TableExistsFlags::DeclSecurity, self.decl_security),
+            (TableExistsFlags::ClassLayout, self.class_layout),
+            (TableExistsFlags::FieldLayout, self.field_layout),
+            (TableExistsFlags::StandAloneSig, self.stand_alone_sig),
+            (TableExistsFlags::EventMap, self.event_map),
+            (TableExistsFlags::EventPtr, self.event_ptr),
+            (TableExistsFlags::Event, self.event),
+            (TableExistsFlags::PropertyMap, self.property_map),
+            (TableExistsFlags::PropertyPtr, self.property_ptr),
+            (TableExistsFlags::Property, self.property),
+            (TableExistsFlags::MethodSemantics, self.method_semantics),
+            (TableExistsFlags::MethodImpl, self.method_impl),
+            (TableExistsFlags::ModuleRef, self.module_ref),
+            (TableExistsFlags::TypeSpec, self.type_spec),
+            (TableExistsFlags::ImplMap, self.impl_map),
+            (TableExistsFlags::FieldRva, self.field_rva),
+            (TableExistsFlags::EncLog, self.enc_log),
+            (TableExistsFlags::EncMap, self.enc_map),
+            (TableExistsFlags::Assembly, self.assembly),
+            (TableExistsFlags::AssemblyProcessor, self.assembly_processor),
+            (TableExistsFlags::AssemblyOs, self.assembly_os),
+            (TableExistsFlags::AssemblyRef, self.assembly_ref),
+            (
+                TableExistsFlags::AssemblyRefProcessor,
+                self.assembly_ref_processor,
+            ),
+            (TableExistsFlags::AssemblyRefOs, self.assembly_ref_os),
+            (TableExistsFlags::File, self.file),
+            (TableExistsFlags::ExportedType, self.exported_type),
+            (TableExistsFlags::ManifestResource, self.manifest_resource),
+            (TableExistsFlags::NestedClass, self.nested_class),
+            (TableExistsFlags::GenericParam, self.generic_param),
+            (TableExistsFlags::MethodSpec, self.method_spec),
+            (
+                TableExistsFlags::GenericParamConstraint,
+                self.generic_param_constraint,
+            ),
+            (TableExistsFlags::Document, self.document),
+            (
+                TableExistsFlags::MethodDebugInformation,
+                self.method_debug_information,
+            ),
+            (TableExistsFlags::LocalScope, self.local_scope),
+            (TableExistsFlags::LocalVariable, self.local_variable),
+            (TableExistsFlags::LocalConstant, self.local_constant),
+            (TableExistsFlags::ImportScope, self.import_scope),
+            (
+                TableExistsFlags::StateMachineMethod,
+                self.state_machine_method,
+            ),
+            (
+                TableExistsFlags::CustomDebugInformation,
+                self.custom_debug_information,
+            ),
+        ]
+    }
+
+    /// The flags of every table with at least one row.
+    pub fn exists_flags(&self) -> TableExistsFlags {
+        self.counts()
+            .iter()
+            .filter(|(_, rows)| rows.0 > 0)
+            .fold(TableExistsFlags::empty(), |flags, (flag, _)| flags | *flag)
+    }
+
+    /// Writes the row count of every table with at least one row.
+    pub fn write_to(&self, writer: &mut DataWriter) -> Result<()> {
+        for (_, rows) in self.counts().iter().filter(|(_, rows)| rows.0 > 0) {
+            writer.write(rows.0)?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1,9 +1,11 @@
 pub mod coded_tokens;
 pub mod metadata;
 mod reader;
+mod rows;
 mod streams_offsets;
 mod tables;
 pub mod values;
+mod writer;
 
 use self::{
     coded_tokens::CodedTokenSizes,
@@ -11,13 +13,15 @@ use self::{
 };
 use super::Version;
 use crate::{
-    dotnet::md::MDStreamFlags,
-    error::Result,
+    dotnet::md::{MDStreamFlags, MDToken},
+    error::{HaoError, Result},
     io::{DataReader, ReadData, Readable},
 };
 pub use reader::*;
+pub use rows::*;
 pub use streams_offsets::*;
 pub use tables::*;
+pub use writer::*;
 
 #[derive(Debug, Clone, Copy)]
 pub struct TableLocation {
@@ -83,6 +87,43 @@ impl Readable for TablesStreamsHeader {
     }
 }
 
+impl TablesStreamsHeader {
+    /// Tables that must be sorted by their primary key. See ECMA-335 II.22.
+    pub const SORTED_TABLES: TableExistsFlags = TableExistsFlags::InterfaceImpl
+        .union(TableExistsFlags::Constant)
+        .union(TableExistsFlags::CustomAttribute)
+        .union(TableExistsFlags::FieldMarshal)
+        .union(TableExistsFlags::DeclSecurity)
+        .union(TableExistsFlags::ClassLayout)
+        .union(TableExistsFlags::FieldLayout)
+        .union(TableExistsFlags::MethodSemantics)
+        .union(TableExistsFlags::MethodImpl)
+        .union(TableExistsFlags::ImplMap)
+        .union(TableExistsFlags::FieldRva)
+        .union(TableExistsFlags::NestedClass)
+        .union(TableExistsFlags::GenericParam)
+        .union(TableExistsFlags::GenericParamConstraint);
+
+    /// Creates the header for a tables stream with the given rows.
+    ///
+    /// The coded token sizes and table locations are calculated
+    /// the same way as when the header is read.
+    pub fn new(rows: &TableRows, flags: MDStreamFlags, version: Version) -> Self {
+        let coded_token_sizes = CodedTokenSizes::from_header(rows);
+        let table_locations =
+            TableLocations::from_metadata(rows, &coded_token_sizes, flags, version);
+
+        Self {
+            valid_tables: rows.exists_flags(),
+            table_locations,
+            flags,
+            version,
+            sorted_tables: Self::SORTED_TABLES,
+            coded_token_sizes,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TablesStreams<'a> {
     pub header: TablesStreamsHeader,
@@ -109,4 +150,104 @@ impl<'a> TablesStreams<'a> {
     pub fn modules(&'a self) -> Result<impl Iterator<Item = Result<ModulesTableRow>> + 'a> {
         self.row_iter(self.header.table_locations.module)
     }
+
+    /// Iterates the rows of the table `T`.
+    ///
+    /// ```no_run
+    /// # use hao::dotnet::{Metadata, md::streams::tables_stream::TypeRefTableRow};
+    /// let data = std::fs::read("Example.Net.dll").unwrap();
+    /// let md = Metadata::parse(&data).unwrap();
+    /// let tables = &md.metadata_streams.tables_stream;
+    ///
+    /// for row in tables.rows::<TypeRefTableRow>().unwrap() {
+    ///     println!("{:?}", row.unwrap());
+    /// }
+    /// ```
+    pub fn rows<T: TableRow>(&'a self) -> Result<TableRowsIterator<'a, T>>
+    where
+        TablesStreamReader<'a>: ReadData<T>,
+    {
+        self.row_iter(self.header.table_locations.get(T::TABLE))
+    }
+
+    /// Reads the row of the table `T` with the given row id.
+    /// Row id's start at 1.
+    ///
+    /// Returns `None` if the table has no such row.
+    pub fn row<T: TableRow>(&'a self, rid: u32) -> Result<Option<T>>
+    where
+        TablesStreamReader<'a>: ReadData<T>,
+    {
+        let Some(location) = self.row_location(T::TABLE, rid) else {
+            return Ok(None);
+        };
+        self.row_iter(location)?.next().transpose()
+    }
+
+    /// Reads the row a metadata token refers to.
+    ///
+    /// Errors if the token is not for the table `T`.
+    pub fn row_by_token<T: TableRow>(&'a self, token: MDToken) -> Result<Option<T>>
+    where
+        TablesStreamReader<'a>: ReadData<T>,
+    {
+        if token.table() != Some(T::TABLE) {
+            return Err(HaoError::InvalidToken(token.raw()));
+        }
+        self.row(token.rid())
+    }
+
+    /// Iterates the rows of any table.
+    ///
+    /// ```no_run
+    /// # use hao::dotnet::{Metadata, md::streams::tables_stream::TableId};
+    /// let data = std::fs::read("Example.Net.dll").unwrap();
+    /// let md = Metadata::parse(&data).unwrap();
+    /// let tables = &md.metadata_streams.tables_stream;
+    ///
+    /// for table in TableId::ALL {
+    ///     for row in tables.any_rows(table).unwrap() {
+    ///         println!("{:?}", row.unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub fn any_rows(&'a self, table: TableId) -> Result<AnyTableRowsIterator<'a>> {
+        AnyTableRowsIterator::new(self.heap_data, &self.header, table)
+    }
+
+    /// Reads the row of any table with the given row id.
+    /// Row id's start at 1.
+    ///
+    /// Returns `None` if the table has no such row.
+    pub fn any_row(&'a self, table: TableId, rid: u32) -> Result<Option<AnyTableRow>> {
+        let Some(location) = self.row_location(table, rid) else {
+            return Ok(None);
+        };
+        let mut reader = TablesStreamReader::new(
+            self.heap_data
+                .get(location.start_offset..location.start_offset + location.row_size)
+                .ok_or(HaoError::InvalidStreamIndex("#~", location.start_offset))?,
+            &self.header,
+        );
+        AnyTableRow::read(&mut reader, table).map(Some)
+    }
+
+    /// Reads the row a metadata token refers to.
+    pub fn any_row_by_token(&'a self, token: MDToken) -> Result<Option<AnyTableRow>> {
+        let table = token.table().ok_or(HaoError::InvalidToken(token.raw()))?;
+        self.any_row(table, token.rid())
+    }
+
+    // The location of a single row of a table.
+    fn row_location(&self, table: TableId, rid: u32) -> Option<TableLocation> {
+        let location = self.header.table_locations.get(table);
+        if rid == 0 || rid > location.rows.0 {
+            return None;
+        }
+        Some(TableLocation {
+            start_offset: location.start_offset + (rid as usize - 1) * location.row_size,
+            rows: TableRowCount(1),
+            row_size: location.row_size,
+        })
+    }
 }
@@ -1,6 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
-use super::{TableLocation, TablesStreamsHeader};
+use super::{AnyTableRow, TableId, TableLocation, TablesStreamsHeader};
 use crate::{
     error::{HaoError, Result},
     io::{DataReader, ReadData},
@@ -97,3 +97,44 @@ where
         Some(self.reader.read())
     }
 }
+
+/// Iterates the rows of a table as [`AnyTableRow`].
+pub struct AnyTableRowsIterator<'a> {
+    reader: TablesStreamReader<'a>,
+    table: TableId,
+    index: usize,
+    rows: usize,
+}
+
+impl<'a> AnyTableRowsIterator<'a> {
+    pub fn new(
+        heap_data: &'a [u8],
+        header: &'a TablesStreamsHeader,
+        table: TableId,
+    ) -> Result<Self> {
+        let location = header.table_locations.get(table);
+        let length = location.rows.0 as usize * location.row_size;
+        let slice = heap_data
+            .get(location.start_offset..location.start_offset + length)
+            .ok_or_else(|| HaoError::InvalidStreamIndex("#~", location.start_offset + length))?;
+
+        Ok(Self {
+            reader: TablesStreamReader::new(slice, header),
+            table,
+            index: 0,
+            rows: location.rows.0 as usize,
+        })
+    }
+}
+
+impl<'a> Iterator for AnyTableRowsIterator<'a> {
+    type Item = Result<AnyTableRow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.rows {
+            return None;
+        }
+        self.index += 1;
+        Some(AnyTableRow::read(&mut self.reader, self.table))
+    }
+}
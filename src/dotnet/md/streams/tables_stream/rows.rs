@@ -0,0 +1,289 @@
+use num_derive::{FromPrimitive, ToPrimitive};
+
+use super::{metadata::TableLocations, tables::*, TableLocation, TablesStreamReader};
+use crate::{error::Result, io::ReadData};
+
+/// The id of a metadata table. This is the high byte of a metadata token. See ECMA-335 II.22.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, FromPrimitive, ToPrimitive)]
+pub enum TableId {
+    Module = 0x00,
+    TypeRef = 0x01,
+    TypeDef = 0x02,
+    FieldPtr = 0x03,
+    Field = 0x04,
+    MethodPtr = 0x05,
+    Method = 0x06,
+    ParamPtr = 0x07,
+    Param = 0x08,
+    InterfaceImpl = 0x09,
+    MemberRef = 0x0A,
+    Constant = 0x0B,
+    CustomAttribute = 0x0C,
+    FieldMarshal = 0x0D,
+    DeclSecurity = 0x0E,
+    ClassLayout = 0x0F,
+    FieldLayout = 0x10,
+    StandAloneSig = 0x11,
+    EventMap = 0x12,
+    EventPtr = 0x13,
+    Event = 0x14,
+    PropertyMap = 0x15,
+    PropertyPtr = 0x16,
+    Property = 0x17,
+    MethodSemantics = 0x18,
+    MethodImpl = 0x19,
+    ModuleRef = 0x1A,
+    TypeSpec = 0x1B,
+    ImplMap = 0x1C,
+    FieldRva = 0x1D,
+    EncLog = 0x1E,
+    EncMap = 0x1F,
+    Assembly = 0x20,
+    AssemblyProcessor = 0x21,
+    AssemblyOs = 0x22,
+    AssemblyRef = 0x23,
+    AssemblyRefProcessor = 0x24,
+    AssemblyRefOs = 0x25,
+    File = 0x26,
+    ExportedType = 0x27,
+    ManifestResource = 0x28,
+    NestedClass = 0x29,
+    GenericParam = 0x2A,
+    MethodSpec = 0x2B,
+    GenericParamConstraint = 0x2C,
+    Document = 0x30,
+    MethodDebugInformation = 0x31,
+    LocalScope = 0x32,
+    LocalVariable = 0x33,
+    LocalConstant = 0x34,
+    ImportScope = 0x35,
+    StateMachineMethod = 0x36,
+    CustomDebugInformation = 0x37,
+}
+
+impl TableId {
+    /// Every table, in the order they are stored in the tables stream.
+    pub const ALL: [TableId; 53] = [
+        TableId::Module,
+        TableId::TypeRef,
+        TableId::TypeDef,
+        TableId::FieldPtr,
+        TableId::Field,
+        TableId::MethodPtr,
+        TableId::Method,
+        TableId::ParamPtr,
+        TableId::Param,
+        TableId::InterfaceImpl,
+        TableId::MemberRef,
+        TableId::Constant,
+        TableId::CustomAttribute,
+        TableId::FieldMarshal,
+        TableId::DeclSecurity,
+        TableId::ClassLayout,
+        TableId::FieldLayout,
+        TableId::StandAloneSig,
+        TableId::EventMap,
+        TableId::EventPtr,
+        TableId::Event,
+        TableId::PropertyMap,
+        TableId::PropertyPtr,
+        TableId::Property,
+        TableId::MethodSemantics,
+        TableId::MethodImpl,
+        TableId::ModuleRef,
+        TableId::TypeSpec,
+        TableId::ImplMap,
+        TableId::FieldRva,
+        TableId::EncLog,
+        TableId::EncMap,
+        TableId::Assembly,
+        TableId::AssemblyProcessor,
+        TableId::AssemblyOs,
+        TableId::AssemblyRef,
+        TableId::AssemblyRefProcessor,
+        TableId::AssemblyRefOs,
+        TableId::File,
+        TableId::ExportedType,
+        TableId::ManifestResource,
+        TableId::NestedClass,
+        TableId::GenericParam,
+        TableId::MethodSpec,
+        TableId::GenericParamConstraint,
+        TableId::Document,
+        TableId::MethodDebugInformation,
+        TableId::LocalScope,
+        TableId::LocalVariable,
+        TableId::LocalConstant,
+        TableId::ImportScope,
+        TableId::StateMachineMethod,
+        TableId::CustomDebugInformation,
+    ];
+}
+
+impl TableLocations {
+    /// The location of the table with the given id.
+    pub fn get(&self, table: TableId) -> TableLocation {
+        match table {
+            TableId::Module => self.module,
+            TableId::TypeRef => self.type_ref,
+            TableId::TypeDef => self.type_def,
+            TableId::FieldPtr => self.field_ptr,
+            TableId::Field => self.field,
+            TableId::MethodPtr => self.method_ptr,
+            TableId::Method => self.method,
+            TableId::ParamPtr => self.param_ptr,
+            TableId::Param => self.param,
+            TableId::InterfaceImpl => self.interface_impl,
+            TableId::MemberRef => self.member_ref,
+            TableId::Constant => self.constant,
+            TableId::CustomAttribute => self.custom_attribute,
+            TableId::FieldMarshal => self.field_marshal,
+            TableId::DeclSecurity => self.decl_security,
+            TableId::ClassLayout => self.class_layout,
+            TableId::FieldLayout => self.field_layout,
+            TableId::StandAloneSig => self.stand_alone_sig,
+            TableId::EventMap => self.event_map,
+            TableId::EventPtr => self.event_ptr,
+            TableId::Event => self.event,
+            TableId::PropertyMap => self.property_map,
+            TableId::PropertyPtr => self.property_ptr,
+            TableId::Property => self.property,
+            TableId::MethodSemantics => self.method_semantics,
+            TableId::MethodImpl => self.method_impl,
+            TableId::ModuleRef => self.module_ref,
+            TableId::TypeSpec => self.type_spec,
+            TableId::ImplMap => self.impl_map,
+            TableId::FieldRva => self.field_rva,
+            TableId::EncLog => self.enc_log,
+            TableId::EncMap => self.enc_map,
+            TableId::Assembly => self.assembly,
+            TableId::AssemblyProcessor => self.assembly_processor,
+            TableId::AssemblyOs => self.assembly_os,
+            TableId::AssemblyRef => self.assembly_ref,
+            TableId::AssemblyRefProcessor => self.assembly_ref_processor,
+            TableId::AssemblyRefOs => self.assembly_ref_os,
+            TableId::File => self.file,
+            TableId::ExportedType => self.exported_type,
+            TableId::ManifestResource => self.manifest_resource,
+            TableId::NestedClass => self.nested_class,
+            TableId::GenericParam => self.generic_param,
+            TableId::MethodSpec => self.method_spec,
+            TableId::GenericParamConstraint => self.generic_param_constraint,
+            TableId::Document => self.document,
+            TableId::MethodDebugInformation => self.method_debug_information,
+            TableId::LocalScope => self.local_scope,
+            TableId::LocalVariable => self.local_variable,
+            TableId::LocalConstant => self.local_constant,
+            TableId::ImportScope => self.import_scope,
+            TableId::StateMachineMethod => self.state_machine_method,
+            TableId::CustomDebugInformation => self.custom_debug_information,
+        }
+    }
+}
+
+/// A row of a metadata table.
+pub trait TableRow: std::fmt::Debug + Clone {
+    /// The table the row belongs to.
+    const TABLE: TableId;
+}
+
+macro_rules! table_rows {
+    ($($name:ident => $row:ident,)+) => {
+        $(
+            impl TableRow for $row {
+                const TABLE: TableId = TableId::$name;
+            }
+        )+
+
+        /// A row of any metadata table, for walking tables without knowing their type.
+        ///
+        /// ```
+        /// # use hao::dotnet::md::streams::tables_stream::{AnyTableRow, ModuleRefTableRow, StringsStreamOffset, TableId};
+        /// let row = AnyTableRow::ModuleRef(ModuleRefTableRow { name: StringsStreamOffset(0) });
+        /// assert_eq!(row.table(), TableId::ModuleRef);
+        /// ```
+        #[derive(Debug, Clone)]
+        pub enum AnyTableRow {
+            $($name($row),)+
+        }
+
+        impl AnyTableRow {
+            /// The table the row belongs to.
+            pub fn table(&self) -> TableId {
+                match self {
+                    $(Self::$name(_) => TableId::$name,)+
+                }
+            }
+
+            pub(crate) fn read(reader: &mut TablesStreamReader, table: TableId) -> Result<Self> {
+                Ok(match table {
+                    $(TableId::$name => Self::$name(reader.read()?),)+
+                })
+            }
+        }
+
+        $(
+            impl From<$row> for AnyTableRow {
+                fn from(row: $row) -> Self {
+                    Self::$name(row)
+                }
+            }
+        )+
+    };
+}
+
+table_rows! {
+    Module => ModulesTableRow,
+    TypeRef => TypeRefTableRow,
+    TypeDef => TypeDefTableRow,
+    FieldPtr => FieldPtrTableRow,
+    Field => FieldTableRow,
+    MethodPtr => MethodPtrTableRow,
+    Method => MethodTableRow,
+    ParamPtr => ParamPtrTableRow,
+    Param => ParamTableRow,
+    InterfaceImpl => InterfaceImplTableRow,
+    MemberRef => MemberRefTableRow,
+    Constant => ConstantTableRow,
+    CustomAttribute => CustomAttributeTableRow,
+    FieldMarshal => FieldMarshalTableRow,
+    DeclSecurity => DeclSecurityTableRow,
+    ClassLayout => ClassLayoutTableRow,
+    FieldLayout => FieldLayoutTableRow,
+    StandAloneSig => StandAloneSigTableRow,
+    EventMap => EventMapTableRow,
+    EventPtr => EventPtrTableRow,
+    Event => EventTableRow,
+    PropertyMap => PropertyMapTableRow,
+    PropertyPtr => PropertyPtrTableRow,
+    Property => PropertyTableRow,
+    MethodSemantics => MethodSemanticsTableRow,
+    MethodImpl => MethodImplTableRow,
+    ModuleRef => ModuleRefTableRow,
+    TypeSpec => TypeSpecTableRow,
+    ImplMap => ImplMapTableRow,
+    FieldRva => FieldRVATableRow,
+    EncLog => ENCLogTableRow,
+    EncMap => ENCMapTableRow,
+    Assembly => AssemblyTableRow,
+    AssemblyProcessor => AssemblyProcessorTableRow,
+    AssemblyOs => AssemblyOSTableRow,
+    AssemblyRef => AssemblyRefTableRow,
+    AssemblyRefProcessor => AssemblyRefProcessorTableRow,
+    AssemblyRefOs => AssemblyRefOSTableRow,
+    File => FileTableRow,
+    ExportedType => ExportedTypeTableRow,
+    ManifestResource => ManifestResourceTableRow,
+    NestedClass => NestedClassTableRow,
+    GenericParam => GenericParamTableRow,
+    MethodSpec => MethodSpecTableRow,
+    GenericParamConstraint => GenericParamConstraintTableRow,
+    Document => DocumentTableRow,
+    MethodDebugInformation => MethodDebugInformationTableRow,
+    LocalScope => LocalScopeTableRow,
+    LocalVariable => LocalVariableTableRow,
+    LocalConstant => LocalConstantTableRow,
+    ImportScope => ImportScopeTableRow,
+    StateMachineMethod => StateMachineMethodTableRow,
+    CustomDebugInformation => CustomDebugInformationTableRow,
+}
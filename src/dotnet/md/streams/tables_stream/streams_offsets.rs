@@ -1,5 +1,9 @@
-use super::{TableRows, TablesStreamReader, ValueSize};
-use crate::{dotnet::md::MDStreamFlags, error::Result, io::ReadData};
+use super::{TableRows, TablesStreamReader, TablesStreamWriter, ValueSize};
+use crate::{
+    dotnet::md::MDStreamFlags,
+    error::Result,
+    io::{ReadData, WriteData},
+};
 
 pub trait TableOffsetSize {
     fn table_offset_size(rows: &TableRows) -> ValueSize;
@@ -34,6 +38,16 @@ impl<'a> ReadData<StringsStreamOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<StringsStreamOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: StringsStreamOffset) -> Result<()> {
+        if StringsStreamOffset::streams_offset_size(self.header.flags) == ValueSize::Big {
+            self.write(offset.0)
+        } else {
+            self.write(offset.0 as u16)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct BlobStreamOffset(pub u32);
@@ -59,6 +73,16 @@ impl<'a> ReadData<BlobStreamOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<BlobStreamOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: BlobStreamOffset) -> Result<()> {
+        if BlobStreamOffset::streams_offset_size(self.header.flags) == ValueSize::Big {
+            self.write(offset.0)
+        } else {
+            self.write(offset.0 as u16)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct BlobStreamOffsetTypeSpec(pub u32);
@@ -84,6 +108,16 @@ impl<'a> ReadData<BlobStreamOffsetTypeSpec> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<BlobStreamOffsetTypeSpec> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: BlobStreamOffsetTypeSpec) -> Result<()> {
+        if BlobStreamOffsetTypeSpec::streams_offset_size(self.header.flags) == ValueSize::Big {
+            self.write(offset.0)
+        } else {
+            self.write(offset.0 as u16)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct GuidStreamOffset(pub u32);
@@ -109,6 +143,16 @@ impl<'a> ReadData<GuidStreamOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<GuidStreamOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: GuidStreamOffset) -> Result<()> {
+        if GuidStreamOffset::streams_offset_size(self.header.flags) == ValueSize::Big {
+            self.write(offset.0)
+        } else {
+            self.write(offset.0 as u16)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct ModuleTableOffset(pub u32);
@@ -154,6 +198,12 @@ impl<'a> ReadData<TypeDefTableOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<TypeDefTableOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: TypeDefTableOffset) -> Result<()> {
+        self.write_table_offset(self.header.table_locations.type_def, offset.0)
+    }
+}
+
 impl TableOffsetSize for TypeDefTableOffset {
     fn table_offset_size(rows: &TableRows) -> ValueSize {
         rows.type_def.row_size()
@@ -188,6 +238,12 @@ impl<'a> ReadData<FieldTableOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<FieldTableOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: FieldTableOffset) -> Result<()> {
+        self.write_table_offset(self.header.table_locations.field, offset.0)
+    }
+}
+
 impl TableOffsetSize for FieldTableOffset {
     fn table_offset_size(rows: &TableRows) -> ValueSize {
         rows.field.row_size()
@@ -222,6 +278,12 @@ impl<'a> ReadData<MethodTableOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<MethodTableOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: MethodTableOffset) -> Result<()> {
+        self.write_table_offset(self.header.table_locations.method, offset.0)
+    }
+}
+
 impl TableOffsetSize for MethodTableOffset {
     fn table_offset_size(rows: &TableRows) -> ValueSize {
         rows.method.row_size()
@@ -256,6 +318,12 @@ impl<'a> ReadData<ParamTableOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<ParamTableOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: ParamTableOffset) -> Result<()> {
+        self.write_table_offset(self.header.table_locations.param, offset.0)
+    }
+}
+
 impl TableOffsetSize for ParamTableOffset {
     fn table_offset_size(rows: &TableRows) -> ValueSize {
         rows.param.row_size()
@@ -460,6 +528,12 @@ impl<'a> ReadData<EventTableOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<EventTableOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: EventTableOffset) -> Result<()> {
+        self.write_table_offset(self.header.table_locations.event, offset.0)
+    }
+}
+
 impl TableOffsetSize for EventTableOffset {
     fn table_offset_size(rows: &TableRows) -> ValueSize {
         rows.event.row_size()
@@ -511,6 +585,12 @@ impl<'a> ReadData<PropertyTableOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<PropertyTableOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: PropertyTableOffset) -> Result<()> {
+        self.write_table_offset(self.header.table_locations.property, offset.0)
+    }
+}
+
 impl TableOffsetSize for PropertyTableOffset {
     fn table_offset_size(rows: &TableRows) -> ValueSize {
         rows.property.row_size()
@@ -562,6 +642,12 @@ impl<'a> ReadData<ModuleRefTableOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<ModuleRefTableOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: ModuleRefTableOffset) -> Result<()> {
+        self.write_table_offset(self.header.table_locations.module_ref, offset.0)
+    }
+}
+
 impl TableOffsetSize for ModuleRefTableOffset {
     fn table_offset_size(rows: &TableRows) -> ValueSize {
         rows.module_ref.row_size()
@@ -834,6 +920,12 @@ impl<'a> ReadData<GenericParamTableOffset> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<GenericParamTableOffset> for TablesStreamWriter<'a> {
+    fn write(&mut self, offset: GenericParamTableOffset) -> Result<()> {
+        self.write_table_offset(self.header.table_locations.generic_param, offset.0)
+    }
+}
+
 impl TableOffsetSize for GenericParamTableOffset {
     fn table_offset_size(rows: &TableRows) -> ValueSize {
         rows.generic_param.row_size()
@@ -4,10 +4,12 @@ use crate::{
         streams::Version,
     },
     error::Result,
-    io::ReadData,
+    io::{ReadData, WriteData},
 };
 
-use super::{coded_tokens::*, reader::TablesStreamReader, streams_offsets::*};
+use super::{
+    coded_tokens::*, reader::TablesStreamReader, streams_offsets::*, writer::TablesStreamWriter,
+};
 use bitflags::bitflags;
 
 #[derive(Debug, Clone)]
@@ -46,6 +48,17 @@ impl<'a> ReadData<ModulesTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&ModulesTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &ModulesTableRow) -> Result<()> {
+        self.write(row.generation)?;
+        self.write(row.name)?;
+        self.write(row.mvid)?;
+        self.write(row.enc_id)?;
+        self.write(row.enc_base_id)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TypeRefTableRow {
     pub resolution_scope: CodedToken<ResolutionScopeToken>,
@@ -71,6 +84,15 @@ impl<'a> ReadData<TypeRefTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&TypeRefTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &TypeRefTableRow) -> Result<()> {
+        self.write(row.resolution_scope)?;
+        self.write(row.name)?;
+        self.write(row.namespace)?;
+        Ok(())
+    }
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct TypeAttributes: u32 {
@@ -160,6 +182,18 @@ impl<'a> ReadData<TypeDefTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&TypeDefTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &TypeDefTableRow) -> Result<()> {
+        self.write(row.flags.bits())?;
+        self.write(row.name)?;
+        self.write(row.namespace)?;
+        self.write(row.extends)?;
+        self.write(row.field_list)?;
+        self.write(row.method_list)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FieldPtrTableRow {
     pub field: FieldTableOffset,
@@ -238,6 +272,15 @@ impl<'a> ReadData<FieldTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&FieldTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &FieldTableRow) -> Result<()> {
+        self.write(row.flags.bits())?;
+        self.write(row.name)?;
+        self.write(row.signature)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MethodPtrTableRow {
     pub method: MethodTableOffset,
@@ -363,14 +406,26 @@ impl<'a> ReadData<MethodTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&MethodTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &MethodTableRow) -> Result<()> {
+        self.write(row.rva)?;
+        self.write(row.impl_flags.bits())?;
+        self.write(row.flags.bits())?;
+        self.write(row.name)?;
+        self.write(row.signature)?;
+        self.write(row.param_list)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ParamPtrTableRow {
-    pub param: ParamPtrTableOffset,
+    pub param: ParamTableOffset,
 }
 
 impl<'a> CalculateTableSize<ParamPtrTableRow> for SizeCalculator<'a> {
     fn calculate_table_size_bytes(&self) -> usize {
-        ParamPtrTableOffset::table_offset_size(self.rows).byte_size()
+        ParamTableOffset::table_offset_size(self.rows).byte_size()
     }
 }
 
@@ -423,6 +478,15 @@ impl<'a> ReadData<ParamTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&ParamTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &ParamTableRow) -> Result<()> {
+        self.write(row.flags.bits())?;
+        self.write(row.sequence)?;
+        self.write(row.name)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct InterfaceImplTableRow {
     pub class: TypeDefTableOffset,
@@ -445,6 +509,14 @@ impl<'a> ReadData<InterfaceImplTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&InterfaceImplTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &InterfaceImplTableRow) -> Result<()> {
+        self.write(row.class)?;
+        self.write(row.interface)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MemberRefTableRow {
     pub class: CodedToken<MemberRefParentToken>,
@@ -470,6 +542,15 @@ impl<'a> ReadData<MemberRefTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&MemberRefTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &MemberRefTableRow) -> Result<()> {
+        self.write(row.class)?;
+        self.write(row.name)?;
+        self.write(row.signature)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ConstantTableRow {
     pub ty: u8,
@@ -498,6 +579,16 @@ impl<'a> ReadData<ConstantTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&ConstantTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &ConstantTableRow) -> Result<()> {
+        self.write(row.ty)?;
+        self.write(row.padding)?;
+        self.write(row.parent)?;
+        self.write(row.value)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CustomAttributeTableRow {
     pub parent: CodedToken<HasCustomAttributeToken>,
@@ -523,6 +614,15 @@ impl<'a> ReadData<CustomAttributeTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&CustomAttributeTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &CustomAttributeTableRow) -> Result<()> {
+        self.write(row.parent)?;
+        self.write(row.ty)?;
+        self.write(row.value)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FieldMarshalTableRow {
     pub parent: CodedToken<HasFieldMarshalToken>,
@@ -545,6 +645,14 @@ impl<'a> ReadData<FieldMarshalTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&FieldMarshalTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &FieldMarshalTableRow) -> Result<()> {
+        self.write(row.parent)?;
+        self.write(row.native_type)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DeclSecurityTableRow {
     pub action: u16,
@@ -595,6 +703,15 @@ impl<'a> ReadData<ClassLayoutTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&ClassLayoutTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &ClassLayoutTableRow) -> Result<()> {
+        self.write(row.packing_size)?;
+        self.write(row.class_size)?;
+        self.write(row.parent)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FieldLayoutTableRow {
     pub offset: u32,
@@ -616,6 +733,14 @@ impl<'a> ReadData<FieldLayoutTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&FieldLayoutTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &FieldLayoutTableRow) -> Result<()> {
+        self.write(row.offset)?;
+        self.write(row.field)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct StandAloneSigTableRow {
     pub signature: BlobStreamOffset,
@@ -635,6 +760,13 @@ impl<'a> ReadData<StandAloneSigTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&StandAloneSigTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &StandAloneSigTableRow) -> Result<()> {
+        self.write(row.signature)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EventMapTableRow {
     pub parent: TypeDefTableOffset,
@@ -657,6 +789,14 @@ impl<'a> ReadData<EventMapTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&EventMapTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &EventMapTableRow) -> Result<()> {
+        self.write(row.parent)?;
+        self.write(row.event_list)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EventPtrTableRow {
     pub event: EventTableOffset,
@@ -712,6 +852,15 @@ impl<'a> ReadData<EventTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&EventTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &EventTableRow) -> Result<()> {
+        self.write(row.event_flags.bits())?;
+        self.write(row.name)?;
+        self.write(row.event_type)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PropertyMapTableRow {
     pub parent: TypeDefTableOffset,
@@ -734,6 +883,14 @@ impl<'a> ReadData<PropertyMapTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&PropertyMapTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &PropertyMapTableRow) -> Result<()> {
+        self.write(row.parent)?;
+        self.write(row.property_list)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PropertyPtrTableRow {
     pub property: PropertyTableOffset,
@@ -793,6 +950,15 @@ impl<'a> ReadData<PropertyTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&PropertyTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &PropertyTableRow) -> Result<()> {
+        self.write(row.prop_flags.bits())?;
+        self.write(row.name)?;
+        self.write(row.ty)?;
+        Ok(())
+    }
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct MethodSemanticsFlags: u16 {
@@ -830,6 +996,15 @@ impl<'a> ReadData<MethodSemanticsTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&MethodSemanticsTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &MethodSemanticsTableRow) -> Result<()> {
+        self.write(row.semantic.bits())?;
+        self.write(row.method)?;
+        self.write(row.association)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MethodImplTableRow {
     pub class: TypeDefTableOffset,
@@ -855,6 +1030,15 @@ impl<'a> ReadData<MethodImplTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&MethodImplTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &MethodImplTableRow) -> Result<()> {
+        self.write(row.class)?;
+        self.write(row.method_body)?;
+        self.write(row.method_declaration)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ModuleRefTableRow {
     pub name: StringsStreamOffset,
@@ -872,6 +1056,13 @@ impl<'a> ReadData<ModuleRefTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&ModuleRefTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &ModuleRefTableRow) -> Result<()> {
+        self.write(row.name)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TypeSpecTableRow {
     pub signature: BlobStreamOffsetTypeSpec,
@@ -891,6 +1082,13 @@ impl<'a> ReadData<TypeSpecTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&TypeSpecTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &TypeSpecTableRow) -> Result<()> {
+        self.write(row.signature)?;
+        Ok(())
+    }
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct PInvokeMapFlags: u16 {
@@ -956,6 +1154,16 @@ impl<'a> ReadData<ImplMapTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&ImplMapTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &ImplMapTableRow) -> Result<()> {
+        self.write(row.mapping_flags.bits())?;
+        self.write(row.member_forwarded)?;
+        self.write(row.import_name)?;
+        self.write(row.import_scope)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FieldRVATableRow {
     pub rva: u32,
@@ -977,6 +1185,14 @@ impl<'a> ReadData<FieldRVATableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&FieldRVATableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &FieldRVATableRow) -> Result<()> {
+        self.write(row.rva)?;
+        self.write(row.field)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ENCLogTableRow {
     pub token: u32,
@@ -1089,6 +1305,21 @@ impl<'a> ReadData<AssemblyTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&AssemblyTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &AssemblyTableRow) -> Result<()> {
+        self.write(row.hash_alg_id)?;
+        self.write(row.major_version)?;
+        self.write(row.minor_version)?;
+        self.write(row.build_number)?;
+        self.write(row.revision_number)?;
+        self.write(row.flags.bits())?;
+        self.write(row.public_key)?;
+        self.write(row.name)?;
+        self.write(row.locale)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AssemblyProcessorTableRow {
     pub processor: u32,
@@ -1174,6 +1405,21 @@ impl<'a> ReadData<AssemblyRefTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&AssemblyRefTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &AssemblyRefTableRow) -> Result<()> {
+        self.write(row.major_version)?;
+        self.write(row.minor_version)?;
+        self.write(row.build_number)?;
+        self.write(row.revision_number)?;
+        self.write(row.flags.bits())?;
+        self.write(row.public_key_or_token)?;
+        self.write(row.name)?;
+        self.write(row.locale)?;
+        self.write(row.hash_value)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AssemblyRefProcessorTableRow {
     pub processor: u32,
@@ -1257,6 +1503,15 @@ impl<'a> ReadData<FileTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&FileTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &FileTableRow) -> Result<()> {
+        self.write(row.flags.bits())?;
+        self.write(row.name)?;
+        self.write(row.hash_value)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ExportedTypeTableRow {
     pub flags: TypeAttributes,
@@ -1288,6 +1543,17 @@ impl<'a> ReadData<ExportedTypeTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&ExportedTypeTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &ExportedTypeTableRow) -> Result<()> {
+        self.write(row.flags.bits())?;
+        self.write(row.type_def_id)?;
+        self.write(row.type_name)?;
+        self.write(row.type_namespace)?;
+        self.write(row.implementation)?;
+        Ok(())
+    }
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct ManifestResourceFlags: u32 {
@@ -1325,6 +1591,16 @@ impl<'a> ReadData<ManifestResourceTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&ManifestResourceTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &ManifestResourceTableRow) -> Result<()> {
+        self.write(row.offset)?;
+        self.write(row.flags.bits())?;
+        self.write(row.name)?;
+        self.write(row.implementation)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NestedClassTableRow {
     pub nested_class: TypeDefTableOffset,
@@ -1347,6 +1623,14 @@ impl<'a> ReadData<NestedClassTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&NestedClassTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &NestedClassTableRow) -> Result<()> {
+        self.write(row.nested_class)?;
+        self.write(row.enclosing_class)?;
+        Ok(())
+    }
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct GenericParamFlags: u16 {
@@ -1404,6 +1688,22 @@ impl<'a> ReadData<GenericParamTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&GenericParamTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &GenericParamTableRow) -> Result<()> {
+        self.write(row.number)?;
+        self.write(row.flags.bits())?;
+        self.write(row.owner)?;
+        self.write(row.name)?;
+        if self.header.version == Version(1, 1) {
+            self.write(
+                row.kind
+                    .unwrap_or(CodedToken::null(TypeDefOrRefToken::TypeDef)),
+            )?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MethodSpecTableRow {
     pub method: CodedToken<MethodDefOrRefToken>,
@@ -1426,6 +1726,14 @@ impl<'a> ReadData<MethodSpecTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&MethodSpecTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &MethodSpecTableRow) -> Result<()> {
+        self.write(row.method)?;
+        self.write(row.instantiation)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GenericParamConstraintTableRow {
     pub owner: GenericParamTableOffset,
@@ -1448,6 +1756,14 @@ impl<'a> ReadData<GenericParamConstraintTableRow> for TablesStreamReader<'a> {
     }
 }
 
+impl<'a> WriteData<&GenericParamConstraintTableRow> for TablesStreamWriter<'a> {
+    fn write(&mut self, row: &GenericParamConstraintTableRow) -> Result<()> {
+        self.write(row.owner)?;
+        self.write(row.constraint)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DocumentTableRow {
     pub name: BlobStreamOffset,
@@ -0,0 +1,68 @@
+use super::{metadata::TableRows, TableLocation, TablesStreamsHeader};
+use crate::{
+    error::Result,
+    io::{DataWriter, WriteData},
+};
+
+pub struct TablesStreamWriter<'a> {
+    pub writer: DataWriter,
+    pub header: &'a TablesStreamsHeader,
+}
+
+impl<'a> TablesStreamWriter<'a> {
+    pub fn new(header: &'a TablesStreamsHeader) -> Self {
+        Self {
+            writer: DataWriter::new(),
+            header,
+        }
+    }
+
+    pub fn write_table_offset(
+        &mut self,
+        target_table_size: TableLocation,
+        value: u32,
+    ) -> Result<()> {
+        if target_table_size.rows.is_large() {
+            self.write(value)
+        } else {
+            self.write(value as u16)
+        }
+    }
+
+    /// Writes the header of the stream, followed by the row count of every table.
+    pub fn write_header(&mut self, rows: &TableRows) -> Result<()> {
+        const LOG_2_RID: u8 = 1;
+
+        self.write(0u32)?;
+        self.write(self.header.version.0)?;
+        self.write(self.header.version.1)?;
+        self.write(self.header.flags.bits())?;
+        self.write(LOG_2_RID)?;
+        self.write(self.header.valid_tables.bits())?;
+        self.write(self.header.sorted_tables.bits())?;
+        rows.write_to(&mut self.writer)
+    }
+
+    pub fn write_rows<T>(&mut self, rows: &[T]) -> Result<()>
+    where
+        for<'r> Self: WriteData<&'r T>,
+    {
+        for row in rows {
+            self.write(row)?;
+        }
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.writer.into_inner()
+    }
+}
+
+impl<'a, T> WriteData<T> for TablesStreamWriter<'a>
+where
+    DataWriter: WriteData<T>,
+{
+    fn write(&mut self, value: T) -> Result<()> {
+        self.writer.write(value)
+    }
+}
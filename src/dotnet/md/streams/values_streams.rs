@@ -1,4 +1,5 @@
 use crate::{
+    dotnet::md::MDToken,
     error::{HaoError, Result},
     io::DataReader,
 };
@@ -26,7 +27,7 @@ impl<'a> StringsStream<'a> {
         let terminator = slice
             .iter()
             .position(|c| *c == 0)
-            .ok_or_else(|| HaoError::InvalidStreamIndex("#Strings", offset))?;
+            .ok_or(HaoError::InvalidStreamIndex("#Strings", offset))?;
 
         std::str::from_utf8(&slice[..terminator])
             .map_err(|_| HaoError::InvalidUTF8String(offset, Vec::from(&slice[..terminator])))
@@ -62,3 +63,143 @@ impl<'a> GuidStream<'a> {
             .map_err(|_| HaoError::InvalidStreamIndex("#GUID", position))
     }
 }
+
+/// A string from the `#US` heap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserString {
+    /// The offset of the string in the heap.
+    /// This is the row of the `0x70` token of the string.
+    pub offset: u32,
+    pub value: String,
+    /// Set when the string contains characters that need special handling
+    /// (anything outside of printable ASCII).
+    pub has_special_chars: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct UserStringsStream<'a> {
+    pub heap_data: &'a [u8],
+}
+
+impl<'a> UserStringsStream<'a> {
+    pub fn from_reader(reader: DataReader<'a>) -> Result<Self> {
+        Ok(Self {
+            heap_data: reader.remaning_slice(),
+        })
+    }
+
+    // Returns the length of the entry and the size of its length prefix.
+    fn read_entry_length(&self, offset: usize) -> Result<(usize, usize)> {
+        let err = || HaoError::InvalidStreamIndex("#US", offset);
+        let data = self.heap_data.get(offset..).ok_or_else(err)?;
+        let b = *data.first().ok_or_else(err)?;
+
+        let (len, prefix) = match b {
+            b if b & 0x80 == 0 => (b as usize, 1),
+            b if b & 0xC0 == 0x80 => {
+                let bytes = data.get(..2).ok_or_else(err)?;
+                (u16::from_be_bytes([b & 0x3F, bytes[1]]) as usize, 2)
+            }
+            b => {
+                let bytes = data.get(..4).ok_or_else(err)?;
+                (
+                    u32::from_be_bytes([b & 0x1F, bytes[1], bytes[2], bytes[3]]) as usize,
+                    4,
+                )
+            }
+        };
+
+        if len > data.len() - prefix {
+            return Err(err());
+        }
+        Ok((len, prefix))
+    }
+
+    /// Reads the string at `offset` in the heap.
+    pub fn read(&self, offset: u32) -> Result<UserString> {
+        let (len, prefix) = self.read_entry_length(offset as usize)?;
+        let start = offset as usize + prefix;
+        let data = &self.heap_data[start..start + len];
+
+        // The UTF-16 data is followed by a single flag byte.
+        let units = data
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect::<Vec<_>>();
+        let has_special_chars = len % 2 == 1 && data[len - 1] != 0;
+
+        Ok(UserString {
+            offset,
+            value: String::from_utf16_lossy(&units),
+            has_special_chars,
+        })
+    }
+
+    /// Reads the value of the string at `offset` in the heap.
+    pub fn read_string(&self, offset: u32) -> Result<String> {
+        self.read(offset).map(|s| s.value)
+    }
+
+    /// Reads the string refrenced by a `0x70` token,
+    /// such as the operand of a `ldstr` instruction.
+    pub fn read_token(&self, token: u32) -> Result<String> {
+        let token = MDToken::from_raw(token);
+        if !token.is_user_string() {
+            return Err(HaoError::BadImageFormat("Token is not a user string"));
+        }
+        self.read_string(token.rid())
+    }
+
+    /// Returns an iterator over every string in the heap.
+    ///
+    /// ```no_run
+    /// # use hao::dotnet::Metadata;
+    /// let data = std::fs::read("Example.Net.dll").unwrap();
+    /// let md = Metadata::parse(&data).unwrap();
+    ///
+    /// for string in md.metadata_streams.user_strings_stream.iter() {
+    ///     let string = string.unwrap();
+    ///     println!("{:#010x}: {:?}", 0x70000000 | string.offset, string.value);
+    /// }
+    /// ```
+    pub fn iter(&self) -> UserStringsIter<'_, 'a> {
+        UserStringsIter {
+            stream: self,
+            // The first entry is always the empty string.
+            offset: 1,
+        }
+    }
+}
+
+pub struct UserStringsIter<'s, 'a> {
+    stream: &'s UserStringsStream<'a>,
+    offset: usize,
+}
+
+impl<'s, 'a> Iterator for UserStringsIter<'s, 'a> {
+    type Item = Result<UserString>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.offset >= self.stream.heap_data.len() {
+                return None;
+            }
+
+            let (len, prefix) = match self.stream.read_entry_length(self.offset) {
+                Ok(v) => v,
+                Err(e) => {
+                    // Stop after a bad entry, the rest of the heap can't be walked.
+                    self.offset = self.stream.heap_data.len();
+                    return Some(Err(e));
+                }
+            };
+            let offset = self.offset;
+            self.offset += prefix + len;
+
+            // Skip the zero padding at the end of the heap.
+            if len != 0 {
+                return Some(self.stream.read(offset as u32));
+            }
+        }
+    }
+}
@@ -1,19 +1,23 @@
 use super::md::streams::MetadataStreams;
 use crate::dotnet::md::metadata_header::{MetadataHeader, MetadataType};
 use crate::error::{HaoError, Result};
-use crate::io::{PEImage};
+use crate::io::PEImage;
+use pewter::pe::sections::cor20::ImageCor20Header;
 
 pub struct Metadata<'a> {
+    pub pe_image: PEImage<'a>,
+    pub cor20_header: ImageCor20Header,
+    pub metadata_header: MetadataHeader<'a>,
+    pub metadata_type: MetadataType,
     pub metadata_streams: MetadataStreams<'a>,
 }
 
 impl<'a> Metadata<'a> {
     pub fn parse(buffer: &'a [u8]) -> Result<Self> {
-
         let pe_image = PEImage::load_bytes(buffer)?;
-       
+
         let cor20_header = pe_image.read_clr_rt_header()?;
-    
+
         if cor20_header.metadata.virtual_address == 0 {
             return Err(HaoError::BadImageFormat(".NET metadata RVA is 0"));
         }
@@ -24,10 +28,9 @@ impl<'a> Metadata<'a> {
             MetadataHeader::from_reader(&mut md_header_reader)?
         };
 
-        match metadata_header.metadata_type()? {
-            MetadataType::Compressed => {}
-            MetadataType::Enc => panic!("Only compressed streams are currently supported"),
-        };
+        // Both the compressed (#~) and uncompressed (#-) tables share the same row layout.
+        // The uncompressed tables may also contain the *Ptr indirection tables.
+        let metadata_type = metadata_header.metadata_type()?;
 
         let metadata_streams = MetadataStreams::from_headers(
             &pe_image,
@@ -35,6 +38,12 @@ impl<'a> Metadata<'a> {
             cor20_header.metadata.virtual_address,
         )?;
 
-        Ok(Self { metadata_streams })
+        Ok(Self {
+            pe_image,
+            cor20_header,
+            metadata_header,
+            metadata_type,
+            metadata_streams,
+        })
     }
 }
@@ -4,4 +4,4 @@ pub mod metadata;
 pub(crate) mod module;
 
 pub use metadata::Metadata;
-pub use module::Module;
+pub use module::{Module, ResolvedToken};
@@ -0,0 +1,323 @@
+use crate::{
+    dotnet::entries::{signature::MethodDefOrRef, values::*, EntList, Entry, Ptr},
+    error::{HaoError, Result},
+};
+
+use super::Module;
+
+fn contains<T>(list: &[Ptr<T>], ptr: &Ptr<T>) -> bool {
+    list.iter().any(|p| p.ptr_eq(ptr))
+}
+
+fn remove<T>(list: &mut EntList<T>, ptr: &Ptr<T>) -> bool {
+    let len = list.len();
+    list.retain(|p| !p.ptr_eq(ptr));
+    len != list.len()
+}
+
+fn is_method(method: &MethodDefOrRef, ptr: &Ptr<Method>) -> bool {
+    matches!(method, MethodDefOrRef::Method(m) if m.0.ptr_eq(ptr))
+}
+
+/// Adding and removing entries.
+///
+/// Members are owned by the type or method they are added to,
+/// and are given their row ids when the module is written,
+/// so removing an entry never leaves a gap in the written tables.
+///
+/// Removing an entry does not remove refrences to it from method bodies, signatures
+/// or other entries. Writing a module that still refrences a removed entry
+/// will fail with [`HaoError::EntryNotInModule`].
+impl Module {
+    /// Adds a type to the module.
+    ///
+    /// ```
+    /// # use hao::{Module, dotnet::{entries::values::TypeDef, md::streams::tables_stream::TypeAttributes}};
+    /// let mut module = Module::default();
+    ///
+    /// let ty = module.add_type(TypeDef::new(TypeAttributes::Public, "Example", "Program", None));
+    /// ty.value_mut().set_name("Renamed");
+    ///
+    /// assert_eq!(module.types().values().next().unwrap().full_name(), "Example.Renamed");
+    /// ```
+    pub fn add_type(&mut self, ty: TypeDef) -> Entry<TypeDef> {
+        let ptr = Ptr::new(ty);
+        self.type_defs.push(ptr.clone());
+        Entry(ptr)
+    }
+
+    /// Adds a type to the module that is nested inside `declaring_type`.
+    pub fn add_nested_type(
+        &mut self,
+        declaring_type: &Entry<TypeDef>,
+        mut ty: TypeDef,
+    ) -> Result<Entry<TypeDef>> {
+        if !contains(&self.type_defs, &declaring_type.0) {
+            return Err(HaoError::EntryNotInModule("TypeDef"));
+        }
+        ty.declaring_type = Some(declaring_type.0.clone());
+        let ty = self.add_type(ty);
+        declaring_type.value_mut().nested_types.push(ty.0.clone());
+        Ok(ty)
+    }
+
+    /// Removes a type along with its members and nested types.
+    pub fn remove_type(&mut self, ty: &Entry<TypeDef>) -> Result<()> {
+        if !contains(&self.type_defs, &ty.0) {
+            return Err(HaoError::EntryNotInModule("TypeDef"));
+        }
+        let declaring_type = ty.value().declaring_type.clone();
+        if let Some(declaring_type) = declaring_type {
+            remove(&mut declaring_type.value_mut().nested_types, &ty.0);
+        }
+        self.forget_type(&ty.0);
+        Ok(())
+    }
+
+    /// Adds a field to the end of the fields of `ty`.
+    ///
+    /// ```
+    /// # use hao::{Module, dotnet::{
+    /// #     entries::{signature::{FieldSignature, ValueType}, values::{Field, TypeDef}},
+    /// #     md::streams::tables_stream::{FieldFlags, TypeAttributes},
+    /// # }};
+    /// let mut module = Module::default();
+    /// let ty = module.add_type(TypeDef::new(TypeAttributes::Public, "Example", "Program", None));
+    ///
+    /// let field = Field::new(FieldFlags::Public, "value", FieldSignature(ValueType::Int32));
+    /// let field = module.add_field(&ty, field).unwrap();
+    /// assert_eq!(ty.value().fields().count(), 1);
+    ///
+    /// module.remove_field(&field).unwrap();
+    /// assert_eq!(ty.value().fields().count(), 0);
+    /// ```
+    pub fn add_field(&mut self, ty: &Entry<TypeDef>, field: Field) -> Result<Entry<Field>> {
+        if !contains(&self.type_defs, &ty.0) {
+            return Err(HaoError::EntryNotInModule("TypeDef"));
+        }
+        let ptr = Ptr::new(field);
+        ty.value_mut().field_list.push(ptr.clone());
+        self.fields.push(ptr.clone());
+        Ok(Entry(ptr))
+    }
+
+    /// Removes a field from its type.
+    pub fn remove_field(&mut self, field: &Entry<Field>) -> Result<()> {
+        if !remove(&mut self.fields, &field.0) {
+            return Err(HaoError::EntryNotInModule("Field"));
+        }
+        for ty in self.type_defs.iter() {
+            remove(&mut ty.value_mut().field_list, &field.0);
+        }
+        self.forget_attributes(&field.value().custom_attributes);
+        Ok(())
+    }
+
+    /// Adds a method to the end of the methods of `ty`.
+    pub fn add_method(&mut self, ty: &Entry<TypeDef>, method: Method) -> Result<Entry<Method>> {
+        if !contains(&self.type_defs, &ty.0) {
+            return Err(HaoError::EntryNotInModule("TypeDef"));
+        }
+        let ptr = Ptr::new(method);
+        ty.value_mut().method_list.push(ptr.clone());
+        self.methods.push(ptr.clone());
+        Ok(Entry(ptr))
+    }
+
+    /// Removes a method and its parameters from its type.
+    ///
+    /// The method is also removed from the properties and events of its type,
+    /// and the method implementations that refer to it.
+    pub fn remove_method(&mut self, method: &Entry<Method>) -> Result<()> {
+        if !contains(&self.methods, &method.0) {
+            return Err(HaoError::EntryNotInModule("Method"));
+        }
+        let ptr = &method.0;
+        let is_ptr = |m: &Option<Ptr<Method>>| m.as_ref().is_some_and(|m| m.ptr_eq(ptr));
+
+        for ty in self.type_defs.iter() {
+            let mut ty = ty.value_mut();
+            if !remove(&mut ty.method_list, ptr) {
+                continue;
+            }
+            ty.method_impls
+                .retain(|m| !is_method(&m.body, ptr) && !is_method(&m.declaration, ptr));
+            for property in ty.property_list.iter() {
+                let mut property = property.value_mut();
+                if is_ptr(&property.getter) {
+                    property.getter = None;
+                }
+                if is_ptr(&property.setter) {
+                    property.setter = None;
+                }
+                remove(&mut property.other_methods, ptr);
+            }
+            for event in ty.event_list.iter() {
+                let mut event = event.value_mut();
+                if is_ptr(&event.adder) {
+                    event.adder = None;
+                }
+                if is_ptr(&event.remover) {
+                    event.remover = None;
+                }
+                if is_ptr(&event.raiser) {
+                    event.raiser = None;
+                }
+                remove(&mut event.other_methods, ptr);
+            }
+        }
+        self.forget_method(ptr);
+        Ok(())
+    }
+
+    /// Adds a parameter to `method`, keeping the parameters ordered by their sequence.
+    pub fn add_param(&mut self, method: &Entry<Method>, param: Param) -> Result<Entry<Param>> {
+        if !contains(&self.methods, &method.0) {
+            return Err(HaoError::EntryNotInModule("Method"));
+        }
+        let sequence = param.sequence;
+        let ptr = Ptr::new(param);
+
+        let mut method = method.value_mut();
+        let index = method
+            .param_list
+            .iter()
+            .position(|p| p.value().sequence > sequence)
+            .unwrap_or(method.param_list.len());
+        method.param_list.insert(index, ptr.clone());
+        self.params.push(ptr.clone());
+        Ok(Entry(ptr))
+    }
+
+    /// Removes a parameter from its method.
+    pub fn remove_param(&mut self, param: &Entry<Param>) -> Result<()> {
+        if !remove(&mut self.params, &param.0) {
+            return Err(HaoError::EntryNotInModule("Param"));
+        }
+        for method in self.methods.iter() {
+            remove(&mut method.value_mut().param_list, &param.0);
+        }
+        self.forget_attributes(&param.value().custom_attributes);
+        Ok(())
+    }
+
+    /// Adds a type refrence to the module.
+    pub fn add_type_ref(&mut self, type_ref: TypeRef) -> Entry<TypeRef> {
+        let ptr = Ptr::new(type_ref);
+        self.type_refs.push(ptr.clone());
+        Entry(ptr)
+    }
+
+    /// Removes a type refrence from the module.
+    pub fn remove_type_ref(&mut self, type_ref: &Entry<TypeRef>) -> Result<()> {
+        if !remove(&mut self.type_refs, &type_ref.0) {
+            return Err(HaoError::EntryNotInModule("TypeRef"));
+        }
+        self.forget_other_attributes(
+            |p| matches!(p, OtherAttributeParent::TypeRef(t) if t.ptr_eq(&type_ref.0)),
+        );
+        Ok(())
+    }
+
+    /// Adds an assembly refrence to the module.
+    ///
+    /// ```
+    /// # use hao::{Module, dotnet::{
+    /// #     entries::{signature::ResolutionScope, values::{AssemblyRef, TypeRef}},
+    /// #     md::streams::tables_stream::AssemblyFlags,
+    /// # }};
+    /// let mut module = Module::default();
+    ///
+    /// let corlib = AssemblyRef::new("System.Runtime", (8, 0, 0, 0), AssemblyFlags::empty(), Vec::new());
+    /// let corlib = module.add_assembly_ref(corlib);
+    /// let object = module.add_type_ref(TypeRef::new(
+    ///     ResolutionScope::AssemblyRef(corlib),
+    ///     "System",
+    ///     "Object",
+    /// ));
+    ///
+    /// assert!(object.value().is_corlib());
+    /// ```
+    pub fn add_assembly_ref(&mut self, assembly_ref: AssemblyRef) -> Entry<AssemblyRef> {
+        let ptr = Ptr::new(assembly_ref);
+        self.assembly_ref.push(ptr.clone());
+        Entry(ptr)
+    }
+
+    /// Removes an assembly refrence from the module.
+    pub fn remove_assembly_ref(&mut self, assembly_ref: &Entry<AssemblyRef>) -> Result<()> {
+        if !remove(&mut self.assembly_ref, &assembly_ref.0) {
+            return Err(HaoError::EntryNotInModule("AssemblyRef"));
+        }
+        self.forget_other_attributes(
+            |p| matches!(p, OtherAttributeParent::AssemblyRef(a) if a.ptr_eq(&assembly_ref.0)),
+        );
+        Ok(())
+    }
+
+    // Removes a type and everything it owns from the module lists.
+    fn forget_type(&mut self, ty: &Ptr<TypeDef>) {
+        remove(&mut self.type_defs, ty);
+        self.forget_other_attributes(
+            |p| matches!(p, OtherAttributeParent::InterfaceImpl(class, _) if class.ptr_eq(ty)),
+        );
+
+        let ty = ty.value();
+        for nested in ty.nested_types.iter() {
+            self.forget_type(nested);
+        }
+        for field in ty.field_list.iter() {
+            remove(&mut self.fields, field);
+            self.forget_attributes(&field.value().custom_attributes);
+        }
+        for method in ty.method_list.iter() {
+            self.forget_method(method);
+        }
+        for property in ty.property_list.iter() {
+            remove(&mut self.properties, property);
+            self.forget_attributes(&property.value().custom_attributes);
+        }
+        for event in ty.event_list.iter() {
+            remove(&mut self.events, event);
+            self.forget_attributes(&event.value().custom_attributes);
+        }
+        self.forget_generic_params(&ty.generic_params);
+        self.forget_attributes(&ty.custom_attributes);
+    }
+
+    // Removes a method and everything it owns from the module lists.
+    fn forget_method(&mut self, method: &Ptr<Method>) {
+        remove(&mut self.methods, method);
+        if self.entry_point.as_ref().is_some_and(|m| m.ptr_eq(method)) {
+            self.entry_point = None;
+        }
+
+        let method = method.value();
+        for param in method.param_list.iter() {
+            remove(&mut self.params, param);
+            self.forget_attributes(&param.value().custom_attributes);
+        }
+        self.forget_generic_params(&method.generic_params);
+        self.forget_attributes(&method.custom_attributes);
+    }
+
+    fn forget_generic_params(&mut self, params: &[Ptr<GenericParam>]) {
+        for param in params {
+            remove(&mut self.generic_params, param);
+            self.forget_attributes(&param.value().custom_attributes);
+            self.forget_other_attributes(|p| {
+                matches!(p, OtherAttributeParent::GenericParamConstraint(owner, _) if owner.ptr_eq(param))
+            });
+        }
+    }
+
+    fn forget_attributes(&mut self, attributes: &[Ptr<CustomAttribute>]) {
+        self.custom_attributes.retain(|a| !contains(attributes, a));
+    }
+
+    // Removes the attributes on an entry that does not list its attributes.
+    fn forget_other_attributes(&mut self, is_parent: impl Fn(&OtherAttributeParent) -> bool) {
+        self.custom_attributes
+            .retain(|a| !a.value().other_parent.as_ref().is_some_and(&is_parent));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    dotnet::{md::image_headers::ComImageFlags, metadata::Metadata},
+    error::Result,
+};
+use pewter::pe::optional_header::OptionalHeaderMagic;
+
+/// The PE and CLI header values of a module that are not part of its metadata.
+///
+/// These are read when the module is loaded and used
+/// when the module is written with [`Module::write`](super::Module::write).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageInfo {
+    /// The `Machine` of the COFF header. e.g. `0x14c` for i386 or `0x8664` for AMD64.
+    pub machine: u16,
+    /// The `Characteristics` of the COFF header.
+    pub characteristics: u16,
+    /// If the image uses a PE32+ optional header.
+    pub pe32_plus: bool,
+    pub image_base: u64,
+    pub subsystem: u16,
+    pub dll_characteristics: u16,
+    /// The version string of the metadata root. e.g. `v4.0.30319`.
+    pub runtime_version: String,
+    pub major_runtime_version: u16,
+    pub minor_runtime_version: u16,
+    pub flags: ComImageFlags,
+}
+
+impl ImageInfo {
+    const IMAGE_FILE_DLL: u16 = 0x2000;
+
+    pub(crate) fn from_metadata(metadata: &Metadata) -> Result<Self> {
+        let pe = &metadata.pe_image.pe;
+        let optional_header = metadata.pe_image.optional_header()?;
+        let windows_fields = &optional_header.windows_specific_fields;
+
+        Ok(Self {
+            machine: pe.coff_header.machine.to_u16(),
+            characteristics: pe.coff_header.characteristics.bits(),
+            pe32_plus: optional_header.standard_fields.magic == OptionalHeaderMagic::PE32Plus,
+            image_base: windows_fields.image_base(),
+            subsystem: windows_fields.subsystem().to_u16(),
+            dll_characteristics: windows_fields.dll_characteristics().bits(),
+            runtime_version: metadata.metadata_header.version.to_string(),
+            major_runtime_version: metadata.cor20_header.major_rt_version,
+            minor_runtime_version: metadata.cor20_header.minor_rt_version,
+            flags: ComImageFlags::from_bits_retain(metadata.cor20_header.flags),
+        })
+    }
+
+    /// Returns `true` if the image is a dll rather than an executable.
+    pub fn is_dll(&self) -> bool {
+        self.characteristics & Self::IMAGE_FILE_DLL != 0
+    }
+}
+
+impl Default for ImageInfo {
+    /// The values used by the C# compiler for an `AnyCPU` library.
+    fn default() -> Self {
+        Self {
+            machine: 0x14C,
+            characteristics: 0x2102,
+            pe32_plus: false,
+            image_base: 0x1000_0000,
+            subsystem: 3,
+            dll_characteristics: 0x8540,
+            runtime_version: "v4.0.30319".to_string(),
+            major_runtime_version: 2,
+            minor_runtime_version: 5,
+            flags: ComImageFlags::ILOnly,
+        }
+    }
+}
@@ -1,21 +1,37 @@
+use std::cell::RefCell;
 use std::fmt::Debug;
+use std::rc::Rc;
 
-use crate::dotnet::entries::{EntryCollection, EntryView, MaybeUninitEntries};
+use crate::dotnet::entries::{
+    custom_attributes::AttributeSource, resolve::ResolvedMember, resolve_member_ref,
+    resolve_type_ref, Entry, EntryCollection, EntryView, MaybeUninitEntries, Ptr,
+};
 use crate::dotnet::{
-    entries::{values::*, EntList},
+    entries::{
+        body::BodySource,
+        signature::{GenericNames, TypeDefOrRef, TypeOrMethodDef, ValueType},
+        values::*,
+        EntList, MaybeUnsetEntry,
+    },
+    md::{
+        image_headers::ComImageFlags,
+        streams::tables_stream::{MethodSemanticsFlags, TableId},
+        MDToken,
+    },
     metadata::Metadata,
 };
 
-use crate::error::HaoError;
+use crate::error::{HaoError, LoadDiagnostic};
 use crate::{error::Result, io::EntryReader};
 
+use super::image_info::ImageInfo;
 use super::resolver::PathAssemblyResolver;
 use super::resolver::{AssemblyLoadResult, AssemblyResolver};
 
 /// Represents a loaded .net module.
 /// ```no_run
 /// # use hao::Module;
-/// let module = Module::from_file(r#"Example.Net.dll"#);
+/// let module = Module::from_path(r#"Example.Net.dll"#).unwrap();
 ///
 /// for ty in module.types().values() {
 ///    for method in ty.methods().values() {
@@ -31,11 +47,36 @@ pub struct Module {
     pub(crate) fields: EntList<Field>,
     pub(crate) methods: EntList<Method>,
     pub(crate) params: EntList<Param>,
+    pub(crate) member_refs: EntList<MemberRef>,
+    pub(crate) method_specs: EntList<MethodSpec>,
 
     pub(crate) module_ref: EntList<ModuleRef>,
     pub(crate) type_specs: EntList<TypeSpec>,
+    pub(crate) stand_alone_sigs: EntList<StandAloneSig>,
 
+    pub(crate) assembly: EntList<Assembly>,
     pub(crate) assembly_ref: EntList<AssemblyRef>,
+    pub(crate) files: EntList<FileDef>,
+    pub(crate) resources: EntList<ManifestResource>,
+    pub(crate) exported_types: EntList<ExportedType>,
+
+    pub(crate) properties: EntList<Property>,
+    pub(crate) events: EntList<Event>,
+    pub(crate) generic_params: EntList<GenericParam>,
+
+    pub(crate) custom_attributes: EntList<CustomAttribute>,
+
+    pub(crate) enc_log: EntList<EncLog>,
+    pub(crate) enc_map: EntList<EncMap>,
+
+    pub(crate) image_info: ImageInfo,
+    pub(crate) entry_point: Option<Ptr<Method>>,
+
+    // Method bodies and custom attribute blobs are decoded from these on demand.
+    // Methods and attributes only hold a weak refrence to them, as they hold entries
+    // that refrence the methods and attributes. The attribute source is only held here.
+    pub(crate) body_source: Rc<BodySource>,
+    _attribute_source: Rc<AttributeSource>,
 }
 
 impl Module {
@@ -43,7 +84,8 @@ impl Module {
     /// with the default [`PathAssemblyResolver`] resolver.
     ///
     /// ```no_run
-    /// let module = Module::from_path(r#"Example.Net.dll"#)?;
+    /// # use hao::Module;
+    /// let module = Module::from_path(r#"Example.Net.dll"#).unwrap();
     /// ```
     pub fn from_path(path: impl AsRef<std::path::Path>) -> Result<Self> {
         let mut exec_path = path.as_ref().to_owned();
@@ -53,7 +95,7 @@ impl Module {
             let md = Metadata::parse(&data)?;
             Self::from_metadata(&md)?
         };
-        
+
         exec_path.pop();
         let mut resolver: PathAssemblyResolver = PathAssemblyResolver::new(&exec_path);
         asm.load_dependancies(&mut resolver)?;
@@ -63,7 +105,8 @@ impl Module {
     /// Load a .net assembly from the given path but do not reolve its dependancies.
     ///
     /// ```no_run
-    /// let module = Module::from_path_no_resolve(r#"Example.Net.dll"#)?;
+    /// # use hao::Module;
+    /// let module = Module::from_path_no_resolve(r#"Example.Net.dll"#).unwrap();
     /// ```
     pub fn from_path_no_resolve(path: impl AsRef<std::path::Path>) -> Result<Self> {
         let data = std::fs::read(path.as_ref()).map_err(HaoError::IoError)?;
@@ -82,7 +125,32 @@ impl Module {
     /// let loaded_module = Module::from_metadata(&md).unwrap();
     /// ```
     pub fn from_metadata(metadada: &Metadata) -> Result<Self> {
-        let entries = {
+        Self::load(metadada, false).map(|(module, _)| module)
+    }
+
+    /// Load a .net assembly from metadata, continuing past malformed rows.
+    /// This will not resolve the modules dependancies.
+    ///
+    /// Columns that fail to load are replaced with an empty value, and rows that fail to load
+    /// are replaced with a placeholder entry that has an empty name.
+    /// Each problem encountered is returned as a [`LoadDiagnostic`].
+    /// ```no_run
+    /// # use hao::{Module, dotnet::Metadata};
+    /// let data = std::fs::read("Example.Net.dll").unwrap();
+    /// let md = Metadata::parse(&data).unwrap();
+    /// let (loaded_module, diagnostics) = Module::from_metadata_lenient(&md).unwrap();
+    ///
+    /// for diagnostic in diagnostics {
+    ///     println!("{}", diagnostic);
+    /// }
+    /// ```
+    pub fn from_metadata_lenient(metadada: &Metadata) -> Result<(Self, Vec<LoadDiagnostic>)> {
+        Self::load(metadada, true)
+    }
+
+    fn load(metadada: &Metadata, lenient: bool) -> Result<(Self, Vec<LoadDiagnostic>)> {
+        let image_info = ImageInfo::from_metadata(metadada)?;
+        let (entries, diagnostics) = {
             let locations = &metadada
                 .metadata_streams
                 .tables_stream
@@ -90,30 +158,385 @@ impl Module {
                 .table_locations;
 
             let maybe_entries = MaybeUninitEntries::from_rows(locations);
-            let reader = EntryReader::from_metadata(&metadada.metadata_streams, &maybe_entries);
+            let reader = EntryReader::from_metadata(metadada, &maybe_entries, lenient);
             maybe_entries.init_rows(locations, &reader)?;
-            maybe_entries
+            let diagnostics = reader.into_diagnostics();
+            (maybe_entries, diagnostics)
         };
 
+        let body_source = Rc::new(BodySource::new(metadada, &entries));
+        let attribute_source = Rc::new(AttributeSource::new(&entries));
+        for method in &entries.methods {
+            if let Some(mut method) = method.try_value_mut() {
+                method.body_source = Rc::downgrade(&body_source);
+            }
+        }
+
+        // Rows that failed to load leniently are left unset.
         for if_impl in entries.interface_impls {
-            let inner = if_impl.into_inner();
-            let InterfaceImpl { class, interface } = inner.assume_init_value();
+            let Some(InterfaceImpl { class, interface }) = if_impl.into_inner().into_value() else {
+                continue;
+            };
             class.value_mut().interface_impl.push(interface);
         }
 
-        Ok(Self {
+        for row in entries.nested_classes {
+            let Some(NestedClassRow {
+                nested_class,
+                enclosing_class,
+            }) = row.into_inner().into_value()
+            else {
+                continue;
+            };
+            nested_class.value_mut().declaring_type = Some(enclosing_class.clone());
+            enclosing_class.value_mut().nested_types.push(nested_class);
+        }
+
+        for map in entries.property_maps {
+            let Some(PropertyMapRow { parent, properties }) = map.into_inner().into_value() else {
+                continue;
+            };
+            parent.value_mut().property_list.extend(properties);
+        }
+
+        for map in entries.event_maps {
+            let Some(EventMapRow { parent, events }) = map.into_inner().into_value() else {
+                continue;
+            };
+            parent.value_mut().event_list.extend(events);
+        }
+
+        for row in entries.method_semantics {
+            let Some(MethodSemanticsRow {
+                semantic,
+                method,
+                association,
+            }) = row.into_inner().into_value()
+            else {
+                continue;
+            };
+            method.value_mut().semantics |= semantic;
+
+            match association {
+                HasSemanticPtr::Property(p) => {
+                    let mut property = p.value_mut();
+                    if semantic.contains(MethodSemanticsFlags::Getter) {
+                        property.getter = Some(method);
+                    } else if semantic.contains(MethodSemanticsFlags::Setter) {
+                        property.setter = Some(method);
+                    } else {
+                        property.other_methods.push(method);
+                    }
+                }
+                HasSemanticPtr::Event(e) => {
+                    let mut event = e.value_mut();
+                    if semantic.contains(MethodSemanticsFlags::AddOn) {
+                        event.adder = Some(method);
+                    } else if semantic.contains(MethodSemanticsFlags::RemoveOn) {
+                        event.remover = Some(method);
+                    } else if semantic.contains(MethodSemanticsFlags::Fire) {
+                        event.raiser = Some(method);
+                    } else {
+                        event.other_methods.push(method);
+                    }
+                }
+            }
+        }
+
+        for row in entries.generic_param_constraints {
+            let Some(GenericParamConstraintRow { owner, constraint }) =
+                row.into_inner().into_value()
+            else {
+                continue;
+            };
+            owner.value_mut().constraints.push(constraint);
+        }
+
+        for param in entries.generic_params.iter() {
+            match &param.value().owner {
+                TypeOrMethodDef::TypeDef(t) => t.0.value_mut().generic_params.push(param.clone()),
+                TypeOrMethodDef::Method(m) => m.0.value_mut().generic_params.push(param.clone()),
+            }
+        }
+
+        Self::set_generic_names(&entries.type_defs);
+
+        for row in entries.constants {
+            let Some(ConstantRow { parent, value }) = row.into_inner().into_value() else {
+                continue;
+            };
+            match parent {
+                HasConstantPtr::Field(f) => f.value_mut().constant = Some(value),
+                HasConstantPtr::Param(p) => p.value_mut().default_value = Some(value),
+                HasConstantPtr::Property(p) => p.value_mut().constant = Some(value),
+            }
+        }
+
+        for row in entries.class_layouts {
+            let Some(ClassLayoutRow { parent, layout }) = row.into_inner().into_value() else {
+                continue;
+            };
+            parent.value_mut().class_layout = Some(layout);
+        }
+
+        for row in entries.field_layouts {
+            let Some(FieldLayoutRow { field, offset }) = row.into_inner().into_value() else {
+                continue;
+            };
+            field.value_mut().offset = Some(offset);
+        }
+
+        // Needs the class layouts to know the size of each value.
+        Self::read_field_data(metadada, entries.field_rvas);
+
+        Self::read_resource_data(metadada, &entries.manifest_resources);
+
+        for row in entries.field_marshals {
+            let Some(FieldMarshalRow {
+                parent,
+                native_type,
+            }) = row.into_inner().into_value()
+            else {
+                continue;
+            };
+            match parent {
+                HasFieldMarshalPtr::Field(f) => f.value_mut().marshal = Some(native_type),
+                HasFieldMarshalPtr::Param(p) => p.value_mut().marshal = Some(native_type),
+            }
+        }
+
+        for row in entries.impl_maps {
+            let Some(ImplMapRow { member, map }) = row.into_inner().into_value() else {
+                continue;
+            };
+            if let MemberForwardedPtr::Method(m) = member {
+                m.value_mut().pinvoke = Some(map);
+            }
+        }
+
+        for row in entries.method_impls {
+            let Some(MethodImplRow { class, method_impl }) = row.into_inner().into_value() else {
+                continue;
+            };
+            class.value_mut().method_impls.push(method_impl);
+        }
+
+        let mut custom_attributes = EntList::with_capacity(entries.custom_attributes.len());
+        for (index, row) in entries.custom_attributes.into_iter().enumerate() {
+            let Some(CustomAttributeRow {
+                parent,
+                mut attribute,
+            }) = row.into_inner().into_value()
+            else {
+                continue;
+            };
+            attribute.source = Rc::downgrade(&attribute_source);
+            let attribute_ptr = Ptr::new_unset();
+            attribute_ptr.set_value((index + 1) as u32, attribute);
+
+            match parent {
+                HasCustomAttributePtr::Module(m) => {
+                    m.value_mut().custom_attributes.push(attribute_ptr.clone())
+                }
+                HasCustomAttributePtr::TypeDef(t) => {
+                    t.value_mut().custom_attributes.push(attribute_ptr.clone())
+                }
+                HasCustomAttributePtr::Field(f) => {
+                    f.value_mut().custom_attributes.push(attribute_ptr.clone())
+                }
+                HasCustomAttributePtr::Method(m) => {
+                    m.value_mut().custom_attributes.push(attribute_ptr.clone())
+                }
+                HasCustomAttributePtr::Param(p) => {
+                    p.value_mut().custom_attributes.push(attribute_ptr.clone())
+                }
+                HasCustomAttributePtr::Property(p) => {
+                    p.value_mut().custom_attributes.push(attribute_ptr.clone())
+                }
+                HasCustomAttributePtr::Event(e) => {
+                    e.value_mut().custom_attributes.push(attribute_ptr.clone())
+                }
+                HasCustomAttributePtr::GenericParam(g) => {
+                    g.value_mut().custom_attributes.push(attribute_ptr.clone())
+                }
+                HasCustomAttributePtr::Assembly(a) => {
+                    a.value_mut().custom_attributes.push(attribute_ptr.clone())
+                }
+                HasCustomAttributePtr::Other(_) => {}
+            }
+            custom_attributes.push(attribute_ptr);
+        }
+
+        // Native entry points are not kept, as the native code is not loaded.
+        let entry_point_token = MDToken::from_raw(metadada.cor20_header.entrypoint_token_or_rva);
+        let entry_point = if image_info.flags.contains(ComImageFlags::NativeEntryPoint)
+            || entry_point_token.table() != Some(TableId::Method)
+        {
+            None
+        } else {
+            entry_point_token
+                .rid()
+                .checked_sub(1)
+                .and_then(|index| entries.methods.get(index as usize))
+                .cloned()
+        };
+
+        let module = Self {
             modules: entries.modules,
             type_refs: entries.type_refs,
             type_defs: entries.type_defs,
             fields: entries.fields,
             methods: entries.methods,
             params: entries.params,
+            member_refs: entries.member_refs,
+            method_specs: entries.method_specs,
 
             module_ref: entries.module_ref,
             type_specs: entries.type_specs,
+            stand_alone_sigs: entries.stand_alone_sigs,
 
+            assembly: entries.assembly,
             assembly_ref: entries.assembly_ref,
-        })
+            files: entries.files,
+            resources: entries.manifest_resources,
+            exported_types: entries.exported_types,
+
+            properties: entries.properties,
+            events: entries.events,
+            generic_params: entries.generic_params,
+
+            custom_attributes,
+
+            enc_log: entries.enc_log,
+            enc_map: entries.enc_map,
+
+            image_info,
+            entry_point,
+
+            body_source,
+            _attribute_source: attribute_source,
+        };
+
+        Ok((module, diagnostics))
+    }
+
+    // The size of the initial value of a field is not stored in the image.
+    // It is taken from the size of the field type, falling back to
+    // the distance to the next initial value or the end of the section.
+    fn read_field_data(metadada: &Metadata, rows: Vec<RefCell<MaybeUnsetEntry<FieldRvaRow>>>) {
+        fn type_size(ty: &ValueType) -> Option<usize> {
+            match ty {
+                ValueType::Boolean | ValueType::SByte | ValueType::Byte => Some(1),
+                ValueType::Char | ValueType::Int16 | ValueType::UInt16 => Some(2),
+                ValueType::Int32 | ValueType::UInt32 | ValueType::Single => Some(4),
+                ValueType::Int64 | ValueType::UInt64 | ValueType::Double => Some(8),
+                ValueType::ValueType(TypeDefOrRef::TypeDef(t)) => t
+                    .value()
+                    .class_layout
+                    .map(|l| l.class_size as usize)
+                    .filter(|size| *size > 0),
+                _ => None,
+            }
+        }
+
+        let rows = rows
+            .into_iter()
+            .filter_map(|row| row.into_inner().into_value())
+            .collect::<Vec<_>>();
+
+        let mut rvas = rows.iter().map(|row| row.rva).collect::<Vec<_>>();
+        rvas.sort_unstable();
+        rvas.dedup();
+
+        for FieldRvaRow { field, rva } in rows {
+            let Some(data) = metadada.pe_image.pe.sections.find_rva_data(rva as usize) else {
+                continue;
+            };
+            let next = rvas
+                .iter()
+                .find(|next| **next > rva)
+                .map(|next| (next - rva) as usize);
+
+            let size = type_size(&field.value().signature.0)
+                .or(next)
+                .unwrap_or(data.len())
+                .min(data.len());
+            field.value_mut().initial_value = Some(data[..size].to_vec());
+        }
+    }
+
+    // Embedded resources are stored one after the other in the resources directory,
+    // each prefixed with its size. See ECMA-335 II.24.2.4.
+    fn read_resource_data(metadada: &Metadata, resources: &EntList<ManifestResource>) {
+        let directory = metadada.cor20_header.resources;
+        if directory.virtual_address == 0 {
+            return;
+        }
+
+        for resource in resources {
+            let mut resource = resource.value_mut();
+            if !resource.is_embedded() {
+                continue;
+            }
+            let Some(data) = metadada
+                .pe_image
+                .pe
+                .sections
+                .find_rva_data(directory.virtual_address as usize)
+                .and_then(|data| data.get(..directory.size as usize))
+                .and_then(|data| data.get(resource.offset as usize..))
+            else {
+                continue;
+            };
+            let Some(size) = data
+                .get(..4)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            else {
+                continue;
+            };
+            resource.data = data.get(4..4 + size as usize).map(|d| d.to_vec());
+        }
+    }
+
+    // Gives the generic parameters used in the signatures of
+    // each type and its members the name of their declaration.
+    fn set_generic_names(type_defs: &EntList<TypeDef>) {
+        fn names(params: &[Ptr<GenericParam>]) -> Vec<String> {
+            let mut params = params
+                .iter()
+                .map(|p| (p.value().number, p.value().name.clone()))
+                .collect::<Vec<_>>();
+            params.sort_by_key(|(number, _)| *number);
+            params.into_iter().map(|(_, name)| name).collect()
+        }
+
+        for ty in type_defs {
+            let ty = ty.value();
+            let type_params = names(&ty.generic_params);
+
+            let type_names = GenericNames {
+                type_params: &type_params,
+                method_params: &[],
+            };
+            for field in ty.field_list.iter() {
+                field.value_mut().signature.set_generic_names(&type_names);
+            }
+            for property in ty.property_list.iter() {
+                property
+                    .value_mut()
+                    .signature
+                    .set_generic_names(&type_names);
+            }
+
+            for method in ty.method_list.iter() {
+                let mut method = method.value_mut();
+                let method_params = names(&method.generic_params);
+                method.signature.set_generic_names(&GenericNames {
+                    type_params: &type_params,
+                    method_params: &method_params,
+                });
+            }
+        }
     }
 
     /// Attempts to load the refrenced assemblies using the given resolver.
@@ -132,19 +555,127 @@ impl Module {
         Ok(())
     }
 
+    /// Writes the module as a .net PE image.
+    ///
+    /// The metadata is rebuilt from the loaded entries, so tokens and heap offsets
+    /// may differ from the image the module was loaded from.
+    /// Win32 resources, the strong name signature, debug information
+    /// and declarative security are not written. Embedded resources are written from
+    /// [`ManifestResource::data`], so they can not be written if their data was not loaded.
+    /// ```no_run
+    /// # use hao::Module;
+    /// let module = Module::from_path_no_resolve(r#"Example.Net.dll"#).unwrap();
+    /// let image = module.write().unwrap();
+    /// ```
+    pub fn write(&self) -> Result<Vec<u8>> {
+        super::writer::write_module(self)
+    }
+
+    /// Writes the module as a .net PE image to the given path.
+    /// See [`Module::write`].
+    ///
+    /// ```no_run
+    /// # use hao::Module;
+    /// let module = Module::from_path_no_resolve(r#"Example.Net.dll"#).unwrap();
+    /// module.write_to_path(r#"Example.Net.Copy.dll"#).unwrap();
+    /// ```
+    pub fn write_to_path(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        let data = self.write()?;
+        std::fs::write(path, data).map_err(HaoError::IoError)
+    }
+
     /// Returns the module infomation of the current module as a [`EntryView`].
     #[inline(always)]
-    pub fn module(&self) -> EntryView<ModuleDef> {
+    pub fn module(&self) -> EntryView<'_, ModuleDef> {
         EntryView(&self.modules[0])
     }
 
+    /// Returns the assembly manifest of the module.
+    ///
+    /// This is `None` for modules that are not the main module of an assembly, such as a `.netmodule`.
+    /// ```no_run
+    /// # use hao::Module;
+    /// let module = Module::from_path_no_resolve(r#"Example.Net.dll"#).unwrap();
+    ///
+    /// if let Some(assembly) = module.assembly() {
+    ///     println!("{}", assembly.value().full_name());
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn assembly(&self) -> Option<EntryView<'_, Assembly>> {
+        self.assembly.first().map(EntryView)
+    }
+
+    /// Finds a type that is not nested in another type by its namespace and name.
+    ///
+    /// ```
+    /// # use hao::{Module, dotnet::{entries::values::TypeDef, md::streams::tables_stream::TypeAttributes}};
+    /// let mut module = Module::default();
+    /// module.add_type(TypeDef::new(TypeAttributes::Public, "Example", "Program", None));
+    ///
+    /// assert!(module.find_type("Example", "Program").is_some());
+    /// ```
+    pub fn find_type(&self, namespace: &str, name: &str) -> Option<Entry<TypeDef>> {
+        self.type_defs
+            .iter()
+            .find(|t| {
+                let t = t.value();
+                t.declaring_type.is_none() && t.full_name_is(namespace, name)
+            })
+            .map(|t| Entry(t.clone()))
+    }
+
+    /// Finds the definition of a type refrence in this module.
+    ///
+    /// Unlike [`TypeRef::resolve`], this also resolves refrences to types in this module.
+    pub fn resolve_type_ref(&self, type_ref: &TypeRef) -> Result<Entry<TypeDef>> {
+        resolve_type_ref(type_ref, Some(self), 0).map(|(ty, _)| ty)
+    }
+
+    /// Finds the definition of a member refrence in this module.
+    ///
+    /// Unlike [`MemberRef::resolve`], this also resolves refrences to members of types in this module.
+    pub fn resolve_member_ref(&self, member_ref: &MemberRef) -> Result<ResolvedMember> {
+        resolve_member_ref(member_ref, Some(self))
+    }
+
     /// Returns an [`EntryCollection`] of [`TypeRef`] with  all the type refrences
     /// inside the current module.
     #[inline(always)]
-    pub fn type_refs(&self) -> EntryCollection<TypeRef> {
+    pub fn type_refs(&self) -> EntryCollection<'_, TypeRef> {
         EntryCollection::new(&self.type_refs)
     }
 
+    /// Returns all the field and method refrences inside the current module.
+    ///
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for member in module.member_refs().values() {
+    ///     println!("{} {}", member.signature(), member);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn member_refs(&self) -> EntryCollection<'_, MemberRef> {
+        EntryCollection::new(&self.member_refs)
+    }
+
+    /// Returns all the generic method instantiations inside the current module.
+    ///
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for spec in module.method_specs().values() {
+    ///     println!("{}", spec);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn method_specs(&self) -> EntryCollection<'_, MethodSpec> {
+        EntryCollection::new(&self.method_specs)
+    }
+
     /// Returns all the type refrences inside the current module.
     ///
     /// ```
@@ -156,7 +687,7 @@ impl Module {
     /// }
     /// ```
     #[inline(always)]
-    pub fn types(&self) -> EntryCollection<TypeDef> {
+    pub fn types(&self) -> EntryCollection<'_, TypeDef> {
         EntryCollection::new(&self.type_defs)
     }
 
@@ -172,7 +703,7 @@ impl Module {
     /// }
     /// ```
     #[inline(always)]
-    pub fn all_fields(&self) -> EntryCollection<Field> {
+    pub fn all_fields(&self) -> EntryCollection<'_, Field> {
         EntryCollection::new(&self.fields)
     }
 
@@ -188,7 +719,7 @@ impl Module {
     /// }
     /// ```
     #[inline(always)]
-    pub fn all_methods(&self) -> EntryCollection<Method> {
+    pub fn all_methods(&self) -> EntryCollection<'_, Method> {
         EntryCollection::new(&self.methods)
     }
 
@@ -204,25 +735,179 @@ impl Module {
     /// }
     /// ```
     #[inline(always)]
-    pub fn all_params(&self) -> EntryCollection<Param> {
+    pub fn all_params(&self) -> EntryCollection<'_, Param> {
         EntryCollection::new(&self.params)
     }
 
     /// Returns all the type specs  defined in the module.
     #[inline(always)]
-    pub fn all_type_specs(&self) -> EntryCollection<TypeSpec> {
+    pub fn all_type_specs(&self) -> EntryCollection<'_, TypeSpec> {
         EntryCollection::new(&self.type_specs)
     }
 
+    /// Returns all the stand alone signatures defined in the module.
+    ///
+    /// These hold the local variables of method bodies and the call site signatures of `calli`.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for sig in module.stand_alone_sigs().values() {
+    ///     println!("{}", sig.signature());
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn stand_alone_sigs(&self) -> EntryCollection<'_, StandAloneSig> {
+        EntryCollection::new(&self.stand_alone_sigs)
+    }
+
     /// Returns all the modules refrenced in the module.
     #[inline(always)]
-    pub fn module_ref(&self) -> EntryCollection<ModuleRef> {
+    pub fn module_ref(&self) -> EntryCollection<'_, ModuleRef> {
         EntryCollection::new(&self.module_ref)
     }
 
     /// Returns all the assemblies  refrenced in the module.
     #[inline(always)]
-    pub fn assembly_ref(&self) -> EntryCollection<AssemblyRef> {
+    pub fn assembly_ref(&self) -> EntryCollection<'_, AssemblyRef> {
         EntryCollection::new(&self.assembly_ref)
     }
+
+    /// Returns the files of the assembly, when it is made up of multiple files.
+    #[inline(always)]
+    pub fn files(&self) -> EntryCollection<'_, FileDef> {
+        EntryCollection::new(&self.files)
+    }
+
+    /// Returns the resources of the assembly.
+    ///
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for resource in module.resources().values() {
+    ///     match resource.implementation() {
+    ///         Some(implementation) => println!("{} (in {})", resource.name(), implementation),
+    ///         None => println!("{} ({} bytes)", resource.name(), resource.data().map_or(0, |d| d.len())),
+    ///     }
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn resources(&self) -> EntryCollection<'_, ManifestResource> {
+        EntryCollection::new(&self.resources)
+    }
+
+    /// Returns the types of the assembly that are defined in its other files,
+    /// or forwarded to other assemblies.
+    ///
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for ty in module.exported_types().values().filter(|t| t.is_forwarder()) {
+    ///     println!("{} -> {}", ty.full_name(), ty.implementation());
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn exported_types(&self) -> EntryCollection<'_, ExportedType> {
+        EntryCollection::new(&self.exported_types)
+    }
+
+    /// Returns all the properties defined in the module regardless of the parent type.
+    ///
+    /// If you want the associated type, use [`TypeDef::properties`].
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for property in module.all_properties().values() {
+    ///     println!("{}", property);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn all_properties(&self) -> EntryCollection<'_, Property> {
+        EntryCollection::new(&self.properties)
+    }
+
+    /// Returns all the events defined in the module regardless of the parent type.
+    ///
+    /// If you want the associated type, use [`TypeDef::events`].
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for event in module.all_events().values() {
+    ///     println!("{}", event);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn all_events(&self) -> EntryCollection<'_, Event> {
+        EntryCollection::new(&self.events)
+    }
+
+    /// Returns all the generic parameters defined in the module regardless of their owner.
+    ///
+    /// If you want the parameters of an entry, use [`TypeDef::generic_params`] or [`Method::generic_params`].
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for param in module.all_generic_params().values() {
+    ///     println!("{} {}", param.owner(), param.name());
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn all_generic_params(&self) -> EntryCollection<'_, GenericParam> {
+        EntryCollection::new(&self.generic_params)
+    }
+
+    /// Returns all the custom attributes defined in the module regardless of what they are applied to.
+    ///
+    /// If you want the attributes of an entry, use [`TypeDef::custom_attributes`],
+    /// [`Method::custom_attributes`], [`Field::custom_attributes`], [`Param::custom_attributes`],
+    /// [`Property::custom_attributes`], [`Event::custom_attributes`] or [`ModuleDef::custom_attributes`].
+    /// Attributes on entries that do not list their attributes, such as type refrences
+    /// and interface implementations, are only found here.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for attribute in module.all_custom_attributes().values() {
+    ///     println!("{}", attribute);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn all_custom_attributes(&self) -> EntryCollection<'_, CustomAttribute> {
+        EntryCollection::new(&self.custom_attributes)
+    }
+
+    /// Returns the edit-and-continue log of the module.
+    /// This is only present in uncompressed (#-) metadata.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for log in module.enc_log().values() {
+    ///     println!("{} {}", log.token(), log.func_code());
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn enc_log(&self) -> EntryCollection<'_, EncLog> {
+        EntryCollection::new(&self.enc_log)
+    }
+
+    /// Returns the edit-and-continue token map of the module.
+    /// This is only present in uncompressed (#-) metadata.
+    /// ```
+    /// # use hao::Module;
+    /// let module = Module::default();
+    ///
+    /// for map in module.enc_map().values() {
+    ///     println!("{}", map.token());
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn enc_map(&self) -> EntryCollection<'_, EncMap> {
+        EntryCollection::new(&self.enc_map)
+    }
 }
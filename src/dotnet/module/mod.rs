@@ -1,3 +1,8 @@
+mod edit;
+mod image_info;
 mod loaded;
 pub mod resolver;
+mod token;
+mod writer;
 pub use loaded::Module;
+pub use token::ResolvedToken;
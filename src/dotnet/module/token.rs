@@ -0,0 +1,75 @@
+use crate::dotnet::{
+    entries::{values::*, EntList, Entry},
+    md::{streams::tables_stream::TableId, MDToken},
+};
+
+use super::Module;
+
+/// The entry a metadata token refrences. See [`Module::resolve_token`].
+#[derive(Debug, Clone)]
+pub enum ResolvedToken {
+    TypeDef(Entry<TypeDef>),
+    TypeRef(Entry<TypeRef>),
+    TypeSpec(Entry<TypeSpec>),
+    Method(Entry<Method>),
+    Field(Entry<Field>),
+    MemberRef(Entry<MemberRef>),
+    MethodSpec(Entry<MethodSpec>),
+    StandAloneSig(Entry<StandAloneSig>),
+    UserString(String),
+}
+
+// Entries keep the row they were loaded from, which
+// no longer matches their index once entries have been removed.
+fn find_entry<T>(list: &EntList<T>, rid: u32) -> Option<Entry<T>> {
+    let index = rid.checked_sub(1)? as usize;
+    list.get(index)
+        .filter(|e| e.value().entry_index() == rid)
+        .or_else(|| list.iter().find(|e| e.value().entry_index() == rid))
+        .cloned()
+        .map(Entry)
+}
+
+impl Module {
+    /// Finds the entry a metadata token refrences.
+    ///
+    /// Tokens are those of the image the module was loaded from,
+    /// as found in method bodies and custom debug information.
+    /// Entries added after loading have no token, so are never returned.
+    ///
+    /// Returns `None` if the token does not refrence a type, method, field, member refrence,
+    /// method instantiation, stand alone signature or user string in the module.
+    ///
+    /// ```
+    /// # use hao::{Module, dotnet::md::{MDToken, streams::tables_stream::TableId}};
+    /// let module = Module::default();
+    ///
+    /// assert!(module.resolve_token(MDToken::new(TableId::TypeDef, 1)).is_none());
+    /// ```
+    pub fn resolve_token(&self, token: MDToken) -> Option<ResolvedToken> {
+        if token.is_user_string() {
+            return self
+                .body_source
+                .user_strings()
+                .read_string(token.rid())
+                .ok()
+                .map(ResolvedToken::UserString);
+        }
+
+        let rid = token.rid();
+        let resolved = match token.table()? {
+            TableId::TypeDef => ResolvedToken::TypeDef(find_entry(&self.type_defs, rid)?),
+            TableId::TypeRef => ResolvedToken::TypeRef(find_entry(&self.type_refs, rid)?),
+            TableId::TypeSpec => ResolvedToken::TypeSpec(find_entry(&self.type_specs, rid)?),
+            TableId::Method => ResolvedToken::Method(find_entry(&self.methods, rid)?),
+            TableId::Field => ResolvedToken::Field(find_entry(&self.fields, rid)?),
+            TableId::MemberRef => ResolvedToken::MemberRef(find_entry(&self.member_refs, rid)?),
+            TableId::MethodSpec => ResolvedToken::MethodSpec(find_entry(&self.method_specs, rid)?),
+            TableId::StandAloneSig => {
+                ResolvedToken::StandAloneSig(find_entry(&self.stand_alone_sigs, rid)?)
+            }
+            _ => return None,
+        };
+        Some(resolved)
+    }
+}
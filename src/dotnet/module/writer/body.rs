@@ -0,0 +1,317 @@
+use super::tokens::Rids;
+use crate::{
+    dotnet::{
+        entries::body::{
+            ExceptionHandler, ExceptionHandlerKind, Instruction, MethodBody, MethodBodyFlags,
+            MethodDataSectionFlags, OpCode, Operand, OperandType,
+        },
+        md::streams::UserStringsHeapBuilder,
+    },
+    error::{HaoError, Result},
+    io::{DataWriter, WriteData},
+};
+
+/// Encodes a [`MethodBody`] from its instructions. See ECMA-335 II.25.4.
+///
+/// The original code is not reused, as the tokens of the operands
+/// and the size of branches may change when the module is written.
+pub(crate) struct MethodBodyWriter<'a> {
+    writer: DataWriter,
+    rids: &'a Rids,
+    user_strings: &'a mut UserStringsHeapBuilder,
+}
+
+impl<'a> MethodBodyWriter<'a> {
+    const TINY_MAX_CODE_SIZE: usize = 0x40;
+    const TINY_MAX_STACK: u16 = 8;
+    const FAT_HEADER_SIZE: u16 = 3;
+
+    pub fn new(rids: &'a Rids, user_strings: &'a mut UserStringsHeapBuilder) -> Self {
+        Self {
+            writer: DataWriter::new(),
+            rids,
+            user_strings,
+        }
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.writer.into_inner()
+    }
+
+    fn write_code(&mut self, instructions: &[Instruction]) -> Result<(Vec<u8>, Vec<u32>)> {
+        let opcodes = relax_branches(instructions)?;
+        let offsets = instruction_offsets(instructions, &opcodes);
+        let code_size = offsets[instructions.len()];
+
+        let mut code = DataWriter::new();
+        for (index, (instruction, opcode)) in instructions.iter().zip(opcodes).enumerate() {
+            let value = opcode.value();
+            if opcode.is_two_byte() {
+                code.write_slice(&value.to_be_bytes());
+            } else {
+                code.write(value as u8)?;
+            }
+
+            let next = offsets[index + 1] as i64;
+            let branch_delta = |target: usize| -> Result<i64> {
+                offsets
+                    .get(target)
+                    .filter(|_| target < instructions.len())
+                    .map(|offset| *offset as i64 - next)
+                    .ok_or(HaoError::BadImageFormat(
+                        "Branch target is not an instruction",
+                    ))
+            };
+
+            match (opcode.operand_type(), &instruction.operand) {
+                (OperandType::InlineNone, Operand::None) => {}
+                (OperandType::ShortInlineVar, Operand::Variable(v)) => {
+                    let v = u8::try_from(*v).map_err(|_| {
+                        HaoError::BadImageFormat(
+                            "Variable index does not fit in a short instruction",
+                        )
+                    })?;
+                    code.write(v)?;
+                }
+                (OperandType::InlineVar, Operand::Variable(v)) => code.write(*v)?,
+                (OperandType::ShortInlineI, Operand::Int8(v)) => code.write(*v as u8)?,
+                (OperandType::ShortInlineI, Operand::UInt8(v)) => code.write(*v)?,
+                (OperandType::InlineI, Operand::Int32(v)) => code.write(*v as u32)?,
+                (OperandType::InlineI8, Operand::Int64(v)) => code.write(*v as u64)?,
+                (OperandType::ShortInlineR, Operand::Float32(v)) => code.write(v.to_bits())?,
+                (OperandType::InlineR, Operand::Float64(v)) => code.write(v.to_bits())?,
+                (OperandType::InlineString, Operand::String(v)) => {
+                    code.write(self.user_strings.add_token(v)?.raw())?
+                }
+                (OperandType::InlineSig, Operand::Signature(sig)) => {
+                    code.write(self.rids.stand_alone_sig_token(&sig.0)?.raw())?
+                }
+                (
+                    OperandType::InlineMethod
+                    | OperandType::InlineType
+                    | OperandType::InlineField
+                    | OperandType::InlineTok,
+                    Operand::Token(token),
+                ) => code.write(self.rids.token(token)?.raw())?,
+                (OperandType::ShortInlineBrTarget, Operand::BranchTarget(target)) => {
+                    code.write(branch_delta(*target)? as i8 as u8)?
+                }
+                (OperandType::InlineBrTarget, Operand::BranchTarget(target)) => {
+                    code.write(branch_delta(*target)? as i32 as u32)?
+                }
+                (OperandType::InlineSwitch, Operand::Switch(targets)) => {
+                    code.write(targets.len() as u32)?;
+                    for target in targets {
+                        code.write(branch_delta(*target)? as i32 as u32)?;
+                    }
+                }
+                _ => {
+                    return Err(HaoError::BadImageFormat(
+                        "Instruction operand does not match its opcode",
+                    ))
+                }
+            }
+        }
+
+        debug_assert_eq!(code.position(), code_size as usize);
+        Ok((code.into_inner(), offsets))
+    }
+
+    fn write_exception_handlers(
+        &mut self,
+        handlers: &[ExceptionHandler],
+        offsets: &[u32],
+    ) -> Result<()> {
+        let offset = |index: usize| -> Result<u32> {
+            offsets.get(index).copied().ok_or(HaoError::BadImageFormat(
+                "Exception handler range is outside of the method body",
+            ))
+        };
+
+        let mut clauses = Vec::with_capacity(handlers.len());
+        for handler in handlers {
+            let try_offset = offset(handler.try_range.start)?;
+            let try_length = offset(handler.try_range.end)? - try_offset;
+            let handler_offset = offset(handler.handler_range.start)?;
+            let handler_length = offset(handler.handler_range.end)? - handler_offset;
+            let class_token_or_filter = match handler.kind() {
+                ExceptionHandlerKind::Catch => handler
+                    .catch_type
+                    .as_ref()
+                    .map(|ty| self.rids.type_def_token(ty))
+                    .transpose()?
+                    .unwrap_or_default()
+                    .raw(),
+                ExceptionHandlerKind::Filter => offset(handler.filter_start.ok_or(
+                    HaoError::BadImageFormat("Filter exception handler has no filter"),
+                )?)?,
+                _ => 0,
+            };
+            clauses.push((
+                handler.flags,
+                try_offset,
+                try_length,
+                handler_offset,
+                handler_length,
+                class_token_or_filter,
+            ));
+        }
+
+        let small_size = 4 + clauses.len() * ExceptionHandler::SMALL_CLAUSE_SIZE;
+        let is_small = small_size <= u8::MAX as usize
+            && clauses.iter().all(
+                |(flags, try_offset, try_length, handler_offset, handler_length, _)| {
+                    flags.bits() <= u16::MAX as u32
+                        && *try_offset <= u16::MAX as u32
+                        && *try_length <= u8::MAX as u32
+                        && *handler_offset <= u16::MAX as u32
+                        && *handler_length <= u8::MAX as u32
+                },
+            );
+
+        self.writer.align(4);
+        if is_small {
+            self.writer.write(MethodDataSectionFlags::EHTable.bits())?;
+            self.writer.write(small_size as u8)?;
+            self.writer.write(0u16)?;
+        } else {
+            let fat_size = 4 + clauses.len() * ExceptionHandler::FAT_CLAUSE_SIZE;
+            if fat_size > 0x00FF_FFFF {
+                return Err(HaoError::BadImageFormat(
+                    "Method has too many exception handlers",
+                ));
+            }
+            self.writer.write(
+                (MethodDataSectionFlags::EHTable | MethodDataSectionFlags::FatFormat).bits(),
+            )?;
+            self.writer
+                .write_slice(&(fat_size as u32).to_le_bytes()[..3]);
+        }
+
+        for (flags, try_offset, try_length, handler_offset, handler_length, token) in clauses {
+            if is_small {
+                self.writer.write(flags.bits() as u16)?;
+                self.writer.write(try_offset as u16)?;
+                self.writer.write(try_length as u8)?;
+                self.writer.write(handler_offset as u16)?;
+                self.writer.write(handler_length as u8)?;
+            } else {
+                self.writer.write(flags.bits())?;
+                self.writer.write(try_offset)?;
+                self.writer.write(try_length)?;
+                self.writer.write(handler_offset)?;
+                self.writer.write(handler_length)?;
+            }
+            self.writer.write(token)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> WriteData<&MethodBody> for MethodBodyWriter<'a> {
+    fn write(&mut self, body: &MethodBody) -> Result<()> {
+        let instructions = body.instructions()?;
+        let exception_handlers = body.exception_handlers_with(&instructions)?;
+        let (code, offsets) = self.write_code(&instructions)?;
+        let init_locals = body.flags.contains(MethodBodyFlags::InitLocals);
+
+        let is_tiny = code.len() < Self::TINY_MAX_CODE_SIZE
+            && body.max_stack <= Self::TINY_MAX_STACK
+            && body.local_var_sig.is_none()
+            && exception_handlers.is_empty()
+            && !init_locals;
+
+        if is_tiny {
+            self.writer
+                .write(MethodBodyFlags::TinyFormat.bits() as u8 | (code.len() as u8) << 2)?;
+            self.writer.write_slice(&code);
+            return Ok(());
+        }
+
+        let mut flags = MethodBodyFlags::FatFormat;
+        flags.set(MethodBodyFlags::InitLocals, init_locals);
+        flags.set(MethodBodyFlags::MoreSects, !exception_handlers.is_empty());
+        let local_var_sig_token = body
+            .local_var_sig
+            .as_ref()
+            .map(|sig| self.rids.stand_alone_sig_token(&sig.0))
+            .transpose()?
+            .unwrap_or_default()
+            .raw();
+
+        self.writer
+            .write(flags.bits() | (Self::FAT_HEADER_SIZE << 12))?;
+        self.writer.write(body.max_stack)?;
+        self.writer.write(code.len() as u32)?;
+        self.writer.write(local_var_sig_token)?;
+        self.writer.write_slice(&code);
+
+        if !exception_handlers.is_empty() {
+            self.write_exception_handlers(&exception_handlers, &offsets)?;
+        }
+        Ok(())
+    }
+}
+
+// The long form of a short branch.
+fn long_branch(opcode: OpCode) -> OpCode {
+    match opcode {
+        OpCode::LeaveS => OpCode::Leave,
+        // `br.s` to `blt.un.s` are laid out in the same order as `br` to `blt.un`.
+        _ => OpCode::from_value(opcode.value() + (OpCode::Br.value() - OpCode::BrS.value()))
+            .unwrap_or(opcode),
+    }
+}
+
+fn instruction_size(instruction: &Instruction, opcode: OpCode) -> u32 {
+    let opcode_size = if opcode.is_two_byte() { 2 } else { 1 };
+    let operand_size = match &instruction.operand {
+        Operand::Switch(targets) => 4 + 4 * targets.len(),
+        _ => opcode.operand_type().size(),
+    };
+    (opcode_size + operand_size) as u32
+}
+
+// The offset of every instruction, followed by the size of the code.
+fn instruction_offsets(instructions: &[Instruction], opcodes: &[OpCode]) -> Vec<u32> {
+    let mut offsets = Vec::with_capacity(instructions.len() + 1);
+    let mut offset = 0;
+    for (instruction, opcode) in instructions.iter().zip(opcodes) {
+        offsets.push(offset);
+        offset += instruction_size(instruction, *opcode);
+    }
+    offsets.push(offset);
+    offsets
+}
+
+/// Picks the opcode of every instruction, turning short branches into long
+/// branches when their target is out of range.
+///
+/// Branches are only ever made longer, so this always finishes.
+fn relax_branches(instructions: &[Instruction]) -> Result<Vec<OpCode>> {
+    let mut opcodes = instructions.iter().map(|i| i.opcode).collect::<Vec<_>>();
+    loop {
+        let offsets = instruction_offsets(instructions, &opcodes);
+        let mut changed = false;
+        for (index, instruction) in instructions.iter().enumerate() {
+            let opcode = opcodes[index];
+            if opcode.operand_type() != OperandType::ShortInlineBrTarget {
+                continue;
+            }
+            let Operand::BranchTarget(target) = instruction.operand else {
+                continue;
+            };
+            let target = *offsets.get(target).ok_or(HaoError::BadImageFormat(
+                "Branch target is not an instruction",
+            ))?;
+            let delta = target as i64 - offsets[index + 1] as i64;
+            if i8::try_from(delta).is_err() {
+                opcodes[index] = long_branch(opcode);
+                changed = true;
+            }
+        }
+        if !changed {
+            return Ok(opcodes);
+        }
+    }
+}
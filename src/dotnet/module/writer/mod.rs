@@ -0,0 +1,895 @@
+mod body;
+mod pe;
+mod signature;
+mod tokens;
+
+use std::borrow::Cow;
+
+use self::{
+    body::MethodBodyWriter,
+    pe::ImageWriter,
+    signature::SignatureWriter,
+    tokens::{RidMap, Rids},
+};
+use crate::{
+    dotnet::{
+        entries::{values::*, Ptr},
+        md::{
+            metadata_header::{MetadataHeader, StreamHeader},
+            streams::{
+                tables_stream::{coded_tokens::*, metadata::*, *},
+                BlobHeapBuilder, GuidHeapBuilder, StringsHeapBuilder, UserStringsHeapBuilder,
+                Version,
+            },
+            MDStreamFlags,
+        },
+    },
+    error::{HaoError, Result},
+    io::{DataWriter, WriteData},
+    Module,
+};
+
+// Interface implementations and generic parameter constraints are written in the order
+// of their owners, so the row of one is after the rows of every owner before its own.
+fn owned_rid<T>(
+    owners: &RidMap<T>,
+    owner: &Ptr<T>,
+    index: usize,
+    count: impl Fn(&T) -> usize,
+) -> Result<u32> {
+    let rid = owners.rid(owner)?;
+    if index >= count(&owner.value()) {
+        return Err(HaoError::BadImageFormat(
+            "Custom attribute parent is no longer part of its owner",
+        ));
+    }
+    let before = owners.rows()[..rid as usize - 1]
+        .iter()
+        .map(|o| count(&o.value()))
+        .sum::<usize>();
+    Ok((before + index + 1) as u32)
+}
+
+/// Serializes `module` into a PE image.
+pub(crate) fn write_module(module: &Module) -> Result<Vec<u8>> {
+    let mut writer = ModuleWriter::new(module)?;
+    let mut image = ImageWriter::new(&module.image_info);
+
+    writer.write_modules();
+    writer.write_references()?;
+    writer.write_types()?;
+    writer.write_fields(&mut image)?;
+    writer.write_methods(&mut image)?;
+    writer.write_properties_and_events()?;
+    writer.write_generic_params()?;
+    writer.write_manifest(&mut image)?;
+    writer.write_custom_attributes(module)?;
+    writer.tables.sort()?;
+
+    let entry_point = module
+        .entry_point
+        .as_ref()
+        .map(|method| writer.rids.method_token(method))
+        .transpose()?
+        .unwrap_or_default()
+        .raw();
+    let metadata = writer.write_metadata()?;
+    image.finish(&metadata, entry_point)
+}
+
+#[derive(Default)]
+struct Heaps {
+    strings: StringsHeapBuilder,
+    user_strings: UserStringsHeapBuilder,
+    guids: GuidHeapBuilder,
+    blobs: BlobHeapBuilder,
+}
+
+impl Heaps {
+    fn string(&mut self, value: &str) -> StringsStreamOffset {
+        StringsStreamOffset(self.strings.add(value))
+    }
+
+    fn guid(&mut self, value: uuid::Uuid) -> GuidStreamOffset {
+        GuidStreamOffset(self.guids.add(value))
+    }
+
+    fn blob(&mut self, value: &[u8]) -> Result<BlobStreamOffset> {
+        Ok(BlobStreamOffset(self.blobs.add(value)?))
+    }
+
+    fn signature<T>(&mut self, rids: &Rids, signature: &T) -> Result<BlobStreamOffset>
+    where
+        for<'s, 'r> SignatureWriter<'s>: WriteData<&'r T>,
+    {
+        let mut writer = SignatureWriter::new(rids);
+        writer.write(signature)?;
+        self.blob(&writer.into_inner())
+    }
+
+    // Heaps that are too large for a 2 byte offset are flagged in the tables stream.
+    fn flags(&self) -> MDStreamFlags {
+        let mut flags = MDStreamFlags::empty();
+        flags.set(
+            MDStreamFlags::BigStrings,
+            self.strings.len() > u16::MAX as usize,
+        );
+        flags.set(
+            MDStreamFlags::BigGUID,
+            self.guids.len() / 16 > u16::MAX as usize,
+        );
+        flags.set(MDStreamFlags::BigBlob, self.blobs.len() > u16::MAX as usize);
+        flags
+    }
+}
+
+/// The rows of every table that is written.
+#[derive(Default)]
+struct Tables {
+    module: Vec<ModulesTableRow>,
+    type_ref: Vec<TypeRefTableRow>,
+    type_def: Vec<TypeDefTableRow>,
+    field: Vec<FieldTableRow>,
+    method: Vec<MethodTableRow>,
+    param: Vec<ParamTableRow>,
+    interface_impl: Vec<InterfaceImplTableRow>,
+    member_ref: Vec<MemberRefTableRow>,
+    constant: Vec<ConstantTableRow>,
+    custom_attribute: Vec<CustomAttributeTableRow>,
+    field_marshal: Vec<FieldMarshalTableRow>,
+    class_layout: Vec<ClassLayoutTableRow>,
+    field_layout: Vec<FieldLayoutTableRow>,
+    stand_alone_sig: Vec<StandAloneSigTableRow>,
+    event_map: Vec<EventMapTableRow>,
+    event: Vec<EventTableRow>,
+    property_map: Vec<PropertyMapTableRow>,
+    property: Vec<PropertyTableRow>,
+    method_semantics: Vec<MethodSemanticsTableRow>,
+    method_impl: Vec<MethodImplTableRow>,
+    module_ref: Vec<ModuleRefTableRow>,
+    type_spec: Vec<TypeSpecTableRow>,
+    impl_map: Vec<ImplMapTableRow>,
+    field_rva: Vec<FieldRVATableRow>,
+    assembly: Vec<AssemblyTableRow>,
+    assembly_ref: Vec<AssemblyRefTableRow>,
+    file: Vec<FileTableRow>,
+    exported_type: Vec<ExportedTypeTableRow>,
+    manifest_resource: Vec<ManifestResourceTableRow>,
+    nested_class: Vec<NestedClassTableRow>,
+    generic_param: Vec<GenericParamTableRow>,
+    method_spec: Vec<MethodSpecTableRow>,
+    generic_param_constraint: Vec<GenericParamConstraintTableRow>,
+}
+
+// Stable sorts rows by a coded token column, keeping the order of rows with the same key.
+fn sort_by_token<T, K: CodedTokenTarget>(
+    rows: &mut Vec<T>,
+    key: impl Fn(&T) -> CodedToken<K>,
+) -> Result<()> {
+    let mut keyed = rows
+        .drain(..)
+        .map(|row| Ok((key(&row).encode()?, row)))
+        .collect::<Result<Vec<_>>>()?;
+    keyed.sort_by_key(|(key, _)| *key);
+    rows.extend(keyed.into_iter().map(|(_, row)| row));
+    Ok(())
+}
+
+impl Tables {
+    /// Sorts the tables that are keyed on a coded token.
+    ///
+    /// The other sorted tables are keyed on a row of their owner
+    /// and are already written in order.
+    fn sort(&mut self) -> Result<()> {
+        sort_by_token(&mut self.constant, |row| row.parent)?;
+        sort_by_token(&mut self.custom_attribute, |row| row.parent)?;
+        sort_by_token(&mut self.field_marshal, |row| row.parent)?;
+        sort_by_token(&mut self.method_semantics, |row| row.association)?;
+        sort_by_token(&mut self.impl_map, |row| row.member_forwarded)?;
+        Ok(())
+    }
+
+    fn rows(&self) -> TableRows {
+        fn count<T>(rows: &[T]) -> TableRowCount {
+            TableRowCount(rows.len() as u32)
+        }
+
+        TableRows {
+            module: count(&self.module),
+            type_ref: count(&self.type_ref),
+            type_def: count(&self.type_def),
+            field: count(&self.field),
+            method: count(&self.method),
+            param: count(&self.param),
+            interface_impl: count(&self.interface_impl),
+            member_ref: count(&self.member_ref),
+            constant: count(&self.constant),
+            custom_attribute: count(&self.custom_attribute),
+            field_marshal: count(&self.field_marshal),
+            class_layout: count(&self.class_layout),
+            field_layout: count(&self.field_layout),
+            stand_alone_sig: count(&self.stand_alone_sig),
+            event_map: count(&self.event_map),
+            event: count(&self.event),
+            property_map: count(&self.property_map),
+            property: count(&self.property),
+            method_semantics: count(&self.method_semantics),
+            method_impl: count(&self.method_impl),
+            module_ref: count(&self.module_ref),
+            type_spec: count(&self.type_spec),
+            impl_map: count(&self.impl_map),
+            field_rva: count(&self.field_rva),
+            assembly: count(&self.assembly),
+            assembly_ref: count(&self.assembly_ref),
+            file: count(&self.file),
+            exported_type: count(&self.exported_type),
+            manifest_resource: count(&self.manifest_resource),
+            nested_class: count(&self.nested_class),
+            generic_param: count(&self.generic_param),
+            method_spec: count(&self.method_spec),
+            generic_param_constraint: count(&self.generic_param_constraint),
+            ..Default::default()
+        }
+    }
+
+    fn write(&self, flags: MDStreamFlags) -> Result<Vec<u8>> {
+        let rows = self.rows();
+        let header = TablesStreamsHeader::new(&rows, flags, Version::new(2, 0));
+
+        let mut writer = TablesStreamWriter::new(&header);
+        writer.write_header(&rows)?;
+        writer.write_rows(&self.module)?;
+        writer.write_rows(&self.type_ref)?;
+        writer.write_rows(&self.type_def)?;
+        writer.write_rows(&self.field)?;
+        writer.write_rows(&self.method)?;
+        writer.write_rows(&self.param)?;
+        writer.write_rows(&self.interface_impl)?;
+        writer.write_rows(&self.member_ref)?;
+        writer.write_rows(&self.constant)?;
+        writer.write_rows(&self.custom_attribute)?;
+        writer.write_rows(&self.field_marshal)?;
+        writer.write_rows(&self.class_layout)?;
+        writer.write_rows(&self.field_layout)?;
+        writer.write_rows(&self.stand_alone_sig)?;
+        writer.write_rows(&self.event_map)?;
+        writer.write_rows(&self.event)?;
+        writer.write_rows(&self.property_map)?;
+        writer.write_rows(&self.property)?;
+        writer.write_rows(&self.method_semantics)?;
+        writer.write_rows(&self.method_impl)?;
+        writer.write_rows(&self.module_ref)?;
+        writer.write_rows(&self.type_spec)?;
+        writer.write_rows(&self.impl_map)?;
+        writer.write_rows(&self.field_rva)?;
+        writer.write_rows(&self.assembly)?;
+        writer.write_rows(&self.assembly_ref)?;
+        writer.write_rows(&self.file)?;
+        writer.write_rows(&self.exported_type)?;
+        writer.write_rows(&self.manifest_resource)?;
+        writer.write_rows(&self.nested_class)?;
+        writer.write_rows(&self.generic_param)?;
+        writer.write_rows(&self.method_spec)?;
+        writer.write_rows(&self.generic_param_constraint)?;
+        writer.writer.align(4);
+        Ok(writer.into_inner())
+    }
+}
+
+struct ModuleWriter<'a> {
+    module: &'a Module,
+    rids: Rids,
+    heaps: Heaps,
+    tables: Tables,
+}
+
+impl<'a> ModuleWriter<'a> {
+    fn new(module: &'a Module) -> Result<Self> {
+        Ok(Self {
+            module,
+            rids: Rids::new(module)?,
+            heaps: Heaps::default(),
+            tables: Tables::default(),
+        })
+    }
+
+    fn write_modules(&mut self) {
+        for module in self.rids.modules.rows() {
+            let module = module.value();
+            self.tables.module.push(ModulesTableRow {
+                generation: module.generation,
+                name: self.heaps.string(&module.name),
+                mvid: self.heaps.guid(module.mvid),
+                enc_id: self.heaps.guid(module.enc_id),
+                enc_base_id: self.heaps.guid(module.enc_base_id),
+            });
+        }
+    }
+
+    // The tables that are only refrenced by other entries.
+    fn write_references(&mut self) -> Result<()> {
+        let (rids, heaps, tables) = (&self.rids, &mut self.heaps, &mut self.tables);
+
+        for type_ref in rids.type_refs.rows() {
+            let type_ref = type_ref.value();
+            tables.type_ref.push(TypeRefTableRow {
+                resolution_scope: rids.resolution_scope(&type_ref.resolution_scope)?,
+                name: heaps.string(&type_ref.name),
+                namespace: heaps.string(&type_ref.namespace),
+            });
+        }
+
+        for member_ref in rids.member_refs.rows() {
+            let member_ref = member_ref.value();
+            tables.member_ref.push(MemberRefTableRow {
+                class: rids.member_ref_parent(&member_ref.parent)?,
+                name: heaps.string(&member_ref.name),
+                signature: heaps.signature(rids, &member_ref.signature)?,
+            });
+        }
+
+        for sig in rids.stand_alone_sigs.rows() {
+            tables.stand_alone_sig.push(StandAloneSigTableRow {
+                signature: heaps.signature(rids, &sig.value().signature)?,
+            });
+        }
+
+        for module_ref in rids.module_refs.rows() {
+            tables.module_ref.push(ModuleRefTableRow {
+                name: heaps.string(&module_ref.value().name),
+            });
+        }
+
+        for type_spec in rids.type_specs.rows() {
+            let signature = heaps.signature(rids, &type_spec.value().signature)?;
+            tables.type_spec.push(TypeSpecTableRow {
+                signature: BlobStreamOffsetTypeSpec(signature.0),
+            });
+        }
+
+        for assembly in rids.assemblies.rows() {
+            let assembly = assembly.value();
+            tables.assembly.push(AssemblyTableRow {
+                hash_alg_id: assembly.hash_alg_id,
+                major_version: assembly.major_version,
+                minor_version: assembly.minor_version,
+                build_number: assembly.build_number,
+                revision_number: assembly.revision_number,
+                flags: assembly.flags,
+                public_key: heaps.blob(&assembly.public_key)?,
+                name: heaps.string(&assembly.name),
+                locale: heaps.string(&assembly.locale),
+            });
+        }
+
+        for assembly_ref in rids.assembly_refs.rows() {
+            let assembly_ref = assembly_ref.value();
+            tables.assembly_ref.push(AssemblyRefTableRow {
+                major_version: assembly_ref.major_version,
+                minor_version: assembly_ref.minor_version,
+                build_number: assembly_ref.build_number,
+                revision_number: assembly_ref.revision_number,
+                flags: assembly_ref.flags,
+                public_key_or_token: heaps.blob(&assembly_ref.public_key_or_token)?,
+                name: heaps.string(&assembly_ref.name),
+                locale: heaps.string(&assembly_ref.locale),
+                hash_value: heaps.blob(&assembly_ref.hash_value)?,
+            });
+        }
+
+        for method_spec in rids.method_specs.rows() {
+            let method_spec = method_spec.value();
+            tables.method_spec.push(MethodSpecTableRow {
+                method: rids.method_def_or_ref(&method_spec.method)?,
+                instantiation: heaps.signature(rids, &method_spec.instantiation)?,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn write_types(&mut self) -> Result<()> {
+        let (rids, heaps, tables) = (&self.rids, &mut self.heaps, &mut self.tables);
+
+        for (index, ty) in rids.type_defs.rows().iter().enumerate() {
+            let rid = index as u32 + 1;
+            let ty = ty.value();
+            tables.type_def.push(TypeDefTableRow {
+                flags: ty.flags,
+                name: heaps.string(&ty.name),
+                namespace: heaps.string(&ty.namespace),
+                extends: match &ty.extends {
+                    Some(extends) => rids.type_def_or_ref(extends)?,
+                    None => CodedToken::null(TypeDefOrRefToken::TypeDef),
+                },
+                field_list: FieldTableOffset(rids.field_lists[index]),
+                method_list: MethodTableOffset(rids.method_lists[index]),
+            });
+
+            for interface in ty.interface_impl.iter() {
+                tables.interface_impl.push(InterfaceImplTableRow {
+                    class: TypeDefTableOffset(rid),
+                    interface: rids.type_def_or_ref(interface)?,
+                });
+            }
+
+            if let Some(layout) = &ty.class_layout {
+                tables.class_layout.push(ClassLayoutTableRow {
+                    packing_size: layout.packing_size,
+                    class_size: layout.class_size,
+                    parent: TypeDefTableOffset(rid),
+                });
+            }
+
+            for method_impl in ty.method_impls.iter() {
+                tables.method_impl.push(MethodImplTableRow {
+                    class: TypeDefTableOffset(rid),
+                    method_body: rids.method_def_or_ref(&method_impl.body)?,
+                    method_declaration: rids.method_def_or_ref(&method_impl.declaration)?,
+                });
+            }
+
+            if let Some(declaring_type) = &ty.declaring_type {
+                tables.nested_class.push(NestedClassTableRow {
+                    nested_class: TypeDefTableOffset(rid),
+                    enclosing_class: TypeDefTableOffset(rids.type_defs.rid(declaring_type)?),
+                });
+            }
+
+            if let Some(property) = ty.property_list.first() {
+                tables.property_map.push(PropertyMapTableRow {
+                    parent: TypeDefTableOffset(rid),
+                    property_list: PropertyTableOffset(rids.properties.rid(property)?),
+                });
+            }
+
+            if let Some(event) = ty.event_list.first() {
+                tables.event_map.push(EventMapTableRow {
+                    parent: TypeDefTableOffset(rid),
+                    event_list: EventTableOffset(rids.events.rid(event)?),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_fields(&mut self, image: &mut ImageWriter) -> Result<()> {
+        let (rids, heaps, tables) = (&self.rids, &mut self.heaps, &mut self.tables);
+
+        for (index, field) in rids.fields.rows().iter().enumerate() {
+            let rid = index as u32 + 1;
+            let field = field.value();
+            tables.field.push(FieldTableRow {
+                flags: field.flags,
+                name: heaps.string(&field.name),
+                signature: heaps.signature(rids, &field.signature)?,
+            });
+
+            if let Some(constant) = &field.constant {
+                let (ty, value) = constant.to_blob();
+                tables.constant.push(ConstantTableRow {
+                    ty,
+                    padding: 0,
+                    parent: CodedToken::new(HasConstantToken::Field, rid),
+                    value: heaps.blob(&value)?,
+                });
+            }
+
+            if let Some(marshal) = &field.marshal {
+                tables.field_marshal.push(FieldMarshalTableRow {
+                    parent: CodedToken::new(HasFieldMarshalToken::Field, rid),
+                    native_type: heaps.blob(marshal)?,
+                });
+            }
+
+            if let Some(offset) = field.offset {
+                tables.field_layout.push(FieldLayoutTableRow {
+                    offset,
+                    field: FieldTableOffset(rid),
+                });
+            }
+
+            if let Some(initial_value) = &field.initial_value {
+                tables.field_rva.push(FieldRVATableRow {
+                    rva: image.add_field_data(initial_value),
+                    field: FieldTableOffset(rid),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_methods(&mut self, image: &mut ImageWriter) -> Result<()> {
+        let (rids, heaps, tables) = (&self.rids, &mut self.heaps, &mut self.tables);
+
+        for (index, method) in rids.methods.rows().iter().enumerate() {
+            let rid = index as u32 + 1;
+            let method = method.value();
+
+            let rva = match method.body()? {
+                Some(body) => {
+                    let mut writer = MethodBodyWriter::new(rids, &mut heaps.user_strings);
+                    writer.write(&body)?;
+                    image.add_method_body(&writer.into_inner())
+                }
+                None => 0,
+            };
+
+            tables.method.push(MethodTableRow {
+                rva,
+                impl_flags: method.impl_flags,
+                flags: method.flags,
+                name: heaps.string(&method.name),
+                signature: heaps.signature(rids, &method.signature)?,
+                param_list: ParamTableOffset(rids.param_lists[index]),
+            });
+
+            if let Some(pinvoke) = &method.pinvoke {
+                tables.impl_map.push(ImplMapTableRow {
+                    mapping_flags: pinvoke.flags,
+                    member_forwarded: CodedToken::new(MemberForwardedToken::Method, rid),
+                    import_name: heaps.string(&pinvoke.import_name),
+                    import_scope: ModuleRefTableOffset(
+                        rids.module_refs.rid(&pinvoke.import_scope)?,
+                    ),
+                });
+            }
+        }
+
+        for (index, param) in rids.params.rows().iter().enumerate() {
+            let rid = index as u32 + 1;
+            let param = param.value();
+            tables.param.push(ParamTableRow {
+                flags: param.flags,
+                sequence: param.sequence,
+                name: heaps.string(&param.name),
+            });
+
+            if let Some(constant) = &param.default_value {
+                let (ty, value) = constant.to_blob();
+                tables.constant.push(ConstantTableRow {
+                    ty,
+                    padding: 0,
+                    parent: CodedToken::new(HasConstantToken::Param, rid),
+                    value: heaps.blob(&value)?,
+                });
+            }
+
+            if let Some(marshal) = &param.marshal {
+                tables.field_marshal.push(FieldMarshalTableRow {
+                    parent: CodedToken::new(HasFieldMarshalToken::Param, rid),
+                    native_type: heaps.blob(marshal)?,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_properties_and_events(&mut self) -> Result<()> {
+        let (rids, heaps, tables) = (&self.rids, &mut self.heaps, &mut self.tables);
+
+        let mut semantics = |semantic: MethodSemanticsFlags,
+                             method: &Ptr<Method>,
+                             association: CodedToken<HasSemanticToken>|
+         -> Result<()> {
+            tables.method_semantics.push(MethodSemanticsTableRow {
+                semantic,
+                method: MethodTableOffset(rids.methods.rid(method)?),
+                association,
+            });
+            Ok(())
+        };
+
+        let mut property_rows = Vec::with_capacity(rids.properties.len() as usize);
+        let mut constants = Vec::new();
+        for (index, property) in rids.properties.rows().iter().enumerate() {
+            let rid = index as u32 + 1;
+            let property = property.value();
+            property_rows.push(PropertyTableRow {
+                prop_flags: property.flags,
+                name: heaps.string(&property.name),
+                ty: heaps.signature(rids, &property.signature)?,
+            });
+
+            let association = CodedToken::new(HasSemanticToken::Property, rid);
+            if let Some(setter) = &property.setter {
+                semantics(MethodSemanticsFlags::Setter, setter, association)?;
+            }
+            if let Some(getter) = &property.getter {
+                semantics(MethodSemanticsFlags::Getter, getter, association)?;
+            }
+            for other in property.other_methods.iter() {
+                semantics(MethodSemanticsFlags::Other, other, association)?;
+            }
+
+            if let Some(constant) = &property.constant {
+                let (ty, value) = constant.to_blob();
+                constants.push(ConstantTableRow {
+                    ty,
+                    padding: 0,
+                    parent: CodedToken::new(HasConstantToken::Property, rid),
+                    value: heaps.blob(&value)?,
+                });
+            }
+        }
+
+        let mut event_rows = Vec::with_capacity(rids.events.len() as usize);
+        for (index, event) in rids.events.rows().iter().enumerate() {
+            let rid = index as u32 + 1;
+            let event = event.value();
+            event_rows.push(EventTableRow {
+                event_flags: event.flags,
+                name: heaps.string(&event.name),
+                event_type: rids.type_def_or_ref(&event.event_type)?,
+            });
+
+            let association = CodedToken::new(HasSemanticToken::Event, rid);
+            if let Some(adder) = &event.adder {
+                semantics(MethodSemanticsFlags::AddOn, adder, association)?;
+            }
+            if let Some(remover) = &event.remover {
+                semantics(MethodSemanticsFlags::RemoveOn, remover, association)?;
+            }
+            if let Some(raiser) = &event.raiser {
+                semantics(MethodSemanticsFlags::Fire, raiser, association)?;
+            }
+            for other in event.other_methods.iter() {
+                semantics(MethodSemanticsFlags::Other, other, association)?;
+            }
+        }
+
+        tables.property = property_rows;
+        tables.event = event_rows;
+        tables.constant.extend(constants);
+        Ok(())
+    }
+
+    fn write_generic_params(&mut self) -> Result<()> {
+        let (rids, heaps, tables) = (&self.rids, &mut self.heaps, &mut self.tables);
+
+        for (index, param) in rids.generic_params.rows().iter().enumerate() {
+            let rid = index as u32 + 1;
+            let param = param.value();
+            tables.generic_param.push(GenericParamTableRow {
+                number: param.number,
+                flags: param.flags,
+                owner: rids.type_or_method_def(&param.owner)?,
+                name: heaps.string(&param.name),
+                kind: None,
+            });
+
+            for constraint in param.constraints.iter() {
+                tables
+                    .generic_param_constraint
+                    .push(GenericParamConstraintTableRow {
+                        owner: GenericParamTableOffset(rid),
+                        constraint: rids.type_def_or_ref(constraint)?,
+                    });
+            }
+        }
+
+        Ok(())
+    }
+
+    // The files, exported types and resources of the assembly.
+    // Embedded resources are added to the image and refrenced by their offset
+    // in the resources, the others keep the offset in the file that holds them.
+    fn write_manifest(&mut self, image: &mut ImageWriter) -> Result<()> {
+        let (rids, heaps, tables) = (&self.rids, &mut self.heaps, &mut self.tables);
+
+        for file in rids.files.rows() {
+            let file = file.value();
+            tables.file.push(FileTableRow {
+                flags: file.flags,
+                name: heaps.string(&file.name),
+                hash_value: heaps.blob(&file.hash_value)?,
+            });
+        }
+
+        for exported_type in rids.exported_types.rows() {
+            let exported_type = exported_type.value();
+            tables.exported_type.push(ExportedTypeTableRow {
+                flags: exported_type.flags,
+                type_def_id: TypeDefTableOffset(exported_type.type_def_id),
+                type_name: heaps.string(&exported_type.name),
+                type_namespace: heaps.string(&exported_type.namespace),
+                implementation: rids.implementation(&exported_type.implementation)?,
+            });
+        }
+
+        for resource in rids.manifest_resources.rows() {
+            let resource = resource.value();
+            let (offset, implementation) = match &resource.implementation {
+                Some(implementation) => (resource.offset, rids.implementation(implementation)?),
+                None => {
+                    let data = resource.data.as_deref().ok_or(HaoError::NotWritable(
+                        "An embedded resource whose data was not loaded",
+                    ))?;
+                    (
+                        image.add_resource(data)?,
+                        CodedToken::null(ImplementationToken::File),
+                    )
+                }
+            };
+            tables.manifest_resource.push(ManifestResourceTableRow {
+                offset,
+                flags: resource.flags,
+                name: heaps.string(&resource.name),
+                implementation,
+            });
+        }
+
+        Ok(())
+    }
+
+    // Attributes are written from the entries that own them,
+    // so attributes on entries that are not loaded are not written.
+    fn write_custom_attributes(&mut self, module: &Module) -> Result<()> {
+        fn add<T>(
+            writer: &mut ModuleWriter,
+            target: HasCustomAttributeToken,
+            owners: impl Fn(&Rids) -> &RidMap<T>,
+            attributes: impl Fn(&T) -> &[Ptr<CustomAttribute>],
+        ) -> Result<()> {
+            let (rids, heaps, tables) = (&writer.rids, &mut writer.heaps, &mut writer.tables);
+            for (index, owner) in owners(rids).rows().iter().enumerate() {
+                let parent = CodedToken::new(target, index as u32 + 1);
+                for attribute in attributes(&owner.value()) {
+                    let attribute = attribute.value();
+                    tables.custom_attribute.push(CustomAttributeTableRow {
+                        parent,
+                        ty: rids.custom_attribute_type(&attribute.constructor)?,
+                        value: heaps.blob(&attribute.value)?,
+                    });
+                }
+            }
+            Ok(())
+        }
+
+        use HasCustomAttributeToken as Target;
+        add(
+            self,
+            Target::Module,
+            |r| &r.modules,
+            |m| &m.custom_attributes,
+        )?;
+        add(
+            self,
+            Target::TypeDef,
+            |r| &r.type_defs,
+            |t| &t.custom_attributes,
+        )?;
+        add(self, Target::Field, |r| &r.fields, |f| &f.custom_attributes)?;
+        add(
+            self,
+            Target::Method,
+            |r| &r.methods,
+            |m| &m.custom_attributes,
+        )?;
+        add(self, Target::Param, |r| &r.params, |p| &p.custom_attributes)?;
+        add(
+            self,
+            Target::Property,
+            |r| &r.properties,
+            |p| &p.custom_attributes,
+        )?;
+        add(self, Target::Event, |r| &r.events, |e| &e.custom_attributes)?;
+        add(
+            self,
+            Target::GenericParam,
+            |r| &r.generic_params,
+            |g| &g.custom_attributes,
+        )?;
+        add(
+            self,
+            Target::Assembly,
+            |r| &r.assemblies,
+            |a| &a.custom_attributes,
+        )?;
+
+        // Attributes on entries that do not list their attributes are kept with their parent.
+        let (rids, heaps, tables) = (&self.rids, &mut self.heaps, &mut self.tables);
+        for attribute in module.custom_attributes.iter() {
+            let attribute = attribute.value();
+            let Some(parent) = &attribute.other_parent else {
+                continue;
+            };
+            let parent = match parent {
+                OtherAttributeParent::TypeRef(t) => {
+                    CodedToken::new(Target::TypeRef, rids.type_refs.rid(t)?)
+                }
+                OtherAttributeParent::MemberRef(m) => {
+                    CodedToken::new(Target::MemberRef, rids.member_refs.rid(m)?)
+                }
+                OtherAttributeParent::TypeSpec(t) => {
+                    CodedToken::new(Target::TypeSpec, rids.type_specs.rid(t)?)
+                }
+                OtherAttributeParent::ModuleRef(m) => {
+                    CodedToken::new(Target::ModuleRef, rids.module_refs.rid(m)?)
+                }
+                OtherAttributeParent::AssemblyRef(a) => {
+                    CodedToken::new(Target::AssemblyRef, rids.assembly_refs.rid(a)?)
+                }
+                OtherAttributeParent::StandAloneSig(s) => {
+                    CodedToken::new(Target::StandAloneSig, rids.stand_alone_sigs.rid(s)?)
+                }
+                OtherAttributeParent::MethodSpec(m) => {
+                    CodedToken::new(Target::MethodSpec, rids.method_specs.rid(m)?)
+                }
+                OtherAttributeParent::InterfaceImpl(class, index) => CodedToken::new(
+                    Target::InterfaceImpl,
+                    owned_rid(&rids.type_defs, class, *index, |t| t.interface_impl.len())?,
+                ),
+                OtherAttributeParent::GenericParamConstraint(owner, index) => CodedToken::new(
+                    Target::GenericParamConstraint,
+                    owned_rid(&rids.generic_params, owner, *index, |g| g.constraints.len())?,
+                ),
+                OtherAttributeParent::File(f) => CodedToken::new(Target::File, rids.files.rid(f)?),
+                OtherAttributeParent::ExportedType(e) => {
+                    CodedToken::new(Target::ExportedType, rids.exported_types.rid(e)?)
+                }
+                OtherAttributeParent::ManifestResource(r) => {
+                    CodedToken::new(Target::ManifestResource, rids.manifest_resources.rid(r)?)
+                }
+                OtherAttributeParent::DeclSecurity => {
+                    return Err(HaoError::NotWritable(
+                        "A custom attribute on declarative security",
+                    ))
+                }
+            };
+            tables.custom_attribute.push(CustomAttributeTableRow {
+                parent,
+                ty: rids.custom_attribute_type(&attribute.constructor)?,
+                value: heaps.blob(&attribute.value)?,
+            });
+        }
+        Ok(())
+    }
+
+    fn write_metadata(self) -> Result<Vec<u8>> {
+        let flags = self.heaps.flags();
+        let tables = self.tables.write(flags)?;
+        let streams: [(&str, Vec<u8>); 5] = [
+            ("#~", tables),
+            ("#Strings", self.heaps.strings.into_heap()),
+            ("#US", self.heaps.user_strings.into_heap()),
+            ("#GUID", self.heaps.guids.into_heap()),
+            ("#Blob", self.heaps.blobs.into_heap()),
+        ];
+
+        let mut header = MetadataHeader {
+            signature: MetadataHeader::SIGNATURE,
+            major_version: 1,
+            minor_version: 1,
+            version: Cow::Borrowed(&self.module.image_info.runtime_version),
+            flags: 0,
+            streams: streams
+                .iter()
+                .map(|(name, data)| StreamHeader {
+                    offset: 0,
+                    stream_size: data.len() as u32,
+                    name: Cow::Borrowed(name),
+                })
+                .collect(),
+        };
+
+        // The size of the header does not depend on the stream offsets.
+        let mut writer = DataWriter::new();
+        writer.write(&header)?;
+        let mut offset = writer.position() as u32;
+        for stream in header.streams.iter_mut() {
+            stream.offset = offset;
+            offset += stream.stream_size;
+        }
+
+        let mut writer = DataWriter::new();
+        writer.write(&header)?;
+        for (_, data) in streams.iter() {
+            writer.write_slice(data);
+        }
+        Ok(writer.into_inner())
+    }
+}
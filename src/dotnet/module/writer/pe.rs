@@ -0,0 +1,406 @@
+use pewter::{
+    io::Writer,
+    pe::{
+        coff::{CoffFileHeader, ImageFileCharacteristics, ImageFileMachine},
+        dos::ImageDosHeader,
+        optional_header::{
+            data_directories::{DataDirectories, ImageDataDirectory},
+            ImageDllCharacteristics, ImageSubsystem, OptionalHeader, OptionalHeaderMagic,
+            OptionalHeaderStandardFields, OptionalHeaderWindowsSpecific,
+        },
+        sections::{SectionFlags, SectionTableRow},
+    },
+};
+
+use crate::{
+    dotnet::{md::image_headers::ComImageFlags, module::image_info::ImageInfo},
+    error::{HaoError, Result},
+    io::{DataWriter, WriteData},
+};
+
+const FILE_ALIGNMENT: u32 = 0x200;
+const SECTION_ALIGNMENT: u32 = 0x2000;
+const TEXT_RVA: u32 = SECTION_ALIGNMENT;
+
+const PE_SIGNATURE: u32 = 0x0000_4550;
+const PE_HEADER_OFFSET: u32 = 0x80;
+const NUMBER_OF_DATA_DIRECTORIES: u32 = 16;
+const SECTION_HEADER_SIZE: u32 = 40;
+
+const COR20_HEADER_SIZE: u32 = 0x48;
+const IAT_SIZE: u32 = 8;
+const IMPORT_DESCRIPTOR_SIZE: u32 = 20;
+const IMAGE_REL_BASED_HIGHLOW: u16 = 3;
+
+fn align(value: u32, alignment: u32) -> u32 {
+    (value + alignment - 1) & !(alignment - 1)
+}
+
+/// Lays out the `.text` section of a managed image and writes the PE file around it.
+///
+/// The section starts with the import address table (PE32 only) and the cor20 header,
+/// followed by method bodies, field data, embedded resources and the metadata.
+/// PE32 images end the section with the `mscoree.dll` import and entry point stub
+/// that is required by older versions of windows.
+pub(crate) struct ImageWriter<'a> {
+    info: &'a ImageInfo,
+    text: DataWriter,
+    resources: DataWriter,
+}
+
+impl<'a> ImageWriter<'a> {
+    pub fn new(info: &'a ImageInfo) -> Self {
+        let mut text = DataWriter::new();
+        if !info.pe32_plus {
+            text.write_zeros(IAT_SIZE as usize);
+        }
+        text.write_zeros(COR20_HEADER_SIZE as usize);
+        Self {
+            info,
+            text,
+            resources: DataWriter::new(),
+        }
+    }
+
+    fn cor20_header_offset(&self) -> usize {
+        if self.info.pe32_plus {
+            0
+        } else {
+            IAT_SIZE as usize
+        }
+    }
+
+    fn rva(&self) -> u32 {
+        TEXT_RVA + self.text.position() as u32
+    }
+
+    /// Adds an encoded method body and returns its RVA.
+    pub fn add_method_body(&mut self, body: &[u8]) -> u32 {
+        self.text.align(4);
+        let rva = self.rva();
+        self.text.write_slice(body);
+        rva
+    }
+
+    /// Adds the initial value of a field and returns its RVA.
+    pub fn add_field_data(&mut self, data: &[u8]) -> u32 {
+        self.text.align(8);
+        let rva = self.rva();
+        self.text.write_slice(data);
+        rva
+    }
+
+    /// Adds the data of an embedded resource and returns its offset in the resources.
+    pub fn add_resource(&mut self, data: &[u8]) -> Result<u32> {
+        self.resources.align(8);
+        let offset = self.resources.position() as u32;
+        self.resources.write(data.len() as u32)?;
+        self.resources.write_slice(data);
+        Ok(offset)
+    }
+
+    /// Adds the metadata and writes the complete PE file.
+    pub fn finish(mut self, metadata: &[u8], entry_point_token: u32) -> Result<Vec<u8>> {
+        let mut resources = ImageDataDirectory::default();
+        if self.resources.position() != 0 {
+            self.text.align(8);
+            resources.virtual_address = self.rva();
+            resources.size = self.resources.position() as u32;
+            self.text.write_slice(self.resources.as_slice());
+        }
+
+        self.text.align(4);
+        let metadata_rva = self.rva();
+        self.text.write_slice(metadata);
+
+        let imports = (!self.info.pe32_plus)
+            .then(|| self.write_imports())
+            .transpose()?;
+        self.write_cor20_header(
+            metadata_rva,
+            metadata.len() as u32,
+            entry_point_token,
+            resources,
+        )?;
+
+        let text = self.text.as_slice();
+        let text_size = text.len() as u32;
+        let headers_size = align(
+            PE_HEADER_OFFSET
+                + 4
+                + CoffFileHeader::SIZE as u32
+                + self.optional_header_size()
+                + SECTION_HEADER_SIZE * if imports.is_some() { 2 } else { 1 },
+            FILE_ALIGNMENT,
+        );
+
+        let mut sections = vec![(
+            SectionTableRow {
+                name: *b".text\0\0\0",
+                virtual_size: text_size,
+                virtual_address: TEXT_RVA,
+                size_of_raw_data: align(text_size, FILE_ALIGNMENT),
+                pointer_to_raw_data: headers_size,
+                characteristics: SectionFlags::CNT_CODE
+                    | SectionFlags::MEM_EXECUTE
+                    | SectionFlags::MEM_READ,
+                ..Default::default()
+            },
+            text.to_vec(),
+        )];
+
+        if let Some(imports) = &imports {
+            let text_section = &sections[0].0;
+            let reloc = Self::relocations(imports.entry_point + 2);
+            sections.push((
+                SectionTableRow {
+                    name: *b".reloc\0\0",
+                    virtual_size: reloc.len() as u32,
+                    virtual_address: align(TEXT_RVA + text_size, SECTION_ALIGNMENT),
+                    size_of_raw_data: align(reloc.len() as u32, FILE_ALIGNMENT),
+                    pointer_to_raw_data: text_section.pointer_to_raw_data
+                        + text_section.size_of_raw_data,
+                    characteristics: SectionFlags::CNT_INITIALIZED_DATA
+                        | SectionFlags::MEM_DISCARDABLE
+                        | SectionFlags::MEM_READ,
+                    ..Default::default()
+                },
+                reloc,
+            ));
+        }
+
+        let optional_header = self.optional_header(headers_size, &sections, imports.as_ref());
+        let coff_header = CoffFileHeader {
+            machine: ImageFileMachine::from_u16(self.info.machine),
+            number_of_sections: sections.len() as u16,
+            date_time_stamp: 0,
+            pointer_to_symbol_table: 0,
+            number_of_symbols: 0,
+            size_of_optional_header: optional_header.size() as u16,
+            characteristics: ImageFileCharacteristics::from_bits_retain(self.info.characteristics),
+        };
+        let dos_header = ImageDosHeader {
+            e_magic: ImageDosHeader::MAGIC_CONSTANT,
+            e_cparhdr: (ImageDosHeader::SIZE / 16) as u16,
+            e_lfanew: PE_HEADER_OFFSET,
+            ..Default::default()
+        };
+
+        let mut headers = Vec::with_capacity(headers_size as usize);
+        headers
+            .write(&dos_header)
+            .map_err(|_| HaoError::BadPeFormat)?;
+        headers.resize(PE_HEADER_OFFSET as usize, 0);
+        headers.extend_from_slice(&PE_SIGNATURE.to_le_bytes());
+        headers
+            .write(&coff_header)
+            .and_then(|_| headers.write(&optional_header))
+            .map_err(|_| HaoError::BadPeFormat)?;
+        for (section, _) in sections.iter() {
+            headers.write(section).map_err(|_| HaoError::BadPeFormat)?;
+        }
+
+        let mut image = DataWriter::new();
+        image.write_slice(&headers);
+        for (section, data) in sections.iter() {
+            image.write_zeros(section.pointer_to_raw_data as usize - image.position());
+            image.write_slice(data);
+        }
+        image.align(FILE_ALIGNMENT as usize);
+        Ok(image.into_inner())
+    }
+
+    fn optional_header_size(&self) -> u32 {
+        let size = if self.info.pe32_plus {
+            OptionalHeader::size_pe32_plus()
+        } else {
+            OptionalHeader::size_pe32()
+        };
+        size as u32 + NUMBER_OF_DATA_DIRECTORIES * ImageDataDirectory::SIZE as u32
+    }
+
+    fn optional_header(
+        &self,
+        headers_size: u32,
+        sections: &[(SectionTableRow, Vec<u8>)],
+        imports: Option<&Imports>,
+    ) -> OptionalHeader {
+        let (text, _) = &sections[0];
+        let reloc = sections.get(1).map(|(section, _)| section);
+        let size_of_image = sections
+            .iter()
+            .map(|(section, _)| {
+                align(
+                    section.virtual_address + section.virtual_size,
+                    SECTION_ALIGNMENT,
+                )
+            })
+            .max()
+            .unwrap_or(TEXT_RVA);
+
+        let mut windows_specific_fields = if self.info.pe32_plus {
+            OptionalHeaderWindowsSpecific::PE32Plus(Default::default())
+        } else {
+            OptionalHeaderWindowsSpecific::PE32(Default::default())
+        };
+        windows_specific_fields.set_image_base(self.info.image_base);
+        windows_specific_fields.set_section_alignment(SECTION_ALIGNMENT);
+        windows_specific_fields.set_file_alignment(FILE_ALIGNMENT);
+        windows_specific_fields.set_major_operating_system_version(4);
+        windows_specific_fields.set_major_subsystem_version(4);
+        windows_specific_fields.set_size_of_image(size_of_image);
+        windows_specific_fields.set_size_of_headers(headers_size);
+        windows_specific_fields.set_subsystem(ImageSubsystem::from_u16(self.info.subsystem));
+        windows_specific_fields.set_dll_characteristics(ImageDllCharacteristics::from_bits_retain(
+            self.info.dll_characteristics,
+        ));
+        windows_specific_fields.set_size_of_stack_reserve(0x10_0000);
+        windows_specific_fields.set_size_of_stack_commit(0x1000);
+        windows_specific_fields.set_size_of_heap_reserve(0x10_0000);
+        windows_specific_fields.set_size_of_heap_commit(0x1000);
+        windows_specific_fields.set_number_of_rva_and_sizes(NUMBER_OF_DATA_DIRECTORIES);
+
+        let directory = |virtual_address: u32, size: u32| ImageDataDirectory {
+            virtual_address,
+            size,
+        };
+        let mut data_directories = DataDirectories {
+            clr_runtime_header: directory(
+                TEXT_RVA + self.cor20_header_offset() as u32,
+                COR20_HEADER_SIZE,
+            ),
+            ..Default::default()
+        };
+        if let (Some(imports), Some(reloc)) = (imports, reloc) {
+            data_directories.import_table = directory(imports.import_directory, imports.size);
+            data_directories.ita = directory(TEXT_RVA, IAT_SIZE);
+            data_directories.base_relocation_table =
+                directory(reloc.virtual_address, reloc.virtual_size);
+        }
+
+        OptionalHeader {
+            standard_fields: OptionalHeaderStandardFields {
+                magic: if self.info.pe32_plus {
+                    OptionalHeaderMagic::PE32Plus
+                } else {
+                    OptionalHeaderMagic::PE32
+                },
+                major_linker_version: 8,
+                minor_linker_version: 0,
+                size_of_code: text.size_of_raw_data,
+                size_of_initilized_data: reloc.map(|r| r.size_of_raw_data).unwrap_or(0),
+                size_of_unitilized_data: 0,
+                address_of_entry_point: imports.map(|i| i.entry_point).unwrap_or(0),
+                base_of_code: TEXT_RVA,
+                base_of_data: (!self.info.pe32_plus)
+                    .then(|| reloc.map(|r| r.virtual_address).unwrap_or(0)),
+            },
+            windows_specific_fields,
+            data_directories,
+        }
+    }
+
+    fn write_cor20_header(
+        &mut self,
+        metadata_rva: u32,
+        metadata_size: u32,
+        entry_point_token: u32,
+        resources: ImageDataDirectory,
+    ) -> Result<()> {
+        // The strong name signature and native entry point are not written,
+        // so the flags that refer to them are cleared.
+        let flags =
+            self.info.flags - ComImageFlags::StrongNameSigned - ComImageFlags::NativeEntryPoint;
+
+        let mut header = DataWriter::new();
+        header.write(COR20_HEADER_SIZE)?;
+        header.write(self.info.major_runtime_version)?;
+        header.write(self.info.minor_runtime_version)?;
+        header.write(metadata_rva)?;
+        header.write(metadata_size)?;
+        header.write(flags.bits())?;
+        header.write(entry_point_token)?;
+        header.write(resources.virtual_address)?;
+        header.write(resources.size)?;
+        // StrongNameSignature, CodeManagerTable, VTableFixups,
+        // ExportAddressTableJumps and ManagedNativeHeader.
+        header.write_zeros(5 * 8);
+
+        let offset = self.cor20_header_offset();
+        for (index, chunk) in header.as_slice().chunks_exact(4).enumerate() {
+            self.text.patch_u32(
+                offset + index * 4,
+                u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]),
+            )?;
+        }
+        Ok(())
+    }
+
+    // Imports `_CorDllMain` or `_CorExeMain` from `mscoree.dll` and
+    // adds the `jmp [iat]` stub that is used as the native entry point.
+    fn write_imports(&mut self) -> Result<Imports> {
+        const DLL_NAME: &[u8] = b"mscoree.dll\0";
+        let entry_name: &[u8] = if self.info.is_dll() {
+            b"_CorDllMain\0"
+        } else {
+            b"_CorExeMain\0"
+        };
+
+        self.text.align(4);
+        let import_directory = self.rva();
+        let lookup_table = import_directory + IMPORT_DESCRIPTOR_SIZE * 2;
+        let hint_name = lookup_table + IAT_SIZE;
+        let dll_name = hint_name + 2 + entry_name.len() as u32;
+
+        // The import descriptor followed by the null descriptor.
+        self.text.write(lookup_table)?;
+        self.text.write(0u32)?;
+        self.text.write(0u32)?;
+        self.text.write(dll_name)?;
+        self.text.write(TEXT_RVA)?;
+        self.text.write_zeros(IMPORT_DESCRIPTOR_SIZE as usize);
+
+        self.text.write(hint_name)?;
+        self.text.write(0u32)?;
+
+        self.text.write(0u16)?;
+        self.text.write_slice(entry_name);
+        self.text.write_slice(DLL_NAME);
+        let size = self.rva() - import_directory;
+
+        self.text.patch_u32(0, hint_name)?;
+
+        // Keep the address of the jump 4 byte aligned.
+        self.text.align(4);
+        self.text.write_zeros(2);
+        let entry_point = self.rva();
+        self.text.write_slice(&[0xFF, 0x25]);
+        self.text
+            .write((self.info.image_base as u32).wrapping_add(TEXT_RVA))?;
+
+        Ok(Imports {
+            import_directory,
+            size,
+            entry_point,
+        })
+    }
+
+    // A single base relocation block for the address in the entry point stub.
+    fn relocations(rva: u32) -> Vec<u8> {
+        let page = rva & !0xFFF;
+        let mut block = Vec::with_capacity(12);
+        block.extend_from_slice(&page.to_le_bytes());
+        block.extend_from_slice(&12u32.to_le_bytes());
+        block.extend_from_slice(
+            &((IMAGE_REL_BASED_HIGHLOW << 12) | (rva & 0xFFF) as u16).to_le_bytes(),
+        );
+        block.extend_from_slice(&0u16.to_le_bytes());
+        block
+    }
+}
+
+struct Imports {
+    import_directory: u32,
+    size: u32,
+    entry_point: u32,
+}
@@ -0,0 +1,327 @@
+use super::tokens::Rids;
+use crate::{
+    dotnet::{
+        entries::signature::{
+            FieldSignature, LocalVarSignature, MemberRefSignature, MethodSignature,
+            MethodSpecSignature, PropertySignature, StandAloneSignature, TypeDefOrRef,
+            TypeSignature, ValueType,
+        },
+        md::streams::{
+            tables_stream::coded_tokens::{CodedToken, TypeDefOrRefToken},
+            ArraySize, CallingConvention, ElementType, SignatureFlags,
+        },
+    },
+    error::Result,
+    io::{DataWriter, WriteData},
+};
+
+/// Encodes signatures into the format used by the `#Blob` heap. See ECMA-335 II.23.2.
+pub(crate) struct SignatureWriter<'a> {
+    writer: DataWriter,
+    rids: &'a Rids,
+}
+
+impl<'a> SignatureWriter<'a> {
+    pub fn new(rids: &'a Rids) -> Self {
+        Self {
+            writer: DataWriter::new(),
+            rids,
+        }
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.writer.into_inner()
+    }
+
+    fn write_element_type(&mut self, element_type: ElementType) -> Result<()> {
+        self.writer.write(element_type as u8)
+    }
+
+    fn write_compressed(&mut self, value: u32) -> Result<()> {
+        self.writer.write_compressed_u32(value)
+    }
+
+    fn write_count(&mut self, count: usize) -> Result<()> {
+        self.write_compressed(count as u32)
+    }
+
+    // TypeDefOrRefOrSpecEncoded
+    fn write_token(&mut self, token: CodedToken<TypeDefOrRefToken>) -> Result<()> {
+        let value = token.encode()?;
+        self.write_compressed(value)
+    }
+
+    fn write_type_def_or_ref(&mut self, ty: &TypeDefOrRef) -> Result<()> {
+        let token = self.rids.type_def_or_ref(ty)?;
+        self.write_token(token)
+    }
+
+    fn write_all<T>(&mut self, values: &[T]) -> Result<()>
+    where
+        for<'v> Self: WriteData<&'v T>,
+    {
+        for value in values {
+            self.write(value)?;
+        }
+        Ok(())
+    }
+
+    fn write_method_header(
+        &mut self,
+        calling_convention: u8,
+        has_this: bool,
+        explicit_this: bool,
+        generic_params: Option<u32>,
+    ) -> Result<()> {
+        let mut flags = SignatureFlags::empty();
+        flags.set(SignatureFlags::HasThis, has_this);
+        flags.set(SignatureFlags::ExplicitThis, explicit_this);
+        flags.set(SignatureFlags::Generic, generic_params.is_some());
+        self.writer.write(calling_convention | flags.bits())?;
+        if let Some(generic_params) = generic_params {
+            self.write_compressed(generic_params)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> WriteData<&ArraySize> for SignatureWriter<'a> {
+    fn write(&mut self, size: &ArraySize) -> Result<()> {
+        match size {
+            ArraySize::SingleDimention => self.write_compressed(0),
+            ArraySize::MultiDimention {
+                rank,
+                sizes,
+                lower_bounds,
+            } => {
+                self.write_compressed(*rank)?;
+                self.write_count(sizes.len())?;
+                for size in sizes {
+                    self.write_compressed(*size)?;
+                }
+                self.write_count(lower_bounds.len())?;
+                for bound in lower_bounds {
+                    self.write_compressed(*bound)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'a> WriteData<&ValueType> for SignatureWriter<'a> {
+    fn write(&mut self, ty: &ValueType) -> Result<()> {
+        match ty {
+            ValueType::Void => self.write_element_type(ElementType::Void),
+            ValueType::Boolean => self.write_element_type(ElementType::Boolean),
+            ValueType::Char => self.write_element_type(ElementType::Char),
+            ValueType::SByte => self.write_element_type(ElementType::I1),
+            ValueType::Byte => self.write_element_type(ElementType::U1),
+            ValueType::Int16 => self.write_element_type(ElementType::I2),
+            ValueType::UInt16 => self.write_element_type(ElementType::U2),
+            ValueType::Int32 => self.write_element_type(ElementType::I4),
+            ValueType::UInt32 => self.write_element_type(ElementType::U4),
+            ValueType::Int64 => self.write_element_type(ElementType::I8),
+            ValueType::UInt64 => self.write_element_type(ElementType::U8),
+            ValueType::Single => self.write_element_type(ElementType::R4),
+            ValueType::Double => self.write_element_type(ElementType::R8),
+            ValueType::String => self.write_element_type(ElementType::String),
+            ValueType::TypedRefrence => self.write_element_type(ElementType::TypedByRef),
+            ValueType::IntPtr => self.write_element_type(ElementType::I),
+            ValueType::UIntPtr => self.write_element_type(ElementType::U),
+            ValueType::Object => self.write_element_type(ElementType::Object),
+            ValueType::Ptr(ty) => {
+                self.write_element_type(ElementType::Ptr)?;
+                self.write(ty.as_ref())
+            }
+            ValueType::ByRef(ty) => {
+                self.write_element_type(ElementType::ByRef)?;
+                self.write(ty.as_ref())
+            }
+            ValueType::ValueType(ty) => {
+                self.write_element_type(ElementType::ValueType)?;
+                self.write_type_def_or_ref(ty)
+            }
+            ValueType::Class(ty) => {
+                self.write_element_type(ElementType::Class)?;
+                self.write_type_def_or_ref(ty)
+            }
+            ValueType::SZArray(ty) => {
+                self.write_element_type(ElementType::SZArray)?;
+                self.write(ty.as_ref())
+            }
+            ValueType::FnPtr(method) => {
+                self.write_element_type(ElementType::FnPtr)?;
+                self.write(method.as_ref())
+            }
+            ValueType::CModReq(modifier, ty) => {
+                self.write_element_type(ElementType::CModReqd)?;
+                self.write_type_def_or_ref(modifier)?;
+                self.write(ty.as_ref())
+            }
+            ValueType::CModOpt(modifier, ty) => {
+                self.write_element_type(ElementType::CModOpt)?;
+                self.write_type_def_or_ref(modifier)?;
+                self.write(ty.as_ref())
+            }
+            ValueType::Sentinel => self.write_element_type(ElementType::Sentinel),
+            ValueType::Internal => self.write_element_type(ElementType::Internal),
+            ValueType::Pinned(ty) => {
+                self.write_element_type(ElementType::Pinned)?;
+                self.write(ty.as_ref())
+            }
+            ValueType::Var {
+                generic_param_index,
+                ..
+            } => {
+                self.write_element_type(ElementType::Var)?;
+                self.write_compressed(*generic_param_index)
+            }
+            ValueType::MVar {
+                generic_param_index,
+                ..
+            } => {
+                self.write_element_type(ElementType::MVar)?;
+                self.write_compressed(*generic_param_index)
+            }
+            ValueType::ValueArray { len, ty } => {
+                self.write_element_type(ElementType::ValueArray)?;
+                self.write_compressed(*len)?;
+                self.write(ty.as_ref())
+            }
+            ValueType::Module(index, ty) => {
+                self.write_element_type(ElementType::Module)?;
+                self.write_compressed(*index)?;
+                self.write(ty.as_ref())
+            }
+            ValueType::GenericInst { ty, generic_args } => {
+                self.write_element_type(ElementType::GenericInst)?;
+                self.write(ty.as_ref())?;
+                self.write_count(generic_args.len())?;
+                self.write_all(generic_args)
+            }
+            ValueType::Array { ty, size } => {
+                self.write_element_type(ElementType::Array)?;
+                self.write(ty.as_ref())?;
+                self.write(size)
+            }
+        }
+    }
+}
+
+impl<'a> WriteData<&TypeSignature> for SignatureWriter<'a> {
+    fn write(&mut self, sig: &TypeSignature) -> Result<()> {
+        match sig {
+            TypeSignature::GenericInst { ty, generic_args } => {
+                self.write_element_type(ElementType::GenericInst)?;
+                self.write(ty)?;
+                self.write_count(generic_args.len())?;
+                self.write_all(generic_args)
+            }
+            TypeSignature::ClassVariable {
+                generic_param_index,
+                ..
+            } => {
+                self.write_element_type(ElementType::Var)?;
+                self.write_compressed(*generic_param_index)
+            }
+            TypeSignature::MethodVariable {
+                generic_param_index,
+                ..
+            } => {
+                self.write_element_type(ElementType::MVar)?;
+                self.write_compressed(*generic_param_index)
+            }
+            TypeSignature::SZArray(ty) => {
+                self.write_element_type(ElementType::SZArray)?;
+                self.write(ty)
+            }
+            TypeSignature::FnPtr(method) => {
+                self.write_element_type(ElementType::FnPtr)?;
+                self.write(method)
+            }
+            TypeSignature::Class(ty) => {
+                self.write_element_type(ElementType::Class)?;
+                self.write_type_def_or_ref(ty)
+            }
+            TypeSignature::ValueType(ty) => {
+                self.write_element_type(ElementType::ValueType)?;
+                self.write_type_def_or_ref(ty)
+            }
+            TypeSignature::Other(sig) => self.write(sig),
+        }
+    }
+}
+
+impl<'a> WriteData<&MethodSignature> for SignatureWriter<'a> {
+    fn write(&mut self, sig: &MethodSignature) -> Result<()> {
+        self.write_method_header(
+            sig.calling_convention as u8,
+            sig.has_this,
+            sig.explicit_this,
+            sig.generic_params,
+        )?;
+
+        // Parameters after the sentinel are counted, the sentinel itself is not.
+        let after_sentinel = sig.params_after_sentinel.as_deref().unwrap_or(&[]);
+        self.write_count(sig.parameters.len() + after_sentinel.len())?;
+        self.write(sig.return_type.as_ref())?;
+        self.write_all(&sig.parameters)?;
+        if sig.params_after_sentinel.is_some() {
+            self.write_element_type(ElementType::Sentinel)?;
+            self.write_all(after_sentinel)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> WriteData<&FieldSignature> for SignatureWriter<'a> {
+    fn write(&mut self, sig: &FieldSignature) -> Result<()> {
+        self.writer.write(CallingConvention::Field as u8)?;
+        self.write(&sig.0)
+    }
+}
+
+impl<'a> WriteData<&PropertySignature> for SignatureWriter<'a> {
+    fn write(&mut self, sig: &PropertySignature) -> Result<()> {
+        self.write_method_header(CallingConvention::Property as u8, sig.has_this, false, None)?;
+        self.write_count(sig.parameters.len())?;
+        self.write(&sig.property_type)?;
+        self.write_all(&sig.parameters)
+    }
+}
+
+impl<'a> WriteData<&LocalVarSignature> for SignatureWriter<'a> {
+    fn write(&mut self, sig: &LocalVarSignature) -> Result<()> {
+        self.writer.write(CallingConvention::LocalSig as u8)?;
+        self.write_count(sig.0.len())?;
+        self.write_all(&sig.0)
+    }
+}
+
+impl<'a> WriteData<&MethodSpecSignature> for SignatureWriter<'a> {
+    fn write(&mut self, sig: &MethodSpecSignature) -> Result<()> {
+        self.writer.write(CallingConvention::GenericInst as u8)?;
+        self.write_count(sig.0.len())?;
+        self.write_all(&sig.0)
+    }
+}
+
+impl<'a> WriteData<&StandAloneSignature> for SignatureWriter<'a> {
+    fn write(&mut self, sig: &StandAloneSignature) -> Result<()> {
+        match sig {
+            StandAloneSignature::LocalVar(locals) => self.write(locals),
+            StandAloneSignature::Method(method) => self.write(method),
+            StandAloneSignature::Field(field) => self.write(field),
+        }
+    }
+}
+
+impl<'a> WriteData<&MemberRefSignature> for SignatureWriter<'a> {
+    fn write(&mut self, sig: &MemberRefSignature) -> Result<()> {
+        match sig {
+            MemberRefSignature::Method(method) => self.write(method),
+            MemberRefSignature::Field(field) => self.write(field),
+        }
+    }
+}
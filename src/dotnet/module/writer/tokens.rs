@@ -0,0 +1,338 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::{
+    dotnet::{
+        entries::{
+            body::TokenOperand,
+            signature::{
+                Implementation, MemberRefParent, MethodDefOrRef, ResolutionScope, TypeDefOrRef,
+                TypeOrMethodDef,
+            },
+            values::*,
+            MaybeUnsetEntry, Ptr,
+        },
+        md::{
+            streams::tables_stream::{coded_tokens::*, TableId},
+            MDToken,
+        },
+    },
+    error::{HaoError, Result},
+    Module,
+};
+
+/// The row ids given to the entries of a single table.
+///
+/// Entries are looked up by the address of their allocation,
+/// so the same entry always maps to the same row.
+pub(crate) struct RidMap<T> {
+    table: &'static str,
+    rows: Vec<Ptr<T>>,
+    rids: HashMap<*const RefCell<MaybeUnsetEntry<T>>, u32>,
+}
+
+impl<T> RidMap<T> {
+    fn new(table: &'static str) -> Self {
+        Self {
+            table,
+            rows: Vec::new(),
+            rids: HashMap::new(),
+        }
+    }
+
+    fn from_list(table: &'static str, list: &[Ptr<T>]) -> Result<Self> {
+        let mut map = Self::new(table);
+        map.extend(list)?;
+        Ok(map)
+    }
+
+    /// Gives `ptr` the next row id.
+    fn insert(&mut self, ptr: &Ptr<T>) -> Result<u32> {
+        let rid = self.rows.len() as u32 + 1;
+        if self.rids.insert(Rc::as_ptr(&ptr.0), rid).is_some() {
+            return Err(HaoError::DuplicateEntry(self.table));
+        }
+        self.rows.push(ptr.clone());
+        Ok(rid)
+    }
+
+    fn extend(&mut self, list: &[Ptr<T>]) -> Result<()> {
+        for ptr in list {
+            self.insert(ptr)?;
+        }
+        Ok(())
+    }
+
+    pub fn rid(&self, ptr: &Ptr<T>) -> Result<u32> {
+        self.rids
+            .get(&Rc::as_ptr(&ptr.0))
+            .copied()
+            .ok_or(HaoError::EntryNotInModule(self.table))
+    }
+
+    /// The row id the next inserted entry will get.
+    pub fn next_rid(&self) -> u32 {
+        self.rows.len() as u32 + 1
+    }
+
+    pub fn rows(&self) -> &[Ptr<T>] {
+        &self.rows
+    }
+
+    pub fn len(&self) -> u32 {
+        self.rows.len() as u32
+    }
+}
+
+/// The row id of every entry that is written to the tables stream.
+///
+/// Member tables are numbered by walking the member lists of their owners,
+/// so that each owner refers to a contiguous run of rows.
+pub(crate) struct Rids {
+    pub modules: RidMap<ModuleDef>,
+    pub type_refs: RidMap<TypeRef>,
+    pub type_defs: RidMap<TypeDef>,
+    pub fields: RidMap<Field>,
+    pub methods: RidMap<Method>,
+    pub params: RidMap<Param>,
+    pub member_refs: RidMap<MemberRef>,
+    pub method_specs: RidMap<MethodSpec>,
+    pub module_refs: RidMap<ModuleRef>,
+    pub type_specs: RidMap<TypeSpec>,
+    pub stand_alone_sigs: RidMap<StandAloneSig>,
+    pub assemblies: RidMap<Assembly>,
+    pub assembly_refs: RidMap<AssemblyRef>,
+    pub files: RidMap<FileDef>,
+    pub exported_types: RidMap<ExportedType>,
+    pub manifest_resources: RidMap<ManifestResource>,
+    pub properties: RidMap<Property>,
+    pub events: RidMap<Event>,
+    pub generic_params: RidMap<GenericParam>,
+
+    /// The first field and method of each type, in type order.
+    pub field_lists: Vec<u32>,
+    pub method_lists: Vec<u32>,
+    /// The first param of each method, in method order.
+    pub param_lists: Vec<u32>,
+}
+
+impl Rids {
+    pub fn new(module: &Module) -> Result<Self> {
+        let mut rids = Self {
+            modules: RidMap::from_list("Module", &module.modules)?,
+            type_refs: RidMap::from_list("TypeRef", &module.type_refs)?,
+            type_defs: RidMap::from_list("TypeDef", &module.type_defs)?,
+            fields: RidMap::new("Field"),
+            methods: RidMap::new("Method"),
+            params: RidMap::new("Param"),
+            member_refs: RidMap::from_list("MemberRef", &module.member_refs)?,
+            method_specs: RidMap::from_list("MethodSpec", &module.method_specs)?,
+            module_refs: RidMap::from_list("ModuleRef", &module.module_ref)?,
+            type_specs: RidMap::from_list("TypeSpec", &module.type_specs)?,
+            stand_alone_sigs: RidMap::from_list("StandAloneSig", &module.stand_alone_sigs)?,
+            assemblies: RidMap::from_list("Assembly", &module.assembly)?,
+            assembly_refs: RidMap::from_list("AssemblyRef", &module.assembly_ref)?,
+            files: RidMap::from_list("File", &module.files)?,
+            exported_types: RidMap::from_list("ExportedType", &module.exported_types)?,
+            manifest_resources: RidMap::from_list("ManifestResource", &module.resources)?,
+            properties: RidMap::new("Property"),
+            events: RidMap::new("Event"),
+            generic_params: RidMap::new("GenericParam"),
+            field_lists: Vec::with_capacity(module.type_defs.len()),
+            method_lists: Vec::with_capacity(module.type_defs.len()),
+            param_lists: Vec::new(),
+        };
+
+        for ty in module.type_defs.iter() {
+            let ty = ty.value();
+            rids.field_lists.push(rids.fields.next_rid());
+            rids.fields.extend(&ty.field_list)?;
+            rids.method_lists.push(rids.methods.next_rid());
+            rids.methods.extend(&ty.method_list)?;
+            rids.properties.extend(&ty.property_list)?;
+            rids.events.extend(&ty.event_list)?;
+        }
+
+        for method in rids.methods.rows.iter() {
+            rids.param_lists.push(rids.params.next_rid());
+            rids.params.extend(&method.value().param_list)?;
+        }
+
+        // The GenericParam table is sorted by owner then number,
+        // so the row ids can only be given once every owner has one.
+        let mut generic_params = Vec::new();
+        for ty in rids.type_defs.rows.iter() {
+            generic_params.extend(ty.value().generic_params.iter().cloned());
+        }
+        for method in rids.methods.rows.iter() {
+            generic_params.extend(method.value().generic_params.iter().cloned());
+        }
+        let mut keyed = generic_params
+            .into_iter()
+            .map(|param| {
+                let owner = rids.type_or_method_def(&param.value().owner)?.encode()?;
+                let number = param.value().number;
+                Ok((owner, number, param))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        keyed.sort_by_key(|(owner, number, _)| (*owner, *number));
+        for (_, _, param) in keyed {
+            rids.generic_params.insert(&param)?;
+        }
+
+        Ok(rids)
+    }
+
+    pub fn type_def_or_ref(&self, ty: &TypeDefOrRef) -> Result<CodedToken<TypeDefOrRefToken>> {
+        Ok(match ty {
+            TypeDefOrRef::TypeDef(t) => {
+                CodedToken::new(TypeDefOrRefToken::TypeDef, self.type_defs.rid(&t.0)?)
+            }
+            TypeDefOrRef::TypeRef(t) => {
+                CodedToken::new(TypeDefOrRefToken::TypeRef, self.type_refs.rid(&t.0)?)
+            }
+            TypeDefOrRef::TypeSpec(t) => {
+                CodedToken::new(TypeDefOrRefToken::TypeSpec, self.type_specs.rid(&t.0)?)
+            }
+        })
+    }
+
+    pub fn resolution_scope(
+        &self,
+        scope: &ResolutionScope,
+    ) -> Result<CodedToken<ResolutionScopeToken>> {
+        Ok(match scope {
+            ResolutionScope::Module(m) => {
+                CodedToken::new(ResolutionScopeToken::Module, self.modules.rid(&m.0)?)
+            }
+            ResolutionScope::ModuleRef(m) => {
+                CodedToken::new(ResolutionScopeToken::ModuleRef, self.module_refs.rid(&m.0)?)
+            }
+            ResolutionScope::AssemblyRef(a) => CodedToken::new(
+                ResolutionScopeToken::AssemblyRef,
+                self.assembly_refs.rid(&a.0)?,
+            ),
+            ResolutionScope::TypeRef(t) => {
+                CodedToken::new(ResolutionScopeToken::TypeRef, self.type_refs.rid(&t.0)?)
+            }
+        })
+    }
+
+    pub fn member_ref_parent(
+        &self,
+        parent: &MemberRefParent,
+    ) -> Result<CodedToken<MemberRefParentToken>> {
+        Ok(match parent {
+            MemberRefParent::TypeDef(t) => {
+                CodedToken::new(MemberRefParentToken::TypeDef, self.type_defs.rid(&t.0)?)
+            }
+            MemberRefParent::TypeRef(t) => {
+                CodedToken::new(MemberRefParentToken::TypeRef, self.type_refs.rid(&t.0)?)
+            }
+            MemberRefParent::ModuleRef(m) => {
+                CodedToken::new(MemberRefParentToken::ModuleRef, self.module_refs.rid(&m.0)?)
+            }
+            MemberRefParent::Method(m) => {
+                CodedToken::new(MemberRefParentToken::Method, self.methods.rid(&m.0)?)
+            }
+            MemberRefParent::TypeSpec(t) => {
+                CodedToken::new(MemberRefParentToken::TypeSpec, self.type_specs.rid(&t.0)?)
+            }
+        })
+    }
+
+    pub fn implementation(
+        &self,
+        implementation: &Implementation,
+    ) -> Result<CodedToken<ImplementationToken>> {
+        Ok(match implementation {
+            Implementation::File(f) => {
+                CodedToken::new(ImplementationToken::File, self.files.rid(&f.0)?)
+            }
+            Implementation::AssemblyRef(a) => CodedToken::new(
+                ImplementationToken::AssemblyRef,
+                self.assembly_refs.rid(&a.0)?,
+            ),
+            Implementation::ExportedType(e) => CodedToken::new(
+                ImplementationToken::ExportedType,
+                self.exported_types.rid(&e.0)?,
+            ),
+        })
+    }
+
+    pub fn method_def_or_ref(
+        &self,
+        method: &MethodDefOrRef,
+    ) -> Result<CodedToken<MethodDefOrRefToken>> {
+        Ok(match method {
+            MethodDefOrRef::Method(m) => {
+                CodedToken::new(MethodDefOrRefToken::Method, self.methods.rid(&m.0)?)
+            }
+            MethodDefOrRef::MemberRef(m) => {
+                CodedToken::new(MethodDefOrRefToken::MemberRef, self.member_refs.rid(&m.0)?)
+            }
+        })
+    }
+
+    pub fn custom_attribute_type(
+        &self,
+        constructor: &MethodDefOrRef,
+    ) -> Result<CodedToken<CustomAttributeTypeToken>> {
+        Ok(match constructor {
+            MethodDefOrRef::Method(m) => {
+                CodedToken::new(CustomAttributeTypeToken::Method, self.methods.rid(&m.0)?)
+            }
+            MethodDefOrRef::MemberRef(m) => CodedToken::new(
+                CustomAttributeTypeToken::MemberRef,
+                self.member_refs.rid(&m.0)?,
+            ),
+        })
+    }
+
+    pub fn type_or_method_def(
+        &self,
+        owner: &TypeOrMethodDef,
+    ) -> Result<CodedToken<TypeOrMethodDefToken>> {
+        Ok(match owner {
+            TypeOrMethodDef::TypeDef(t) => {
+                CodedToken::new(TypeOrMethodDefToken::TypeDef, self.type_defs.rid(&t.0)?)
+            }
+            TypeOrMethodDef::Method(m) => {
+                CodedToken::new(TypeOrMethodDefToken::Method, self.methods.rid(&m.0)?)
+            }
+        })
+    }
+
+    /// The metadata token of an instruction operand.
+    pub fn token(&self, operand: &TokenOperand) -> Result<MDToken> {
+        let (table, rid) = match operand {
+            TokenOperand::TypeDef(t) => (TableId::TypeDef, self.type_defs.rid(&t.0)?),
+            TokenOperand::TypeRef(t) => (TableId::TypeRef, self.type_refs.rid(&t.0)?),
+            TokenOperand::TypeSpec(t) => (TableId::TypeSpec, self.type_specs.rid(&t.0)?),
+            TokenOperand::Method(m) => (TableId::Method, self.methods.rid(&m.0)?),
+            TokenOperand::Field(f) => (TableId::Field, self.fields.rid(&f.0)?),
+            TokenOperand::MemberRef(m) => (TableId::MemberRef, self.member_refs.rid(&m.0)?),
+            TokenOperand::MethodSpec(m) => (TableId::MethodSpec, self.method_specs.rid(&m.0)?),
+            TokenOperand::Unresolved(token) => return Ok(*token),
+        };
+        Ok(MDToken::new(table, rid))
+    }
+
+    pub fn stand_alone_sig_token(&self, sig: &Ptr<StandAloneSig>) -> Result<MDToken> {
+        Ok(MDToken::new(
+            TableId::StandAloneSig,
+            self.stand_alone_sigs.rid(sig)?,
+        ))
+    }
+
+    pub fn type_def_token(&self, ty: &TypeDefOrRef) -> Result<MDToken> {
+        self.token(&match ty {
+            TypeDefOrRef::TypeDef(t) => TokenOperand::TypeDef(t.clone()),
+            TypeDefOrRef::TypeRef(t) => TokenOperand::TypeRef(t.clone()),
+            TypeDefOrRef::TypeSpec(t) => TokenOperand::TypeSpec(t.clone()),
+        })
+    }
+
+    pub fn method_token(&self, method: &Ptr<Method>) -> Result<MDToken> {
+        Ok(MDToken::new(TableId::Method, self.methods.rid(method)?))
+    }
+}
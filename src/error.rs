@@ -36,6 +36,72 @@ pub enum HaoError {
     RecursionLimitReached,
     #[error("Invalid refrence to enttry in {0} table at index {1}.")]
     InvalidEntryRefrence(&'static str, usize),
+    #[error("Invalid method body at RVA {0:#x}. {1}")]
+    InvalidMethodBody(u32, &'static str),
+    #[error("Invalid opcode ({0:#x}) at IL offset {1}.")]
+    InvalidOpCode(u16, usize),
+    #[error("Invalid metadata token {0:#010x}.")]
+    InvalidToken(u32),
+    #[error("{0} entry is not part of the module being written.")]
+    EntryNotInModule(&'static str),
+    #[error("{0} entry is listed more than once in the module.")]
+    DuplicateEntry(&'static str),
+    #[error("{0} can not be written.")]
+    NotWritable(&'static str),
+    #[error("The underlying type of enum {0} in a custom attribute is not known. The assembly that defines it may not be loaded.")]
+    UnresolvedEnum(String),
+    #[error("Could not resolve refrence. {0}")]
+    ResolveError(#[from] ResolveError),
     #[error("unknown error")]
     Unknown,
 }
+
+/// Why a refrence could not be resolved to its definition.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ResolveError {
+    /// The refrenced assembly has not been loaded.
+    /// See [`crate::Module::load_dependancies`].
+    #[error("Assembly {0} is not loaded.")]
+    AssemblyNotLoaded(String),
+    /// The type is in another module of the same assembly, which is not loaded.
+    #[error("Module {0} is not loaded.")]
+    ModuleNotLoaded(String),
+    /// The type is in the module it is refrenced from, which is not known to the refrence.
+    /// See [`crate::Module::resolve_type_ref`].
+    #[error("{0} is refrenced from its own module.")]
+    OwnModule(String),
+    /// The refrenced module or type does not define the type.
+    #[error("{0} is not defined in {1}.")]
+    TypeNotFound(String, String),
+    /// The type does not define a member with the name and signature of the refrence.
+    #[error("{0} is not defined in {1}.")]
+    MemberNotFound(String, String),
+    /// The type is not a refrence to a type definition, such as an array or pointer type.
+    #[error("{0} does not refrence a type definition.")]
+    NotATypeDef(String),
+}
+
+/// A problem found in a row of a metadata table while loading a module leniently.
+///
+/// See [`crate::Module::from_metadata_lenient`].
+#[derive(Debug)]
+pub struct LoadDiagnostic {
+    /// The name of the table the row belongs to, e.g. `TypeDef`.
+    pub table: &'static str,
+    /// The row id of the row. Row id's start at 1.
+    pub row: u32,
+    /// The column that could not be read.
+    /// `None` if the whole row was replaced with a placeholder.
+    pub column: Option<&'static str>,
+    pub error: HaoError,
+}
+
+impl std::fmt::Display for LoadDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} row {}", self.table, self.row)?;
+        if let Some(column) = self.column {
+            write!(f, " ({})", column)?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::error::{HaoError, Result};
+
+pub trait WriteData<T> {
+    fn write(&mut self, value: T) -> Result<()>;
+}
+
+pub trait Writable {
+    fn to_writer(&self, writer: &mut DataWriter) -> Result<()>;
+}
+
+impl<T> WriteData<&T> for DataWriter
+where
+    T: Writable,
+{
+    #[inline(always)]
+    fn write(&mut self, value: &T) -> Result<()> {
+        value.to_writer(self)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DataWriter {
+    buffer: Vec<u8>,
+}
+
+impl DataWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn position(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline(always)]
+    pub fn write_slice(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    pub fn write_zeros(&mut self, len: usize) {
+        self.buffer.resize(self.buffer.len() + len, 0);
+    }
+
+    /// Pads the buffer with zeros up to the next multiple of `alignment`.
+    pub fn align(&mut self, alignment: usize) {
+        let padding = (alignment - self.position() % alignment) % alignment;
+        self.write_zeros(padding);
+    }
+
+    /// Overwrites a `u32` that has already been written at `position`.
+    pub fn patch_u32(&mut self, position: usize, value: u32) -> Result<()> {
+        let dest = self
+            .buffer
+            .get_mut(position..position + 4)
+            .ok_or(HaoError::NotEnoughDataLeft(position + 4))?;
+        dest.copy_from_slice(&value.to_le_bytes());
+        Ok(())
+    }
+
+    /// Writes an unsigned integer with the compression used by blobs and signatures.
+    /// See ECMA-335 II.23.2.
+    pub fn write_compressed_u32(&mut self, value: u32) -> Result<()> {
+        match value {
+            0..=0x7F => self.write_slice(&[value as u8]),
+            0x80..=0x3FFF => self.write_slice(&((value as u16) | 0x8000).to_be_bytes()),
+            0x4000..=0x1FFF_FFFF => self.write_slice(&(value | 0xC000_0000).to_be_bytes()),
+            _ => {
+                return Err(HaoError::BadImageFormat(
+                    "Value is too large to be compressed",
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buffer
+    }
+}
+
+macro_rules! impl_write_data {
+    ($($t:ty),+) => {
+        $(
+            impl WriteData<$t> for DataWriter {
+                #[inline(always)]
+                fn write(&mut self, value: $t) -> Result<()> {
+                    self.write_slice(&value.to_le_bytes());
+                    Ok(())
+                }
+            }
+         )*
+    };
+}
+
+impl_write_data! {
+    u8,
+    u16,
+    u32,
+    u64
+}
@@ -1,20 +1,27 @@
+use std::{
+    cell::{Cell, OnceCell, RefCell},
+    collections::HashMap,
+};
+
 use crate::{
     dotnet::{
-        entries::{values::TypeDef, GetEntryField, MaybeUninitEntries},
+        entries::{values::TypeDef, GetEntryField, MaybeUninitEntries, MaybeUnsetEntry},
         entries::{
-            values::{Field, Method, Param},
-            Ptr, RowRange,
+            values::{Event, Field, GenericParam, Method, ModuleRef, Param, PointerRow, Property},
+            Placeholder, Ptr, RowRange,
         },
         md::streams::{
             tables_stream::{
                 coded_tokens::{CodedToken, CodedTokenTarget},
-                BlobStreamOffset, BlobStreamOffsetTypeSpec, FieldTableOffset, GuidStreamOffset,
-                MethodTableOffset, ParamTableOffset, StringsStreamOffset, TypeDefTableOffset,
+                BlobStreamOffset, BlobStreamOffsetTypeSpec, EventTableOffset, FieldTableOffset,
+                GenericParamTableOffset, GuidStreamOffset, MethodTableOffset, ModuleRefTableOffset,
+                ParamTableOffset, PropertyTableOffset, StringsStreamOffset, TypeDefTableOffset,
             },
             MetadataStreams, SignatureDef, TypeSigDef,
         },
+        Metadata,
     },
-    error::{HaoError, Result},
+    error::{HaoError, LoadDiagnostic, Result},
 };
 
 use super::ReadData;
@@ -28,19 +35,103 @@ pub(crate) trait GetTableForRead<T> {
     type TablevalueType: Clone;
     fn to_index(&self, offset: T) -> Result<usize>;
     fn get_table(&self) -> &[Ptr<Self::TablevalueType>];
+
+    /// The `*Ptr` table that member lists index through, if the table has one.
+    /// Empty unless the metadata is uncompressed (#-).
+    fn get_pointer_table(&self) -> &[Ptr<PointerRow<Self::TablevalueType>>] {
+        &[]
+    }
 }
 
 pub(crate) struct EntryReader<'a> {
     pub(crate) streams: &'a MetadataStreams<'a>,
     pub(crate) entries: &'a MaybeUninitEntries,
+    lenient: bool,
+    current_row: Cell<(&'static str, u32)>,
+    diagnostics: RefCell<Vec<LoadDiagnostic>>,
+    pub(crate) method_owners:
+        OnceCell<HashMap<*const RefCell<MaybeUnsetEntry<Method>>, Ptr<TypeDef>>>,
 }
 
 impl<'a> EntryReader<'a> {
     pub(crate) fn from_metadata(
-        streams: &'a MetadataStreams<'a>,
+        metadata: &'a Metadata<'a>,
         entries: &'a MaybeUninitEntries,
+        lenient: bool,
     ) -> Self {
-        Self { streams, entries }
+        Self {
+            streams: &metadata.metadata_streams,
+            entries,
+            lenient,
+            current_row: Cell::new(("", 0)),
+            diagnostics: RefCell::new(Vec::new()),
+            method_owners: OnceCell::new(),
+        }
+    }
+
+    /// Sets the row that any following diagnostics are reported against.
+    pub(crate) fn set_current_row(&self, table: &'static str, row: u32) {
+        self.current_row.set((table, row));
+    }
+
+    /// Records a problem with the current row when loading leniently.
+    /// Otherwise the error is returned as is.
+    pub(crate) fn report(&self, column: Option<&'static str>, error: HaoError) -> Result<()> {
+        if !self.lenient {
+            return Err(error);
+        }
+        let (table, row) = self.current_row.get();
+        self.diagnostics.borrow_mut().push(LoadDiagnostic {
+            table,
+            row,
+            column,
+            error,
+        });
+        Ok(())
+    }
+
+    /// Replaces a column that failed to load with a placeholder when loading leniently.
+    pub(crate) fn recover<V: Placeholder>(
+        &self,
+        column: &'static str,
+        value: Result<V>,
+    ) -> Result<V> {
+        value.or_else(|error| {
+            self.report(Some(column), error)?;
+            Ok(V::placeholder())
+        })
+    }
+
+    /// Reads a `#Strings` column.
+    /// Invalid UTF-8 is decoded lossily rather than replaced when loading leniently.
+    pub(crate) fn read_string(
+        &self,
+        column: &'static str,
+        offset: StringsStreamOffset,
+    ) -> Result<String> {
+        match self.read(offset) {
+            Err(HaoError::InvalidUTF8String(position, bytes)) if self.lenient => {
+                let value = String::from_utf8_lossy(&bytes).into_owned();
+                self.report(Some(column), HaoError::InvalidUTF8String(position, bytes))?;
+                Ok(value)
+            }
+            value => self.recover(column, value),
+        }
+    }
+
+    /// Reads the raw contents of a `#Blob` column.
+    pub(crate) fn read_blob(&self, offset: BlobStreamOffset) -> Result<Vec<u8>> {
+        Ok(self
+            .streams
+            .blob_stream
+            .get_blob(offset.0)?
+            .reader
+            .remaning_slice()
+            .to_vec())
+    }
+
+    pub(crate) fn into_diagnostics(self) -> Vec<LoadDiagnostic> {
+        self.diagnostics.into_inner()
     }
 }
 
@@ -82,24 +173,37 @@ where
 
     fn read(&self, identifier: RowRange<T>) -> Result<Self::EntryValue> {
         let target_rows = self.get_table();
+        let pointer_rows = self.get_pointer_table();
+
+        let row_count = if pointer_rows.is_empty() {
+            target_rows.len()
+        } else {
+            pointer_rows.len()
+        };
 
         let start = self.to_index(identifier.start)?;
         let end = identifier.end.map(|v| self.to_index(v)).transpose()?;
 
-        let end = end.unwrap_or(target_rows.len());
+        let end = end.unwrap_or(row_count).min(row_count);
 
         if start == end {
             Ok(Vec::new())
         } else {
-            if start >= target_rows.len() {
+            if start >= row_count || start > end {
                 return Err(HaoError::InvalidEntryRefrence(
                     std::any::type_name::<Self::EntryValue>(),
                     start,
                 ));
             }
 
-            let slice = target_rows.get(start..end).unwrap_or(&target_rows[start..]);
-            Ok(slice.to_vec())
+            if pointer_rows.is_empty() {
+                Ok(target_rows[start..end].to_vec())
+            } else {
+                Ok(pointer_rows[start..end]
+                    .iter()
+                    .map(|row| row.value().0.clone())
+                    .collect())
+            }
         }
     }
 }
@@ -114,6 +218,9 @@ impl<'a> GetTableForRead<FieldTableOffset> for EntryReader<'a> {
     fn get_table(&self) -> &[Ptr<Self::TablevalueType>] {
         self.entries.fields.as_slice()
     }
+    fn get_pointer_table(&self) -> &[Ptr<PointerRow<Self::TablevalueType>>] {
+        self.entries.field_ptrs.as_slice()
+    }
 }
 
 impl<'a> GetTableForRead<TypeDefTableOffset> for EntryReader<'a> {
@@ -138,6 +245,9 @@ impl<'a> GetTableForRead<ParamTableOffset> for EntryReader<'a> {
     fn get_table(&self) -> &[Ptr<Self::TablevalueType>] {
         self.entries.params.as_slice()
     }
+    fn get_pointer_table(&self) -> &[Ptr<PointerRow<Self::TablevalueType>>] {
+        self.entries.param_ptrs.as_slice()
+    }
 }
 
 impl<'a> GetTableForRead<MethodTableOffset> for EntryReader<'a> {
@@ -150,6 +260,63 @@ impl<'a> GetTableForRead<MethodTableOffset> for EntryReader<'a> {
     fn get_table(&self) -> &[Ptr<Self::TablevalueType>] {
         self.entries.methods.as_slice()
     }
+    fn get_pointer_table(&self) -> &[Ptr<PointerRow<Self::TablevalueType>>] {
+        self.entries.method_ptrs.as_slice()
+    }
+}
+
+impl<'a> GetTableForRead<PropertyTableOffset> for EntryReader<'a> {
+    type TablevalueType = Property;
+    fn to_index(&self, offset: PropertyTableOffset) -> Result<usize> {
+        (offset.0 as usize).checked_sub(1).ok_or_else(|| {
+            HaoError::InvalidEntryRefrence(std::any::type_name::<Self::TablevalueType>(), 0)
+        })
+    }
+    fn get_table(&self) -> &[Ptr<Self::TablevalueType>] {
+        self.entries.properties.as_slice()
+    }
+    fn get_pointer_table(&self) -> &[Ptr<PointerRow<Self::TablevalueType>>] {
+        self.entries.property_ptrs.as_slice()
+    }
+}
+
+impl<'a> GetTableForRead<EventTableOffset> for EntryReader<'a> {
+    type TablevalueType = Event;
+    fn to_index(&self, offset: EventTableOffset) -> Result<usize> {
+        (offset.0 as usize).checked_sub(1).ok_or_else(|| {
+            HaoError::InvalidEntryRefrence(std::any::type_name::<Self::TablevalueType>(), 0)
+        })
+    }
+    fn get_table(&self) -> &[Ptr<Self::TablevalueType>] {
+        self.entries.events.as_slice()
+    }
+    fn get_pointer_table(&self) -> &[Ptr<PointerRow<Self::TablevalueType>>] {
+        self.entries.event_ptrs.as_slice()
+    }
+}
+
+impl<'a> GetTableForRead<GenericParamTableOffset> for EntryReader<'a> {
+    type TablevalueType = GenericParam;
+    fn to_index(&self, offset: GenericParamTableOffset) -> Result<usize> {
+        (offset.0 as usize).checked_sub(1).ok_or_else(|| {
+            HaoError::InvalidEntryRefrence(std::any::type_name::<Self::TablevalueType>(), 0)
+        })
+    }
+    fn get_table(&self) -> &[Ptr<Self::TablevalueType>] {
+        self.entries.generic_params.as_slice()
+    }
+}
+
+impl<'a> GetTableForRead<ModuleRefTableOffset> for EntryReader<'a> {
+    type TablevalueType = ModuleRef;
+    fn to_index(&self, offset: ModuleRefTableOffset) -> Result<usize> {
+        (offset.0 as usize).checked_sub(1).ok_or_else(|| {
+            HaoError::InvalidEntryRefrence(std::any::type_name::<Self::TablevalueType>(), 0)
+        })
+    }
+    fn get_table(&self) -> &[Ptr<Self::TablevalueType>] {
+        self.entries.module_ref.as_slice()
+    }
 }
 
 impl<'a> ValueReadable<u16> for EntryReader<'a> {
@@ -1,7 +1,9 @@
 mod data_reader;
+mod data_writer;
 mod entry_reader;
 mod peimage;
 
 pub use data_reader::*;
+pub use data_writer::*;
 pub use entry_reader::*;
 pub use peimage::PEImage;
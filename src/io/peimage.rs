@@ -3,7 +3,7 @@ use crate::io::DataReader;
 
 use pewter::pe::optional_header::OptionalHeader;
 use pewter::pe::sections::cor20::ImageCor20Header;
-use pewter::pe::sections::{SectionRow};
+use pewter::pe::sections::SectionRow;
 use pewter::PEFile;
 
 pub struct PEImage<'a> {
@@ -18,10 +18,13 @@ impl<'a> PEImage<'a> {
     }
 
     pub fn optional_header(&self) -> Result<&OptionalHeader> {
-        self.pe.optional_header.as_ref().ok_or(HaoError::BadPeFormat)
+        self.pe
+            .optional_header
+            .as_ref()
+            .ok_or(HaoError::BadPeFormat)
     }
 
-    pub fn rva_to_section(&self, rva: u32) -> Option<&SectionRow> {
+    pub fn rva_to_section(&self, rva: u32) -> Option<&SectionRow<'_>> {
         self.pe.sections.find_rva(rva as usize)
     }
 
@@ -34,7 +37,7 @@ impl<'a> PEImage<'a> {
             .pe
             .sections
             .find_rva_data(rva as usize)
-            .ok_or_else(|| HaoError::BadRva(rva))?;
+            .ok_or(HaoError::BadRva(rva))?;
 
         let data = if let Some(size) = size {
             &data[..size]
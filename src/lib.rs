@@ -1,11 +1,15 @@
 //! `hao` is a library for reading and writing .net modules and assemblies in rust.
 //!
-//! Currently, `hao` is quite struct with the binaries it recieved, erroring with malfomed
-//! binaries rather than attempting to ignore the issues. Though the plan is to impliment
-//! a feature flag that can optionally continue parsing the file and expose the raw, non-sanitary
-//! parts that it encounters.
+//! By default, `hao` is quite strict with the binaries it recieves, erroring with malfomed
+//! binaries rather than attempting to ignore the issues. [`Module::from_metadata_lenient`] can instead
+//! be used to continue loading past malformed rows, replacing them with placeholders and reporting
+//! each problem it encounters as a [`error::LoadDiagnostic`].
 //!
-//! The current state of this library is read-only with .net framework/core files and largely untested with mono, however
+//! Loaded modules can be written back out as a PE image with [`Module::write`]. The metadata is rebuilt
+//! from the loaded entries, so parts of the original image that are not loaded (such as win32 resources or
+//! the strong name signature) are not kept.
+//!
+//! The library targets .net framework/core files and is largely untested with mono, however
 //! mono support is definetly going to be supported in the future.
 //!
 //! The entrypoint for this library is the [`Module`] struct. You can get started with loading a module like so:
@@ -14,9 +18,9 @@
 //! use hao::{dotnet::md::streams::tables_stream::FieldFlags, Module};
 //!
 //! let module = Module::from_path(r#"C:\re\dnspy\bin\dnlib.dll"#).unwrap();
-//! 
+//!
 //! println!("loaded");
-//! 
+//!
 //! for ty in module.types().values() {
 //!     println!("{} {{", ty);
 //!     if ty.is_enum() {